@@ -3,10 +3,64 @@
 /// `nss` stands for numbers, symbols and sizes.
 /// It includes some basics, for mathematics and mathematical use
 pub mod nss {
+    mod greek_letter;
+    mod wide;
+    mod greek_input;
+    pub use greek_input::{GreekInput, InputLayout};
+    mod greek_encoding;
+    pub use greek_encoding::{EncodingError, GreekEncoding};
+    mod greek_codes;
+    pub use greek_codes::{GreekCodeError, to_morse, from_morse, to_braille, from_braille, letters_to_braille, braille_to_letters};
+    mod greek_collation;
+    pub use greek_collation::{collate, to_upper, to_lower};
+    mod script;
+    pub use script::{ScriptError, to_superscript, to_subscript, from_superscript, from_subscript};
+    mod math_text;
+    pub use math_text::{MathText, WordBoundary};
+    mod symbol;
+    pub use symbol::{Symbol, SymbolAccent, SymbolBase, SymbolError, SymbolGenerator};
+    mod digits;
+    pub use digits::{Digits, Irrational};
+    mod quantity;
+    pub use quantity::{Dimension, DimensionError, Quantity, SiUnit};
+    mod si_prefix;
+    pub use si_prefix::{SiPrefix, SiPrefixError, format_si, parse_si};
+    mod typed;
+    pub use typed::{Dim, DimDiv, DimMul, Dims, DimensionType, Exp, ExpAdd, ExpSub, Exponent, TypedQuantity};
+    pub use typed::{Acceleration, Amount, Area, Charge, Current, Dimensionless, Energy, Force, Frequency, Length, LuminousIntensity};
+    pub use typed::{Mass, Power, Pressure, Resistance, Temperature, Time, Velocity, Voltage, Volume};
+    mod units;
+    pub use units::{Unit, UnitError, convert};
+    mod data_size;
+    pub use data_size::{DataRate, DataSize, DataSizeError, PrefixSystem};
+    mod measurement;
+    pub use measurement::{Measurement, MeasurementError};
+    mod number_format;
+    pub use number_format::{Locale, Notation, NumberFormat, NumberFormatError, Precision, RoundingMode};
+    mod rational;
+    pub use rational::{Integer, Rational, RationalError};
+    mod big_int;
+    pub use big_int::{BigInt, BigIntError, BigUint};
+    mod big_decimal;
+    pub use big_decimal::{BigDecimal, BigDecimalError, DecimalContext};
+    mod complex;
+    pub use complex::{Complex, ComplexError, Real};
+    mod quaternion;
+    pub use quaternion::{EulerOrder, Quaternion};
+    mod dual;
+    pub use dual::{Dual, DualN, HyperDual};
+    mod tape;
+    pub use tape::{Gradients, Tape, Var};
+    mod interval;
+    pub use interval::{Interval, IntervalRoot};
+    mod fixed;
+    pub use fixed::{Fixed, FixedError, FixedInt};
+    pub mod constants;
+
     /// contains the Greek alphabet
     /// 
     /// An enumeration which includes all the characters of the Greek alphabet and offers some methods to deal with them.
-    # [derive(Clone,Copy,PartialEq,PartialOrd,Debug)]
+    # [derive(Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Debug)]
     pub enum GreekAlphabet {
         Alpha,  Beta,   Gamma,  Delta,  Epsilon,
         Zeta,   Eta,    Theta,  Iota,   Kappa,
@@ -22,8 +76,9 @@ pub mod nss {
         /// 
         /// # Example:
         /// ```rust
-        /// let sigma = GreekAlphabet::Pi;
-        /// assert_eq!("Σ", sigma.uppercase());
+        /// # use math_sd::nss::GreekAlphabet;
+        /// let sigma = GreekAlphabet::Sigma;
+        /// assert_eq!('Σ', sigma.uppercase());
         /// ```
         pub fn uppercase(&self) -> char {
             match self {
@@ -61,8 +116,9 @@ pub mod nss {
         /// 
         /// # Example:
         /// ```rust
+        /// # use math_sd::nss::GreekAlphabet;
         /// let pi = GreekAlphabet::Pi;
-        /// assert_eq!("π", pi.lowercase());
+        /// assert_eq!('π', pi.lowercase());
         /// ```
         pub fn lowercase(&self) -> char {
             match self {
@@ -102,6 +158,7 @@ pub mod nss {
         /// 
         /// # Examples
         /// ```rust
+        /// # use math_sd::nss::GreekAlphabet;
         /// let psi = GreekAlphabet::Psi;
         /// assert_eq!("ps", psi.transcription());
         /// ```
@@ -143,6 +200,7 @@ pub mod nss {
         /// 
         /// # Examples
         /// ```rust
+        /// # use math_sd::nss::GreekAlphabet;
         /// let psi = GreekAlphabet::Psi;
         /// assert_eq!(String::from("ps"), psi.transcription_string());
         /// ```
@@ -157,6 +215,7 @@ pub mod nss {
         /// 
         /// # Examples
         /// ```rust
+        /// # use math_sd::nss::GreekAlphabet;
         /// let pi = GreekAlphabet::Pi;
         /// let letters : (char,char) = pi.letters();
         /// ```
@@ -170,6 +229,7 @@ pub mod nss {
         /// 
         /// # Examples
         /// ```rust
+        /// # use math_sd::nss::GreekAlphabet;
         /// let pi = GreekAlphabet::Pi;
         /// let letters : (String,String) = pi.letters_string();
         /// ```
@@ -183,6 +243,7 @@ pub mod nss {
         /// 
         /// # Examples
         /// ```rust
+        /// # use math_sd::nss::GreekAlphabet;
         /// let pi = GreekAlphabet::Pi;
         /// let letters : Vec<char> = pi.letters_vec();
         /// ```
@@ -196,6 +257,7 @@ pub mod nss {
         /// 
         /// # Examples
         /// ```rust
+        /// # use math_sd::nss::GreekAlphabet;
         /// let pi = GreekAlphabet::Pi;
         /// let letters : Vec<String> = pi.letters_vec_string();
         /// ```
@@ -209,6 +271,7 @@ pub mod nss {
         /// 
         /// # Examples
         /// ```rust
+        /// # use math_sd::nss::GreekAlphabet;
         /// let pi = GreekAlphabet::Pi;
         /// let letters : [char;2] = pi.letters_array();
         /// ```
@@ -222,12 +285,14 @@ pub mod nss {
         /// 
         /// # Examples
         /// ```rust
+        /// # use math_sd::nss::GreekAlphabet;
         /// let pi = GreekAlphabet::Pi;
         /// let letters : [String;2] = pi.letters_array_string();
         /// ```
         pub fn letters_array_string(&self) -> [String;2] {
             [String::from(self.uppercase()), String::from(self.lowercase())]
         }
+
     }
     
     impl std::fmt::Display for GreekAlphabet {
@@ -263,6 +328,7 @@ pub mod nss {
         }
     }
 
+    #[allow(clippy::derivable_impls)]
     impl std::default::Default for GreekAlphabet {
         fn default() -> Self {
             GreekAlphabet::Empty
@@ -449,7 +515,7 @@ pub mod nss {
     impl std::convert::From<&str> for GreekAlphabet {
         fn from(t: &str) -> Self {
             if t.len() == 1 {
-                for c in t.chars() {
+                if let Some(c) = t.chars().next() {
                     return GreekAlphabet::from(c)
                 }
             }
@@ -459,7 +525,7 @@ pub mod nss {
     impl std::convert::From<String> for GreekAlphabet {
         fn from(t: String) -> Self {
             if t.len() == 1 {
-                for c in t.chars() {
+                if let Some(c) = t.chars().next() {
                     return GreekAlphabet::from(c)
                 }
             }
@@ -467,17 +533,20 @@ pub mod nss {
         }
     }
 
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<char> for GreekAlphabet {
         fn into(self) -> char {
             self.lowercase()
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<String> for GreekAlphabet {
         fn into(self) -> String {
             self.lowercase().to_string()
         }
     }
 
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<u8> for GreekAlphabet {
         fn into(self) -> u8 {
             match self {
@@ -486,6 +555,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<u16> for GreekAlphabet {
         fn into(self) -> u16 {
             match self {
@@ -494,6 +564,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<u32> for GreekAlphabet {
         fn into(self) -> u32 {
             match self {
@@ -502,6 +573,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<u64> for GreekAlphabet {
         fn into(self) -> u64 {
             match self {
@@ -510,6 +582,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<u128> for GreekAlphabet {
         fn into(self) -> u128 {
             match self {
@@ -518,6 +591,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<usize> for GreekAlphabet {
         fn into(self) -> usize {
             match self {
@@ -527,6 +601,7 @@ pub mod nss {
         }
     }
     
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<i8> for GreekAlphabet {
         fn into(self) -> i8 {
             match self {
@@ -535,6 +610,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<i16> for GreekAlphabet {
         fn into(self) -> i16 {
             match self {
@@ -543,6 +619,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<i32> for GreekAlphabet {
         fn into(self) -> i32 {
             match self {
@@ -551,6 +628,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<i64> for GreekAlphabet {
         fn into(self) -> i64 {
             match self {
@@ -559,6 +637,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<i128> for GreekAlphabet {
         fn into(self) -> i128 {
             match self {
@@ -567,6 +646,7 @@ pub mod nss {
             }
         }
     }
+    #[allow(clippy::from_over_into)]
     impl std::convert::Into<isize> for GreekAlphabet {
         fn into(self) -> isize {
             match self {
@@ -581,7 +661,7 @@ pub mod nss {
         fn add(self, other: Self) -> Self {
             let mut p : u8 = (self as u8 + 1) + (other as u8 + 1);
             if p > GreekAlphabet::Omega.into() {
-                p = p - (GreekAlphabet::Omega as u8 + 1);
+                p -= GreekAlphabet::Omega as u8 + 1;
             }
             GreekAlphabet::from(p)
         }
@@ -591,7 +671,7 @@ pub mod nss {
         fn sub(self, other: Self) -> Self {
             let mut p : i8 = (self as i8 + 1) - (other as i8 + 1);
             if p < 1 {
-                p = p + (GreekAlphabet::Omega as i8 + 1);
+                p += GreekAlphabet::Omega as i8 + 1;
             }
             GreekAlphabet::from(p)
         }
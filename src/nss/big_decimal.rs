@@ -0,0 +1,967 @@
+use super::{BigInt, BigIntError, BigUint, RoundingMode};
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+/// an error of parsing a [`BigDecimal`]
+///
+/// The offsets are byte offsets into the text.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum BigDecimalError {
+    /// the text is empty or only whitespace
+    Empty,
+    /// a character which does not belong to a decimal number, as `(offset, char)`
+    Unexpected(usize, char),
+    /// the text ends before a digit, as `offset`
+    UnexpectedEnd(usize),
+    /// the exponent does not fit an `i64`
+    OutOfRange,
+}
+
+impl std::fmt::Display for BigDecimalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            BigDecimalError::Empty                  =>  write!(f,"empty text"),
+            BigDecimalError::Unexpected(offset, c)  =>  write!(f,"unexpected {:?} at {}",c,offset),
+            BigDecimalError::UnexpectedEnd(offset)  =>  write!(f,"expected a digit at {}",offset),
+            BigDecimalError::OutOfRange             =>  write!(f,"exponent out of range")
+        }
+    }
+}
+impl std::error::Error for BigDecimalError {}
+
+/// an exact decimal number `unscaled · 10^exponent` of arbitrary size
+///
+/// The operators `+`, `-` and `*` are exact. Division, square roots and the elementary functions
+/// round their results, so they are methods of a [`DecimalContext`].
+/// Trailing zeros are not kept, so `1.50` and `1.5` are the same number.
+/// `*` panics if the exponent of the product overflows, [`BigDecimal::try_mul`] returns an error instead.
+/// `Display` writes the number without exponent unless that needs more than 1000 zeros,
+/// then it writes the number like `{:e}`.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::BigDecimal;
+/// let price : BigDecimal = "19.99".parse().unwrap();
+/// let total = &price * &BigDecimal::from(3) - "0.97".parse::<BigDecimal>().unwrap();
+/// assert_eq!("59", total.to_string());
+/// let tiny : BigDecimal = "-1.25e-30".parse().unwrap();
+/// assert_eq!("-0.00000000000000000000000000000125", tiny.to_string());
+/// assert_eq!("-1.25e-30", format!("{:e}", tiny));
+/// assert_eq!("1e-1000000000", "1e-1000000000".parse::<BigDecimal>().unwrap().to_string());
+/// assert_eq!(Some("0.1000000000000000055511151231257827021181583404541015625".parse().unwrap()), BigDecimal::from_f64(0.1));
+/// ```
+# [derive(Clone,PartialEq,Eq,Hash,Default)]
+pub struct BigDecimal {
+    // without trailing zeros, so zero is `0e0`
+    unscaled: BigInt,
+    exponent: i64,
+}
+
+impl BigDecimal {
+    /// returns `unscaled · 10^exponent`
+    pub fn new(unscaled: BigInt, exponent: i64) -> Self {
+        if unscaled.is_zero() {
+            return BigDecimal::zero()
+        }
+        let mut unscaled = unscaled;
+        let mut exponent = exponent;
+        for &(divisor, zeros) in &[(10_000_000_000_000_000u64, 16), (10, 1)] {
+            let divisor = BigInt::from(divisor);
+            loop {
+                let (quotient, remainder) = unscaled.div_rem(&divisor);
+                if !remainder.is_zero() {
+                    break
+                }
+                unscaled = quotient;
+                exponent += zeros;
+            }
+        }
+        BigDecimal { unscaled, exponent }
+    }
+    /// returns `0`
+    pub fn zero() -> Self {
+        BigDecimal { unscaled: BigInt::zero(), exponent: 0 }
+    }
+    /// returns `1`
+    pub fn one() -> Self {
+        BigDecimal { unscaled: BigInt::one(), exponent: 0 }
+    }
+    /// returns the digits as an integer without trailing zeros
+    pub fn unscaled(&self) -> &BigInt {
+        &self.unscaled
+    }
+    /// returns the power of ten of the last digit
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+    /// returns the number of significant digits, `0` for zero
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::BigDecimal;
+    /// assert_eq!(3, "-0.00125".parse::<BigDecimal>().unwrap().digits());
+    /// assert_eq!(1, "2.000e8".parse::<BigDecimal>().unwrap().digits());
+    /// ```
+    pub fn digits(&self) -> u64 {
+        decimal_digits(self.unscaled.magnitude())
+    }
+    // the power of ten of the first digit
+    fn adjusted(&self) -> i64 {
+        self.exponent + self.digits() as i64 - 1
+    }
+    /// returns whether the number is zero
+    pub fn is_zero(&self) -> bool {
+        self.unscaled.is_zero()
+    }
+    /// returns whether the number is less than zero
+    pub fn is_negative(&self) -> bool {
+        self.unscaled.is_negative()
+    }
+    /// returns whether the number has no fractional digits
+    pub fn is_integer(&self) -> bool {
+        self.exponent >= 0
+    }
+    /// returns `-1`, `0` or `1` for negative numbers, zero and positive numbers
+    pub fn signum(&self) -> i8 {
+        self.unscaled.signum()
+    }
+    /// returns the absolute value
+    pub fn abs(&self) -> BigDecimal {
+        BigDecimal { unscaled: self.unscaled.abs(), exponent: self.exponent }
+    }
+    /// returns the number raised to a power
+    ///
+    /// # Panics
+    /// Panics if the exponent of the power overflows.
+    pub fn pow(&self, exponent: u32) -> BigDecimal {
+        let power = self.exponent.checked_mul(exponent as i64).expect("exponent overflow");
+        BigDecimal { unscaled: self.unscaled.pow(exponent), exponent: power }
+    }
+    /// returns the exact product, or [`BigDecimalError::OutOfRange`] if its exponent does not fit an `i64`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigDecimal, BigDecimalError};
+    /// let large : BigDecimal = "1e9223372036854775807".parse().unwrap();
+    /// assert_eq!(Ok(BigDecimal::from(10).pow(3)), "1e-9223372036854775804".parse::<BigDecimal>().unwrap().try_mul(&large));
+    /// assert_eq!(Err(BigDecimalError::OutOfRange), large.try_mul(&BigDecimal::from(10)));
+    /// ```
+    pub fn try_mul(&self, other: &BigDecimal) -> Result<BigDecimal, BigDecimalError> {
+        if self.is_zero() || other.is_zero() {
+            return Ok(BigDecimal::zero())
+        }
+        let exponent = self.exponent.checked_add(other.exponent).ok_or(BigDecimalError::OutOfRange)?;
+        Ok(BigDecimal::new(&self.unscaled * &other.unscaled, exponent))
+    }
+    /// returns the number rounded to `decimals` digits after the decimal point,
+    /// a negative `decimals` rounds to tens, hundreds and so on
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigDecimal, RoundingMode};
+    /// let value : BigDecimal = "-2.345".parse().unwrap();
+    /// assert_eq!("-2.34", value.round_decimals(2, RoundingMode::HalfEven).to_string());
+    /// assert_eq!("-2.35", value.round_decimals(2, RoundingMode::HalfUp).to_string());
+    /// assert_eq!("-2", value.round_decimals(0, RoundingMode::Ceiling).to_string());
+    /// assert_eq!("1200", BigDecimal::from(1250).round_decimals(-2, RoundingMode::HalfEven).to_string());
+    /// ```
+    pub fn round_decimals(&self, decimals: i64, rounding: RoundingMode) -> BigDecimal {
+        round_at(self.unscaled.clone(), self.exponent, false, -decimals, rounding)
+    }
+
+    /// returns the nearest `f64`, which may be an infinity or zero
+    pub fn to_f64(&self) -> f64 {
+        // the standard library parses decimal numbers correctly rounded
+        format!("{}e{}",self.unscaled,self.exponent).parse().unwrap_or(0.0)
+    }
+    /// returns the exact value of a finite `f64`, or `None` if it is not finite
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None
+        }
+        let bits = value.to_bits();
+        let biased = (bits >> 52 & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased == 0 { (fraction, -1074) } else { (fraction | 1 << 52, biased - 1075) };
+        let magnitude = BigUint::from(mantissa);
+        // m · 2^-k is m · 5^k · 10^-k
+        let (magnitude, exponent) = if exponent >= 0 {
+            (magnitude << exponent as u64, 0)
+        } else {
+            (magnitude * BigUint::from(5u8).pow(exponent.unsigned_abs() as u32), exponent)
+        };
+        Some(BigDecimal::new(BigInt::from_biguint(value < 0.0, magnitude), exponent))
+    }
+
+    fn add_decimal(&self, other: &BigDecimal) -> BigDecimal {
+        let exponent = self.exponent.min(other.exponent);
+        let sum = shifted(&self.unscaled, self.exponent - exponent) + shifted(&other.unscaled, other.exponent - exponent);
+        BigDecimal::new(sum, exponent)
+    }
+    fn sub_decimal(&self, other: &BigDecimal) -> BigDecimal {
+        self.add_decimal(&-other)
+    }
+    fn mul_decimal(&self, other: &BigDecimal) -> BigDecimal {
+        self.try_mul(other).expect("exponent overflow")
+    }
+}
+
+/// the precision and rounding of the inexact operations on [`BigDecimal`]
+///
+/// Every result is correctly rounded: it is the exact result rounded to `precision` significant digits.
+/// The elementary functions are evaluated with more digits than needed,
+/// and again with even more digits until the rounding is certain.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{BigDecimal, DecimalContext, RoundingMode};
+/// let context = DecimalContext::new(30);
+/// assert_eq!("3.14159265358979323846264338328", context.pi().to_string());
+/// let two = BigDecimal::from(2);
+/// assert_eq!("1.41421356237309504880168872421", context.sqrt(&two).to_string());
+/// assert_eq!("0.693147180559945309417232121458", context.ln(&two).to_string());
+/// assert_eq!("7.38905609893065022723042746058", context.exp(&two).to_string());
+/// let third = context.div(&BigDecimal::one(), &BigDecimal::from(3));
+/// assert_eq!("0.333333333333333333333333333333", third.to_string());
+/// let floor = context.with_rounding(RoundingMode::Floor);
+/// assert_eq!("-0.333333333333333333333333333334", floor.div(&BigDecimal::from(-1), &BigDecimal::from(3)).to_string());
+/// ```
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct DecimalContext {
+    precision: u32,
+    rounding: RoundingMode,
+}
+
+impl DecimalContext {
+    /// returns a context of `precision` significant digits which rounds half to even
+    ///
+    /// # Panics
+    /// Panics if `precision` is zero.
+    pub fn new(precision: u32) -> Self {
+        assert!(precision > 0, "a precision of zero digits");
+        DecimalContext { precision, rounding: RoundingMode::HalfEven }
+    }
+    /// returns the context with another rounding mode
+    pub fn with_rounding(self, rounding: RoundingMode) -> Self {
+        DecimalContext { rounding, ..self }
+    }
+    /// returns the number of significant digits
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+    /// returns the rounding mode
+    pub fn rounding(&self) -> RoundingMode {
+        self.rounding
+    }
+
+    /// returns the number rounded to the precision
+    pub fn round(&self, value: &BigDecimal) -> BigDecimal {
+        round_digits(value.unscaled.clone(), value.exponent, false, self.precision as u64, self.rounding)
+    }
+    /// returns `a + b` rounded to the precision
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigDecimal, DecimalContext, RoundingMode};
+    /// let context = DecimalContext::new(5).with_rounding(RoundingMode::TowardZero);
+    /// let large = BigDecimal::from(10_000);
+    /// let tiny : BigDecimal = "-1e-1000000000".parse().unwrap();
+    /// assert_eq!("9999.9", context.add(&large, &tiny).to_string());
+    /// ```
+    pub fn add(&self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        if a.is_zero() || b.is_zero() {
+            return self.round(&(a + b))
+        }
+        let (large, small) = if a.adjusted() >= b.adjusted() { (a, b) } else { (b, a) };
+        // the large number is a multiple of 10^last, and no rounding boundary lies between
+        // two such multiples, so a smaller summand only decides the side
+        let last = large.exponent.min(large.adjusted() - self.precision as i64 - 2);
+        if small.adjusted() < last {
+            let side = BigDecimal { unscaled: BigInt::from(small.signum()), exponent: last - 1 };
+            return self.round(&(large + &side))
+        }
+        self.round(&(a + b))
+    }
+    /// returns `a - b` rounded to the precision
+    pub fn sub(&self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        self.add(a, &-b)
+    }
+    /// returns `a · b` rounded to the precision
+    pub fn mul(&self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        self.round(&(a * b))
+    }
+    /// returns `a / b` rounded to the precision
+    ///
+    /// # Panics
+    /// Panics if `b` is zero.
+    pub fn div(&self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        assert!(!b.is_zero(), "division by zero");
+        if a.is_zero() {
+            return BigDecimal::zero()
+        }
+        // enough digits for a quotient of `precision + 1` digits
+        let shift = (self.precision as i64 + 1 + b.digits() as i64 - a.digits() as i64).max(0);
+        let (quotient, remainder) = shifted(&a.unscaled, shift).div_rem(&b.unscaled);
+        round_digits(quotient, a.exponent - b.exponent - shift, !remainder.is_zero(), self.precision as u64, self.rounding)
+    }
+    /// returns the square root rounded to the precision
+    ///
+    /// # Panics
+    /// Panics if the number is negative.
+    pub fn sqrt(&self, value: &BigDecimal) -> BigDecimal {
+        assert!(!value.is_negative(), "square root of a negative number");
+        if value.is_zero() {
+            return BigDecimal::zero()
+        }
+        // enough digits for a root of `precision + 1` digits and an even exponent
+        let mut shift = (2 * self.precision as i64 + 2 - value.digits() as i64).max(0);
+        if (value.exponent - shift) % 2 != 0 {
+            shift += 1;
+        }
+        let square = shifted(&value.unscaled, shift).into_magnitude();
+        let root = square.sqrt();
+        let exact = &root * &root == square;
+        round_digits(BigInt::from(root), (value.exponent - shift) / 2, !exact, self.precision as u64, self.rounding)
+    }
+    /// returns π rounded to the precision
+    pub fn pi(&self) -> BigDecimal {
+        self.settle(|scale| {
+            let (pi, error) = pi_fixed(scale);
+            (pi, -(scale as i64), error)
+        })
+    }
+    /// returns `e^value` rounded to the precision
+    ///
+    /// # Panics
+    /// Panics if `|value|` is `10^18` or more, so the exponent of the result would overflow.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigDecimal, DecimalContext};
+    /// let context = DecimalContext::new(20);
+    /// assert_eq!("2.7182818284590452354", context.exp(&BigDecimal::one()).to_string());
+    /// assert_eq!("1.9700711140170469939e434", format!("{:e}", context.exp(&BigDecimal::from(1000))));
+    /// assert_eq!("5.0759588975494567653e-435", format!("{:e}", context.exp(&BigDecimal::from(-1000))));
+    /// ```
+    pub fn exp(&self, value: &BigDecimal) -> BigDecimal {
+        if value.is_zero() {
+            return BigDecimal::one()
+        }
+        assert!(value.adjusted() < 18, "exponent overflow");
+        let integer_digits = (value.adjusted() + 1).max(0) as u64;
+        self.settle(|scale| {
+            let halvings = halvings(scale);
+            let scale = amplified(scale, halvings) + integer_digits;
+            let one = BigInt::from(ten_pow(scale));
+            let (ln10, ln10_error) = ln10_fixed(scale);
+            let x = shifted(&value.unscaled, value.exponent + scale as i64);
+            // x = n·ln 10 + r with |r| ≤ ln 10 / 2, so e^x = 10^n · e^r
+            let n = ((&x << 1) + &ln10).div_euclid(&(&ln10 << 1));
+            let r = &x - &n * &ln10;
+            let n = i64::try_from(n).expect("exponent overflow");
+            let mut error = 2.0 + n.unsigned_abs() as f64 * ln10_error;
+            // e^r = (e^(r / 2^k))^(2^k)
+            let s = r >> halvings;
+            let bound = 1.2 / (1u64 << halvings) as f64;
+            error = error / (1u64 << halvings) as f64 + 1.0;
+            let mut term = one.clone();
+            let mut sum = one.clone();
+            let mut terms = 0u64;
+            while !term.is_zero() {
+                terms += 1;
+                term = &term * &s / (&one * BigInt::from(terms));
+                sum += &term;
+            }
+            error = error * bound.exp() + 2.0 * terms as f64 + 2.0;
+            for squaring in 0..halvings {
+                sum = &sum * &sum / &one;
+                error = 2.0 * (bound * (1u64 << squaring) as f64).exp() * error + 1.0;
+            }
+            (sum, n - scale as i64, error)
+        })
+    }
+    /// returns the natural logarithm rounded to the precision
+    ///
+    /// # Panics
+    /// Panics if the number is not positive.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigDecimal, DecimalContext};
+    /// let context = DecimalContext::new(20);
+    /// assert_eq!("2.302585092994045684", context.ln(&BigDecimal::from(10)).to_string());
+    /// assert_eq!("-2302.585092994045684", context.ln(&"1e-1000".parse().unwrap()).to_string());
+    /// assert_eq!("9.99999999999999999995e-21", format!("{:e}", DecimalContext::new(21).ln(&"1.00000000000000000001".parse().unwrap())));
+    /// ```
+    pub fn ln(&self, value: &BigDecimal) -> BigDecimal {
+        assert!(value.signum() > 0, "logarithm of a non-positive number");
+        if *value == BigDecimal::one() {
+            return BigDecimal::zero()
+        }
+        // value = m · 10^power with 1 ≤ m < 10
+        let power = value.adjusted();
+        let power_digits = power.unsigned_abs().checked_ilog10().map_or(0, |digits| digits as u64 + 1);
+        self.settle(|scale| {
+            let halvings = halvings(scale);
+            let scale = amplified(scale, halvings + 1) + power_digits;
+            let one = BigInt::from(ten_pow(scale));
+            // ln m = 2^k · ln(m^(1 / 2^k))
+            let mut y = shifted(&value.unscaled, value.exponent - power + scale as i64);
+            for _ in 0..halvings {
+                y = BigInt::from((y.magnitude() * one.magnitude()).sqrt());
+            }
+            // ln y = 2 · atanh((y - 1) / (y + 1))
+            let z = (&y - &one) * &one / (&y + &one);
+            let z2 = &z * &z / &one;
+            let mut term = z.clone();
+            let mut sum = z;
+            let mut terms = 0u64;
+            while !term.is_zero() {
+                terms += 1;
+                term = &term * &z2 / &one;
+                sum += &term / BigInt::from(2 * terms + 1);
+            }
+            let mut error = (3.0 * terms as f64 + 7.0) * (2u64 << halvings) as f64;
+            let (ln10, ln10_error) = ln10_fixed(scale);
+            error += power.unsigned_abs() as f64 * ln10_error;
+            ((sum << (halvings + 1)) + ln10 * BigInt::from(power), -(scale as i64), error)
+        })
+    }
+    /// returns the sine of radians rounded to the precision
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigDecimal, DecimalContext};
+    /// let context = DecimalContext::new(20);
+    /// assert_eq!("0.84147098480789650665", context.sin(&BigDecimal::one()).to_string());
+    /// assert_eq!("-0.000030144353359488449214", context.sin(&BigDecimal::from(355)).to_string());
+    /// assert_eq!("-0.85220084976718880177", context.sin(&"1e22".parse().unwrap()).to_string());
+    /// ```
+    pub fn sin(&self, value: &BigDecimal) -> BigDecimal {
+        if value.is_zero() {
+            return BigDecimal::zero()
+        }
+        // sin x is about x for small x, which needs as many more decimals
+        let small = (-value.adjusted()).max(0) as u64;
+        self.settle(|scale| sine(value, scale + small, false))
+    }
+    /// returns the cosine of radians rounded to the precision
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigDecimal, DecimalContext};
+    /// let context = DecimalContext::new(20);
+    /// assert_eq!("0.5403023058681397174", context.cos(&BigDecimal::one()).to_string());
+    /// assert_eq!("-0.99999999954565898017", context.cos(&BigDecimal::from(355)).to_string());
+    /// ```
+    pub fn cos(&self, value: &BigDecimal) -> BigDecimal {
+        if value.is_zero() {
+            return BigDecimal::one()
+        }
+        self.settle(|scale| sine(value, scale, true))
+    }
+    /// returns the arctangent in radians rounded to the precision
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigDecimal, DecimalContext, RoundingMode};
+    /// let context = DecimalContext::new(20);
+    /// assert_eq!("0.78539816339744830962", context.atan(&BigDecimal::one()).to_string());
+    /// assert_eq!("-1.5707963267948966192", context.atan(&"-1e30".parse().unwrap()).to_string());
+    /// assert_eq!("1e-30", format!("{:e}", context.atan(&"1e-30".parse().unwrap())));
+    /// let floor = context.with_rounding(RoundingMode::Floor);
+    /// assert_eq!("9.9999999999999999999e-31", format!("{:e}", floor.atan(&"1e-30".parse().unwrap())));
+    /// ```
+    pub fn atan(&self, value: &BigDecimal) -> BigDecimal {
+        if value.is_zero() {
+            return BigDecimal::zero()
+        }
+        // atan x is about x for small x, which needs as many more decimals
+        let small = (-value.adjusted()).max(0) as u64;
+        self.settle(|scale| {
+            let halvings = halvings(scale);
+            let scale = amplified(scale, halvings) + small;
+            let one = BigInt::from(ten_pow(scale));
+            let magnitude = value.abs();
+            // atan x = π/2 - atan(1/x)
+            let inverted = magnitude > BigDecimal::one();
+            let mut a = if inverted {
+                reciprocal(&magnitude, scale)
+            } else {
+                shifted(&magnitude.unscaled, magnitude.exponent + scale as i64)
+            };
+            let mut error = 1.0;
+            // atan a = 2 · atan(a / (1 + √(1 + a²)))
+            for _ in 0..halvings {
+                let root = BigInt::from((&one * &one + &a * &a).into_magnitude().sqrt());
+                a = &a * &one / (&one + root);
+                error = error / 2.0 + 2.0;
+            }
+            let a2 = &a * &a / &one;
+            let mut term = a.clone();
+            let mut sum = a;
+            let mut terms = 0u64;
+            while !term.is_zero() {
+                terms += 1;
+                term = -(&term * &a2 / &one);
+                sum += &term / BigInt::from(2 * terms + 1);
+            }
+            let mut result = sum << halvings;
+            error = (2.0 * error + 3.0 * terms as f64 + 3.0) * (1u64 << halvings) as f64;
+            if inverted {
+                let (pi, pi_error) = pi_fixed(scale);
+                result = (pi >> 1) - result;
+                error += pi_error / 2.0 + 1.0;
+            }
+            if value.is_negative() {
+                result = -result;
+            }
+            (result, -(scale as i64), error)
+        })
+    }
+
+    // rounds an approximation `(value, exponent, error)` of a result within `error · 10^exponent`,
+    // which is computed with `precision + guard` digits, and retries with more guard digits until
+    // the lowest and the highest possible result round alike
+    fn settle<F: Fn(u64) -> (BigInt, i64, f64)>(&self, approximate: F) -> BigDecimal {
+        let mut guard = 8;
+        loop {
+            let (value, exponent, error) = approximate(self.precision as u64 + guard);
+            let error = BigInt::from(error.ceil() as u128) + BigInt::one();
+            let low = self.round(&BigDecimal::new(&value - &error, exponent));
+            let high = self.round(&BigDecimal::new(value + error, exponent));
+            if low == high {
+                return low
+            }
+            guard *= 2;
+        }
+    }
+}
+
+// 10^exponent
+fn ten_pow(exponent: u64) -> BigUint {
+    BigUint::from(10u8).pow(u32::try_from(exponent).expect("exponent overflow"))
+}
+
+// the number of decimal digits, 0 for zero
+fn decimal_digits(value: &BigUint) -> u64 {
+    if value.is_zero() {
+        return 0
+    }
+    // bits · log10(2) is one or two less than the digits, the float may miss by one
+    let mut digits = ((value.bits() - 1) as f64 * std::f64::consts::LOG10_2) as u64;
+    while *value >= ten_pow(digits) {
+        digits += 1;
+    }
+    digits
+}
+
+// trunc(unscaled · 10^shift)
+fn shifted(unscaled: &BigInt, shift: i64) -> BigInt {
+    if shift >= 0 {
+        unscaled * BigInt::from(ten_pow(shift as u64))
+    } else if shift.unsigned_abs() > decimal_digits(unscaled.magnitude()) {
+        BigInt::zero()
+    } else {
+        unscaled / BigInt::from(ten_pow(shift.unsigned_abs()))
+    }
+}
+
+// trunc(10^scale / value) for a positive value
+fn reciprocal(value: &BigDecimal, scale: u64) -> BigInt {
+    match scale as i64 - value.exponent {
+        shift if shift < 0  =>  BigInt::zero(),
+        shift               =>  BigInt::from(ten_pow(shift as u64)) / &value.unscaled
+    }
+}
+
+// rounds `unscaled · 10^exponent`, or a number a little larger in magnitude if `sticky`,
+// to a multiple of `10^target`
+fn round_at(unscaled: BigInt, exponent: i64, sticky: bool, target: i64, rounding: RoundingMode) -> BigDecimal {
+    if target <= exponent && !sticky {
+        return BigDecimal::new(unscaled, exponent)
+    }
+    let negative = unscaled.is_negative();
+    let mut magnitude = unscaled.into_magnitude();
+    let mut exponent = exponent;
+    if target <= exponent {
+        // one more digit, so the sticky part lies below it
+        magnitude *= ten_pow((exponent - target + 1) as u64);
+        exponent = target - 1;
+    }
+    let drop = (target - exponent) as u64;
+    let (quotient, half, inexact) = if drop > decimal_digits(&magnitude) + 1 {
+        // less than a tenth of 10^target
+        (BigUint::zero(), Ordering::Less, sticky || !magnitude.is_zero())
+    } else {
+        let divisor = ten_pow(drop);
+        let (quotient, remainder) = magnitude.div_rem(&divisor);
+        let half = match (&remainder << 1).cmp(&divisor) {
+            Ordering::Equal if sticky   =>  Ordering::Greater,
+            half                        =>  half
+        };
+        let inexact = sticky || !remainder.is_zero();
+        (quotient, half, inexact)
+    };
+    let up = match rounding {
+        RoundingMode::HalfEven      =>  half == Ordering::Greater || half == Ordering::Equal && quotient.is_odd(),
+        RoundingMode::HalfUp        =>  half != Ordering::Less,
+        RoundingMode::TowardZero    =>  false,
+        RoundingMode::Ceiling       =>  inexact && !negative,
+        RoundingMode::Floor         =>  inexact && negative
+    };
+    let quotient = if up { quotient + BigUint::one() } else { quotient };
+    BigDecimal::new(BigInt::from_biguint(negative, quotient), target)
+}
+
+// rounds `unscaled · 10^exponent`, or a number a little larger in magnitude if `sticky`,
+// to `precision` significant digits
+fn round_digits(unscaled: BigInt, exponent: i64, sticky: bool, precision: u64, rounding: RoundingMode) -> BigDecimal {
+    if unscaled.is_zero() {
+        return BigDecimal::zero()
+    }
+    let digits = decimal_digits(unscaled.magnitude()) as i64;
+    round_at(unscaled, exponent, sticky, exponent + digits - precision as i64, rounding)
+}
+
+// the number of argument halvings before a series, which trades series terms for squarings
+fn halvings(scale: u64) -> u64 {
+    ((scale as f64).sqrt() as u64).clamp(1, 40)
+}
+
+// the scale which keeps `scale` decimals after an error is multiplied by 2^halvings
+fn amplified(scale: u64, halvings: u64) -> u64 {
+    scale + (halvings + 1) * 302 / 1000 + 2
+}
+
+// atan(1/q) or atanh(1/q) · 10^scale with its error in units of the last place
+fn arctan_inverse(q: u64, scale: u64, hyperbolic: bool) -> (BigInt, f64) {
+    let q2 = BigInt::from(q * q);
+    let mut power = BigInt::from(ten_pow(scale)) / BigInt::from(q);
+    let mut sum = power.clone();
+    let mut terms = 0u64;
+    while !power.is_zero() {
+        terms += 1;
+        power /= &q2;
+        let term = &power / BigInt::from(2 * terms + 1);
+        if hyperbolic || terms.is_multiple_of(2) { sum += term } else { sum -= term }
+    }
+    (sum, 2.0 * terms as f64 + 3.0)
+}
+
+// ln 10 = 6 · atanh(1/3) + 2 · atanh(1/9), which are 3 · ln 2 and ln 1.25
+fn ln10_fixed(scale: u64) -> (BigInt, f64) {
+    let (third, third_error) = arctan_inverse(3, scale, true);
+    let (ninth, ninth_error) = arctan_inverse(9, scale, true);
+    (third * BigInt::from(6u8) + (ninth << 1), 6.0 * third_error + 2.0 * ninth_error)
+}
+
+// π = 16 · atan(1/5) - 4 · atan(1/239)
+fn pi_fixed(scale: u64) -> (BigInt, f64) {
+    let (fifth, fifth_error) = arctan_inverse(5, scale, false);
+    let (other, other_error) = arctan_inverse(239, scale, false);
+    ((fifth << 4) - (other << 2), 16.0 * fifth_error + 4.0 * other_error)
+}
+
+// sin x or cos x · 10^scale with its error, after reducing x to |x - n·π/2| ≤ π/4
+fn sine(value: &BigDecimal, scale: u64, cosine: bool) -> (BigInt, i64, f64) {
+    let integer_digits = (value.adjusted() + 1).max(0) as u64;
+    let scale = scale + integer_digits + 3;
+    let one = BigInt::from(ten_pow(scale));
+    let (pi, pi_error) = pi_fixed(scale);
+    let x = shifted(&value.unscaled, value.exponent + scale as i64);
+    // n = round(2x / π)
+    let n = ((&x << 2) + &pi).div_euclid(&(&pi << 1));
+    let r = ((&x << 1) - &n * &pi) >> 1;
+    let r_error = 2.0 + n.magnitude().to_f64() * pi_error / 2.0;
+    let quadrant = u8::try_from(n.rem_euclid(&BigInt::from(4u8))).unwrap_or(0);
+    // sin x is sin r, cos r, -sin r, -cos r and cos x is cos r, -sin r, -cos r, sin r by quadrant
+    let (mut term, mut index) = if cosine == (quadrant % 2 == 0) { (one.clone(), 1u64) } else { (r.clone(), 2) };
+    let negative = if cosine { quadrant == 1 || quadrant == 2 } else { quadrant >= 2 };
+    let r2 = &r * &r / &one;
+    let mut sum = term.clone();
+    let mut terms = 0u64;
+    while !term.is_zero() {
+        terms += 1;
+        term = -(&term * &r2 / (&one * BigInt::from(index * (index + 1))));
+        sum += &term;
+        index += 2;
+    }
+    let error = 2.0 * r_error + 3.0 * terms as f64 + 3.0;
+    (if negative { -sum } else { sum }, -(scale as i64), error)
+}
+
+impl std::cmp::PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for BigDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sign = self.signum().cmp(&other.signum());
+        if sign != Ordering::Equal || self.is_zero() {
+            return sign
+        }
+        // the first digits decide before the digits are aligned
+        let magnitude = self.adjusted().cmp(&other.adjusted());
+        let magnitude = if magnitude == Ordering::Equal {
+            let exponent = self.exponent.min(other.exponent);
+            let a = shifted(&self.unscaled.abs(), self.exponent - exponent);
+            let b = shifted(&other.unscaled.abs(), other.exponent - exponent);
+            a.cmp(&b)
+        } else {
+            magnitude
+        };
+        if self.is_negative() { magnitude.reverse() } else { magnitude }
+    }
+}
+
+// the most zeros `Display` pads a number with before it switches to scientific notation
+const PLAIN_ZEROS: i64 = 1000;
+
+// the digits of `digits · 10^exponent` with at least `decimals` decimals
+fn fixed_text(digits: &str, exponent: i64, decimals: usize) -> String {
+    if exponent >= 0 {
+        let mut text = digits.to_string() + &"0".repeat(exponent as usize);
+        if decimals > 0 {
+            text.push('.');
+            text.push_str(&"0".repeat(decimals));
+        }
+        return text
+    }
+    let fraction = exponent.unsigned_abs() as usize;
+    let padded = if digits.len() <= fraction { "0".repeat(fraction + 1 - digits.len()) + digits } else { digits.to_string() };
+    let (integer, fraction) = padded.split_at(padded.len() - fraction);
+    format!("{}.{}{}",integer,fraction,"0".repeat(decimals.saturating_sub(fraction.len())))
+}
+
+impl BigDecimal {
+    // the number in scientific notation, with `precision` decimals rounded half to even
+    fn scientific(&self, f: &mut std::fmt::Formatter<'_>, e: char) -> std::result::Result<(), std::fmt::Error> {
+        let value = match f.precision() {
+            Some(decimals)  =>  round_digits(self.unscaled.clone(), self.exponent, false, decimals as u64 + 1, RoundingMode::HalfEven),
+            None            =>  self.clone()
+        };
+        let digits = value.unscaled.magnitude().to_string();
+        // wider than `i64`, so the exponent of a number near the largest exponent does not overflow
+        let adjusted = if value.is_zero() { 0 } else { value.exponent as i128 + digits.len() as i128 - 1 };
+        let decimals = f.precision().unwrap_or(0).max(digits.len() - 1);
+        let mut text = digits[..1].to_string();
+        if decimals > 0 {
+            text.push('.');
+            text.push_str(&digits[1..]);
+            text.push_str(&"0".repeat(decimals + 1 - digits.len()));
+        }
+        f.pad_integral(!value.is_negative(), "", &format!("{}{}{}",text,e,adjusted))
+    }
+}
+
+impl std::fmt::Display for BigDecimal {
+    /// writes the number without exponent, `{:.2}` rounds half to even to two decimals,
+    /// a number which needs more than 1000 zeros is written like `{:e}`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let value = match f.precision() {
+            Some(decimals)  =>  self.round_decimals(decimals as i64, RoundingMode::HalfEven),
+            None            =>  self.clone()
+        };
+        // a precision asks for its zeros, so only the zeros after the digits of a large number count then
+        if value.exponent > PLAIN_ZEROS || (f.precision().is_none() && value.adjusted() < -PLAIN_ZEROS) {
+            return value.scientific(f, 'e')
+        }
+        let digits = value.unscaled.magnitude().to_string();
+        f.pad_integral(!value.is_negative(), "", &fixed_text(&digits, value.exponent, f.precision().unwrap_or(0)))
+    }
+}
+impl std::fmt::Debug for BigDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+impl std::fmt::LowerExp for BigDecimal {
+    /// writes the number with one digit before the decimal point, like `1.25e-30`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        self.scientific(f, 'e')
+    }
+}
+impl std::fmt::UpperExp for BigDecimal {
+    /// writes the number with one digit before the decimal point, like `1.25E-30`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        self.scientific(f, 'E')
+    }
+}
+
+impl std::str::FromStr for BigDecimal {
+    type Err = BigDecimalError;
+    /// parses a decimal number with an optional sign and exponent, like `-12.5`, `.5` or `6.02214076e23`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigDecimal, BigDecimalError};
+    /// assert_eq!(Ok(BigDecimal::from(602_214_076) * BigDecimal::from(10).pow(15)), "6.02214076e23".parse());
+    /// assert_eq!(Ok(BigDecimal::from(-5)), "−5.000".parse());
+    /// assert_eq!(Err(BigDecimalError::Unexpected(2, ',')), "12,5".parse::<BigDecimal>());
+    /// assert_eq!(Err(BigDecimalError::UnexpectedEnd(4)), "1.5e".parse::<BigDecimal>());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, BigDecimalError> {
+        let start = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(BigDecimalError::Empty)
+        }
+        let unsigned = ['-', '−'].iter().find_map(|&sign| trimmed.strip_prefix(sign));
+        let negative = unsigned.is_some();
+        let unsigned = unsigned.unwrap_or_else(|| trimmed.strip_prefix('+').unwrap_or(trimmed));
+        let offset = start + trimmed.len() - unsigned.len();
+        let end = unsigned.find(['e', 'E']).unwrap_or(unsigned.len());
+        let (mantissa, exponent_text) = unsigned.split_at(end);
+        let mut digits = String::with_capacity(mantissa.len());
+        let mut decimals = None;
+        for (index, c) in mantissa.char_indices() {
+            match (c, decimals) {
+                ('0'..='9', _)      =>  {
+                    digits.push(c);
+                    decimals = decimals.map(|decimals| decimals + 1);
+                },
+                ('.', None)         =>  decimals = Some(0i64),
+                _                   =>  return Err(BigDecimalError::Unexpected(offset + index, c))
+            }
+        }
+        if digits.is_empty() {
+            return Err(match exponent_text.chars().next() {
+                Some(c) =>  BigDecimalError::Unexpected(offset + end, c),
+                None    =>  BigDecimalError::UnexpectedEnd(offset + end)
+            })
+        }
+        let mut exponent = 0i64;
+        if let Some(power) = exponent_text.get(1..) {
+            let unsigned = ['-', '−'].iter().find_map(|&sign| power.strip_prefix(sign));
+            let negative = unsigned.is_some();
+            let unsigned = unsigned.unwrap_or_else(|| power.strip_prefix('+').unwrap_or(power));
+            let offset = offset + end + 1 + power.len() - unsigned.len();
+            if unsigned.is_empty() {
+                return Err(BigDecimalError::UnexpectedEnd(offset))
+            }
+            for (index, c) in unsigned.char_indices() {
+                let digit = c.to_digit(10).ok_or(BigDecimalError::Unexpected(offset + index, c))?;
+                exponent = exponent.checked_mul(10).and_then(|exponent| exponent.checked_add(digit as i64)).ok_or(BigDecimalError::OutOfRange)?;
+            }
+            if negative {
+                exponent = -exponent;
+            }
+        }
+        let exponent = exponent.checked_sub(decimals.unwrap_or(0)).ok_or(BigDecimalError::OutOfRange)?;
+        // the trailing zeros move into the exponent here, where its overflow is an error
+        let significant = digits.trim_end_matches('0').len().max(1);
+        let exponent = exponent.checked_add((digits.len() - significant) as i64).ok_or(BigDecimalError::OutOfRange)?;
+        digits.truncate(significant);
+        let magnitude = BigUint::from_str_radix(&digits, 10).map_err(|_| BigDecimalError::Empty)?;
+        Ok(BigDecimal::new(BigInt::from_biguint(negative, magnitude), exponent))
+    }
+}
+
+// the exact operators for owned and borrowed numbers
+macro_rules! impl_decimal_ops {
+    ($($trait:ident, $method:ident, $assign:ident, $assign_method:ident, $core:ident;)*) => {
+        $(
+            impl std::ops::$trait<&BigDecimal> for &BigDecimal {
+                type Output = BigDecimal;
+                fn $method(self, other: &BigDecimal) -> BigDecimal {
+                    self.$core(other)
+                }
+            }
+            impl std::ops::$trait<BigDecimal> for &BigDecimal {
+                type Output = BigDecimal;
+                fn $method(self, other: BigDecimal) -> BigDecimal {
+                    self.$core(&other)
+                }
+            }
+            impl std::ops::$trait<&BigDecimal> for BigDecimal {
+                type Output = BigDecimal;
+                fn $method(self, other: &BigDecimal) -> BigDecimal {
+                    self.$core(other)
+                }
+            }
+            impl std::ops::$trait for BigDecimal {
+                type Output = BigDecimal;
+                fn $method(self, other: BigDecimal) -> BigDecimal {
+                    self.$core(&other)
+                }
+            }
+            impl std::ops::$assign<&BigDecimal> for BigDecimal {
+                fn $assign_method(&mut self, other: &BigDecimal) {
+                    *self = self.$core(other);
+                }
+            }
+            impl std::ops::$assign for BigDecimal {
+                fn $assign_method(&mut self, other: BigDecimal) {
+                    *self = self.$core(&other);
+                }
+            }
+        )*
+    };
+}
+impl_decimal_ops! {
+    Add, add, AddAssign, add_assign, add_decimal;
+    Sub, sub, SubAssign, sub_assign, sub_decimal;
+    Mul, mul, MulAssign, mul_assign, mul_decimal;
+}
+
+impl std::ops::Neg for &BigDecimal {
+    type Output = BigDecimal;
+    fn neg(self) -> BigDecimal {
+        BigDecimal { unscaled: -&self.unscaled, exponent: self.exponent }
+    }
+}
+impl std::ops::Neg for BigDecimal {
+    type Output = BigDecimal;
+    fn neg(self) -> BigDecimal {
+        BigDecimal { unscaled: -self.unscaled, exponent: self.exponent }
+    }
+}
+
+impl std::iter::Sum for BigDecimal {
+    fn sum<I: Iterator<Item = BigDecimal>>(iter: I) -> Self {
+        iter.fold(BigDecimal::zero(), |sum, value| sum + value)
+    }
+}
+impl std::iter::Product for BigDecimal {
+    fn product<I: Iterator<Item = BigDecimal>>(iter: I) -> Self {
+        iter.fold(BigDecimal::one(), |product, value| product * value)
+    }
+}
+
+impl std::convert::From<BigInt> for BigDecimal {
+    fn from(integer: BigInt) -> Self {
+        BigDecimal::new(integer, 0)
+    }
+}
+impl std::convert::From<BigUint> for BigDecimal {
+    fn from(integer: BigUint) -> Self {
+        BigDecimal::new(BigInt::from(integer), 0)
+    }
+}
+impl std::convert::TryFrom<BigDecimal> for BigInt {
+    type Error = BigIntError;
+    /// converts a number without fractional digits
+    fn try_from(value: BigDecimal) -> Result<Self, BigIntError> {
+        if !value.is_integer() {
+            return Err(BigIntError::OutOfRange)
+        }
+        Ok(shifted(&value.unscaled, value.exponent))
+    }
+}
+
+// the conversions from the primitive integers
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl std::convert::From<$integer> for BigDecimal {
+                fn from(integer: $integer) -> Self {
+                    BigDecimal::new(BigInt::from(integer), 0)
+                }
+            }
+        )*
+    };
+}
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
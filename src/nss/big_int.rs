@@ -0,0 +1,1522 @@
+use super::rational::{sealed::Sealed, Integer};
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+// operands with fewer limbs are multiplied by schoolbook
+const KARATSUBA_THRESHOLD : usize = 32;
+// operands with at least as many limbs are multiplied by Toom-3
+const TOOM3_THRESHOLD : usize = 192;
+
+/// an error of parsing or converting a [`BigUint`] or [`BigInt`]
+///
+/// The offsets are byte offsets into the text.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum BigIntError {
+    /// the text is empty or only whitespace
+    Empty,
+    /// a character which is no digit of the radix, as `(offset, char)`
+    Unexpected(usize, char),
+    /// the text ends before a digit, as `offset`
+    UnexpectedEnd(usize),
+    /// the number does not fit the target type
+    OutOfRange,
+}
+
+impl std::fmt::Display for BigIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            BigIntError::Empty                  =>  write!(f,"empty text"),
+            BigIntError::Unexpected(offset, c)  =>  write!(f,"unexpected {:?} at {}",c,offset),
+            BigIntError::UnexpectedEnd(offset)  =>  write!(f,"expected a digit at {}",offset),
+            BigIntError::OutOfRange             =>  write!(f,"number out of range")
+        }
+    }
+}
+impl std::error::Error for BigIntError {}
+
+/// a natural number of arbitrary size
+///
+/// Products of large numbers use Karatsuba and, for the largest, Toom-3 multiplication.
+/// The operators panic where the operators of the unsigned integers do,
+/// on subtracting a larger number and on dividing by zero.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::BigUint;
+/// let factorial : BigUint = (1..=30u32).map(BigUint::from).product();
+/// assert_eq!("265252859812191058636308480000000", factorial.to_string());
+/// let power = BigUint::from(2u8).pow(200);
+/// assert_eq!(201, power.bits());
+/// assert_eq!(BigUint::from(1u8) << 200, power);
+/// assert_eq!(BigUint::from(8u8), BigUint::from(1_000_000_007u64).pow(3) % BigUint::from(1_000_000_005u64));
+/// ```
+# [derive(Clone,PartialEq,Eq,Hash,Default)]
+pub struct BigUint {
+    // little-endian without leading zero limbs, so zero has none
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    /// returns `0`
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+    /// returns `1`
+    pub fn one() -> Self {
+        BigUint { limbs: vec![1] }
+    }
+    // the number of limbs which may have leading zero limbs
+    fn from_limbs(mut limbs: Vec<u64>) -> Self {
+        normalize(&mut limbs);
+        BigUint { limbs }
+    }
+    fn from_wide(value: u128) -> Self {
+        BigUint::from_limbs(vec![value as u64, (value >> 64) as u64])
+    }
+    fn to_wide(&self) -> Option<u128> {
+        match self.limbs[..] {
+            []              =>  Some(0),
+            [low]           =>  Some(low as u128),
+            [low, high]     =>  Some((high as u128) << 64 | low as u128),
+            _               =>  None
+        }
+    }
+    /// returns whether the number is zero
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+    /// returns whether the number is odd
+    pub fn is_odd(&self) -> bool {
+        self.limbs.first().is_some_and(|limb| limb & 1 == 1)
+    }
+    /// returns the number of bits without leading zeros, `0` for zero
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(last)  =>  64 * self.limbs.len() as u64 - last.leading_zeros() as u64,
+            None        =>  0
+        }
+    }
+    /// returns whether the bit is set
+    pub fn bit(&self, bit: u64) -> bool {
+        self.limbs.get((bit / 64) as usize).is_some_and(|limb| limb >> (bit % 64) & 1 == 1)
+    }
+    /// sets or clears the bit
+    pub fn set_bit(&mut self, bit: u64, value: bool) {
+        let index = (bit / 64) as usize;
+        if value {
+            if self.limbs.len() <= index {
+                self.limbs.resize(index + 1, 0);
+            }
+            self.limbs[index] |= 1 << (bit % 64);
+        } else if index < self.limbs.len() {
+            self.limbs[index] &= !(1 << (bit % 64));
+            normalize(&mut self.limbs);
+        }
+    }
+    /// returns the number of trailing zero bits, or `None` for zero
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        let index = self.limbs.iter().position(|&limb| limb != 0)?;
+        Some(64 * index as u64 + self.limbs[index].trailing_zeros() as u64)
+    }
+    /// returns the number of set bits
+    pub fn count_ones(&self) -> u64 {
+        self.limbs.iter().map(|limb| limb.count_ones() as u64).sum()
+    }
+    /// returns the number raised to a power
+    pub fn pow(&self, exponent: u32) -> BigUint {
+        let mut power = BigUint::one();
+        for bit in (0..32 - exponent.leading_zeros()).rev() {
+            power = &power * &power;
+            if exponent >> bit & 1 == 1 {
+                power = &power * self;
+            }
+        }
+        power
+    }
+    /// returns `self^exponent mod modulus`
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::BigUint;
+    /// let prime : BigUint = "170141183460469231731687303715884105727".parse().unwrap();
+    /// let exponent = &prime - BigUint::one();
+    /// assert_eq!(BigUint::one(), BigUint::from(3u8).pow_mod(&exponent, &prime));
+    /// ```
+    pub fn pow_mod(&self, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        assert!(!modulus.is_zero(), "attempt to calculate the remainder with a divisor of zero");
+        let base = self % modulus;
+        let mut power = BigUint::one() % modulus;
+        for bit in (0..exponent.bits()).rev() {
+            power = &power * &power % modulus;
+            if exponent.bit(bit) {
+                power = &power * &base % modulus;
+            }
+        }
+        power
+    }
+    /// returns the greatest integer whose square is less than or equal to the number
+    pub fn sqrt(&self) -> BigUint {
+        if self.is_zero() {
+            return BigUint::zero()
+        }
+        // Newton's method from above
+        let mut root = BigUint::one() << self.bits().div_ceil(2);
+        loop {
+            let next = (&root + self / &root) >> 1;
+            if next >= root {
+                return root
+            }
+            root = next;
+        }
+    }
+    /// returns the greatest common divisor, `0` if both are zero
+    pub fn gcd(&self, other: &BigUint) -> BigUint {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let rest = &a % &b;
+            a = b;
+            b = rest;
+        }
+        a
+    }
+    /// returns the quotient and remainder
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    pub fn div_rem(&self, other: &BigUint) -> (BigUint, BigUint) {
+        assert!(!other.is_zero(), "attempt to divide by zero");
+        let (quotient, remainder) = div_rem(&self.limbs, &other.limbs);
+        (BigUint::from_limbs(quotient), BigUint::from_limbs(remainder))
+    }
+    /// returns `self - other`, or `None` if `other` is larger
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if compare(&self.limbs, &other.limbs) == Ordering::Less {
+            return None
+        }
+        let mut difference = self.limbs.clone();
+        sub_assign(&mut difference, &other.limbs);
+        Some(BigUint { limbs: difference })
+    }
+    /// returns `self / other`, or `None` if `other` is zero
+    pub fn checked_div(&self, other: &BigUint) -> Option<BigUint> {
+        if other.is_zero() { None } else { Some(self.div_rem(other).0) }
+    }
+    /// returns `self % other`, or `None` if `other` is zero
+    pub fn checked_rem(&self, other: &BigUint) -> Option<BigUint> {
+        if other.is_zero() { None } else { Some(self.div_rem(other).1) }
+    }
+    /// returns the number in the given radix with lowercase letters
+    ///
+    /// # Panics
+    /// Panics when `radix` is not in `2..=36`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::BigUint;
+    /// let n = BigUint::from(u128::MAX) + BigUint::one();
+    /// assert_eq!("1".to_string() + &"0".repeat(128), n.to_str_radix(2));
+    /// assert_eq!("f5lxx1zz5pnorynqglhzmsp34", n.to_str_radix(36));
+    /// ```
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        check_radix(radix);
+        self.radix_digits(radix).iter().map(|&digit| std::char::from_digit(digit as u32, radix).unwrap_or('0')).collect()
+    }
+    // the digits in a radix of `2..=36`, the most significant first and `[0]` for zero
+    pub(super) fn radix_digits(&self, radix: u32) -> Vec<u8> {
+        if self.is_zero() {
+            return vec![0]
+        }
+        let (chunk, digits) = radix_chunk(radix);
+        let mut rest = self.limbs.clone();
+        let mut chunks = Vec::new();
+        while !rest.is_empty() {
+            chunks.push(div_small(&mut rest, chunk));
+        }
+        let mut all_digits = Vec::with_capacity(chunks.len() * digits);
+        for (i, &chunk) in chunks.iter().rev().enumerate() {
+            let mut chunk_digits = Vec::with_capacity(digits);
+            let mut chunk = chunk;
+            for _ in 0..digits {
+                chunk_digits.push((chunk % radix as u64) as u8);
+                chunk /= radix as u64;
+                // the first chunk has no leading zeros
+                if i == 0 && chunk == 0 {
+                    break
+                }
+            }
+            all_digits.extend(chunk_digits.iter().rev());
+        }
+        all_digits
+    }
+    /// returns the number of a text in the given radix, with an optional `+`
+    ///
+    /// # Panics
+    /// Panics when `radix` is not in `2..=36`.
+    ///
+    /// # Errors
+    /// Returns a `BigIntError` if the text has no digits or other characters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{BigIntError, BigUint};
+    /// assert_eq!(Ok(BigUint::from(u64::MAX)), BigUint::from_str_radix("ffffFFFFffffFFFF", 16));
+    /// assert_eq!(Err(BigIntError::Unexpected(2, '2')), BigUint::from_str_radix("10201", 2));
+    /// ```
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<BigUint, BigIntError> {
+        check_radix(radix);
+        let start = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(BigIntError::Empty)
+        }
+        let unsigned = trimmed.strip_prefix('+').unwrap_or(trimmed);
+        parse_digits(unsigned, start + trimmed.len() - unsigned.len(), radix)
+    }
+    /// returns the nearest `f64`, or infinity if the number is too large
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bits();
+        if bits <= 64 {
+            return self.limbs.first().map_or(0.0, |&limb| limb as f64)
+        }
+        // the top 64 bits, the last one set if any bit below is set, round like the whole number
+        let shift = bits - 64;
+        let top = shr(&self.limbs, shift)[0];
+        let sticky = self.trailing_zeros().is_some_and(|zeros| zeros < shift) as u64;
+        (top | sticky) as f64 * 2f64.powi(shift.min(2048) as i32)
+    }
+
+    fn add_big(&self, other: &BigUint) -> BigUint {
+        BigUint { limbs: add(&self.limbs, &other.limbs) }
+    }
+    fn sub_big(&self, other: &BigUint) -> BigUint {
+        self.checked_sub(other).expect("attempt to subtract with overflow")
+    }
+    fn mul_big(&self, other: &BigUint) -> BigUint {
+        BigUint { limbs: mul(&self.limbs, &other.limbs) }
+    }
+    fn div_big(&self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+    fn rem_big(&self, other: &BigUint) -> BigUint {
+        assert!(!other.is_zero(), "attempt to calculate the remainder with a divisor of zero");
+        self.div_rem(other).1
+    }
+    fn and_big(&self, other: &BigUint) -> BigUint {
+        BigUint::from_limbs(self.limbs.iter().zip(&other.limbs).map(|(a, b)| a & b).collect())
+    }
+    fn or_big(&self, other: &BigUint) -> BigUint {
+        BigUint { limbs: bitwise(&self.limbs, &other.limbs, |a, b| a | b) }
+    }
+    fn xor_big(&self, other: &BigUint) -> BigUint {
+        BigUint::from_limbs(bitwise(&self.limbs, &other.limbs, |a, b| a ^ b))
+    }
+}
+
+/// an integer of arbitrary size
+///
+/// The number is stored as sign and [`BigUint`] magnitude.
+/// Division rounds toward zero like the primitive integers,
+/// the bitwise operators and `>>` act as on an infinite two's complement, so `-1 >> 1` is `-1`.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{BigInt, Rational};
+/// let a : BigInt = "-123456789012345678901234567890".parse().unwrap();
+/// let b = BigInt::from(987_654_321u32);
+/// assert_eq!("-121932631124828532112482853211126352690", (&a * &b).to_string());
+/// assert_eq!(BigInt::from(-124_999), &a / &b / BigInt::from(1_000_000_000_000_000u64));
+/// assert_eq!(BigInt::from(-2), BigInt::from(-3) >> 1);
+/// assert_eq!(BigInt::from(-8 & 13), BigInt::from(-8) & BigInt::from(13));
+/// let half = Rational::new(BigInt::from(1), BigInt::from(2));
+/// assert_eq!("1/1267650600228229401496703205376", half.pow(100).to_string());
+/// ```
+# [derive(Clone,PartialEq,Eq,Hash,Default)]
+pub struct BigInt {
+    // never true for zero
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    /// returns `0`
+    pub fn zero() -> Self {
+        BigInt { negative: false, magnitude: BigUint::zero() }
+    }
+    /// returns `1`
+    pub fn one() -> Self {
+        BigInt { negative: false, magnitude: BigUint::one() }
+    }
+    /// returns the integer of the sign and magnitude
+    pub fn from_biguint(negative: bool, magnitude: BigUint) -> Self {
+        BigInt { negative: negative && !magnitude.is_zero(), magnitude }
+    }
+    fn from_wide(value: i128) -> Self {
+        BigInt::from_biguint(value < 0, BigUint::from_wide(value.unsigned_abs()))
+    }
+    fn to_wide(&self) -> Option<i128> {
+        let magnitude = self.magnitude.to_wide()?;
+        if self.negative {
+            if magnitude > 1 << 127 { None } else { Some((magnitude as i128).wrapping_neg()) }
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+    /// returns the absolute value
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+    /// returns the absolute value as `BigUint`
+    pub fn into_magnitude(self) -> BigUint {
+        self.magnitude
+    }
+    /// returns whether the integer is zero
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+    /// returns whether the integer is less than zero
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    /// returns whether the integer is greater than zero
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+    /// returns `-1`, `0` or `1` by the sign
+    pub fn signum(&self) -> i8 {
+        if self.negative { -1 } else if self.is_zero() { 0 } else { 1 }
+    }
+    /// returns the absolute value
+    pub fn abs(&self) -> BigInt {
+        BigInt { negative: false, magnitude: self.magnitude.clone() }
+    }
+    /// returns the number of bits of the magnitude
+    pub fn bits(&self) -> u64 {
+        self.magnitude.bits()
+    }
+    /// returns the integer raised to a power
+    pub fn pow(&self, exponent: u32) -> BigInt {
+        BigInt::from_biguint(self.negative && exponent % 2 == 1, self.magnitude.pow(exponent))
+    }
+    /// returns the quotient rounded toward zero, and the remainder with the sign of `self`
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    pub fn div_rem(&self, other: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, remainder) = self.magnitude.div_rem(&other.magnitude);
+        (BigInt::from_biguint(self.negative != other.negative, quotient), BigInt::from_biguint(self.negative, remainder))
+    }
+    /// returns the quotient rounded toward negative infinity if `other` is positive, so the remainder is never negative
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::BigInt;
+    /// let (a, b) = (BigInt::from(-7), BigInt::from(2));
+    /// assert_eq!((BigInt::from(-4), BigInt::from(1)), (a.div_euclid(&b), a.rem_euclid(&b)));
+    /// ```
+    pub fn div_euclid(&self, other: &BigInt) -> BigInt {
+        let (quotient, remainder) = self.div_rem(other);
+        if !remainder.negative {
+            quotient
+        } else if other.negative {
+            quotient + BigInt::one()
+        } else {
+            quotient - BigInt::one()
+        }
+    }
+    /// returns the remainder from `0` to `|other| - 1`
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    pub fn rem_euclid(&self, other: &BigInt) -> BigInt {
+        let remainder = self.rem_big(other);
+        if remainder.negative { remainder + other.abs() } else { remainder }
+    }
+    /// returns `self / other`, or `None` if `other` is zero
+    pub fn checked_div(&self, other: &BigInt) -> Option<BigInt> {
+        if other.is_zero() { None } else { Some(self.div_rem(other).0) }
+    }
+    /// returns `self % other`, or `None` if `other` is zero
+    pub fn checked_rem(&self, other: &BigInt) -> Option<BigInt> {
+        if other.is_zero() { None } else { Some(self.div_rem(other).1) }
+    }
+    /// returns the integer in the given radix with lowercase letters
+    ///
+    /// # Panics
+    /// Panics when `radix` is not in `2..=36`.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let digits = self.magnitude.to_str_radix(radix);
+        if self.negative { format!("-{}", digits) } else { digits }
+    }
+    /// returns the integer of a text in the given radix, with an optional `+`, `-` or `−`
+    ///
+    /// # Panics
+    /// Panics when `radix` is not in `2..=36`.
+    ///
+    /// # Errors
+    /// Returns a `BigIntError` if the text has no digits or other characters.
+    pub fn from_str_radix(text: &str, radix: u32) -> Result<BigInt, BigIntError> {
+        check_radix(radix);
+        let start = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(BigIntError::Empty)
+        }
+        let unsigned = ['-', '−'].iter().find_map(|&sign| trimmed.strip_prefix(sign));
+        let negative = unsigned.is_some();
+        let unsigned = unsigned.unwrap_or_else(|| trimmed.strip_prefix('+').unwrap_or(trimmed));
+        let magnitude = parse_digits(unsigned, start + trimmed.len() - unsigned.len(), radix)?;
+        Ok(BigInt::from_biguint(negative, magnitude))
+    }
+    /// returns the nearest `f64`, or an infinity if the integer is too large
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self.magnitude.to_f64();
+        if self.negative { -magnitude } else { magnitude }
+    }
+
+    fn add_big(&self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt { negative: self.negative, magnitude: self.magnitude.add_big(&other.magnitude) }
+        }
+        match self.magnitude.cmp(&other.magnitude) {
+            Ordering::Less      =>  BigInt::from_biguint(other.negative, other.magnitude.sub_big(&self.magnitude)),
+            _                   =>  BigInt::from_biguint(self.negative, self.magnitude.sub_big(&other.magnitude))
+        }
+    }
+    fn sub_big(&self, other: &BigInt) -> BigInt {
+        self.add_big(&-other)
+    }
+    fn mul_big(&self, other: &BigInt) -> BigInt {
+        BigInt::from_biguint(self.negative != other.negative, self.magnitude.mul_big(&other.magnitude))
+    }
+    fn div_big(&self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+    fn rem_big(&self, other: &BigInt) -> BigInt {
+        assert!(!other.is_zero(), "attempt to calculate the remainder with a divisor of zero");
+        self.div_rem(other).1
+    }
+    // the limbs of the two's complement, with at least one sign bit
+    fn twos_complement(&self, length: usize) -> Vec<u64> {
+        let mut limbs = self.magnitude.limbs.clone();
+        limbs.resize(length, 0);
+        if self.negative {
+            for limb in limbs.iter_mut() {
+                *limb = !*limb;
+            }
+            add_at(&mut limbs, &[1], 0);
+            limbs.truncate(length);
+        }
+        limbs
+    }
+    fn from_twos_complement(mut limbs: Vec<u64>) -> BigInt {
+        let negative = limbs.last().is_some_and(|limb| limb >> 63 == 1);
+        if negative {
+            for limb in limbs.iter_mut() {
+                *limb = !*limb;
+            }
+            add_at(&mut limbs, &[1], 0);
+        }
+        BigInt::from_biguint(negative, BigUint::from_limbs(limbs))
+    }
+    fn bitwise_big(&self, other: &BigInt, operation: fn(u64, u64) -> u64) -> BigInt {
+        let length = self.magnitude.limbs.len().max(other.magnitude.limbs.len()) + 1;
+        let (a, b) = (self.twos_complement(length), other.twos_complement(length));
+        BigInt::from_twos_complement(a.iter().zip(&b).map(|(&a, &b)| operation(a, b)).collect())
+    }
+    fn and_big(&self, other: &BigInt) -> BigInt {
+        self.bitwise_big(other, |a, b| a & b)
+    }
+    fn or_big(&self, other: &BigInt) -> BigInt {
+        self.bitwise_big(other, |a, b| a | b)
+    }
+    fn xor_big(&self, other: &BigInt) -> BigInt {
+        self.bitwise_big(other, |a, b| a ^ b)
+    }
+}
+
+// removes the leading zero limbs
+fn normalize(limbs: &mut Vec<u64>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+// the limbs without leading zero limbs
+fn trim(limbs: &[u64]) -> &[u64] {
+    &limbs[..limbs.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1)]
+}
+
+// compares limbs without leading zero limbs
+fn compare(a: &[u64], b: &[u64]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut sum = a.to_vec();
+    add_at(&mut sum, b, 0);
+    sum
+}
+
+// adds `b` shifted by `offset` limbs
+fn add_at(sum: &mut Vec<u64>, b: &[u64], offset: usize) {
+    if sum.len() < offset + b.len() {
+        sum.resize(offset + b.len(), 0);
+    }
+    let mut carry = false;
+    for (i, &limb) in b.iter().enumerate() {
+        let (partial, first) = sum[offset + i].overflowing_add(limb);
+        let (partial, second) = partial.overflowing_add(carry as u64);
+        sum[offset + i] = partial;
+        carry = first || second;
+    }
+    let mut i = offset + b.len();
+    while carry {
+        if i == sum.len() {
+            sum.push(0);
+        }
+        let (partial, overflow) = sum[i].overflowing_add(1);
+        sum[i] = partial;
+        carry = overflow;
+        i += 1;
+    }
+}
+
+// subtracts a smaller or equal `b`
+fn sub_assign(difference: &mut Vec<u64>, b: &[u64]) {
+    let mut borrow = false;
+    for (i, limb) in difference.iter_mut().enumerate() {
+        if i >= b.len() && !borrow {
+            break
+        }
+        let (partial, first) = limb.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (partial, second) = partial.overflowing_sub(borrow as u64);
+        *limb = partial;
+        borrow = first || second;
+    }
+    normalize(difference);
+}
+
+fn bitwise(a: &[u64], b: &[u64], operation: fn(u64, u64) -> u64) -> Vec<u64> {
+    (0..a.len().max(b.len())).map(|i| operation(a.get(i).copied().unwrap_or(0), b.get(i).copied().unwrap_or(0))).collect()
+}
+
+fn shl(a: &[u64], bits: u64) -> Vec<u64> {
+    if a.is_empty() {
+        return Vec::new()
+    }
+    let (limbs, rest) = ((bits / 64) as usize, (bits % 64) as u32);
+    let mut shifted = vec![0; limbs];
+    if rest == 0 {
+        shifted.extend_from_slice(a);
+    } else {
+        let mut carry = 0;
+        for &limb in a {
+            shifted.push(limb << rest | carry);
+            carry = limb >> (64 - rest);
+        }
+        if carry != 0 {
+            shifted.push(carry);
+        }
+    }
+    shifted
+}
+
+fn shr(a: &[u64], bits: u64) -> Vec<u64> {
+    let limbs = (bits / 64) as usize;
+    if limbs >= a.len() {
+        return Vec::new()
+    }
+    let (a, rest) = (&a[limbs..], (bits % 64) as u32);
+    let mut shifted : Vec<u64> = if rest == 0 {
+        a.to_vec()
+    } else {
+        (0..a.len()).map(|i| a[i] >> rest | a.get(i + 1).map_or(0, |&next| next << (64 - rest))).collect()
+    };
+    normalize(&mut shifted);
+    shifted
+}
+
+fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = (trim(a), trim(b));
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.is_empty() {
+        return Vec::new()
+    }
+    let mut product = if b.len() < KARATSUBA_THRESHOLD {
+        schoolbook(a, b)
+    } else if 2 * b.len() <= a.len() {
+        unbalanced(a, b)
+    } else if b.len() < TOOM3_THRESHOLD {
+        karatsuba(a, b)
+    } else {
+        toom3(a, b)
+    };
+    normalize(&mut product);
+    product
+}
+
+fn schoolbook(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = vec![0u64; a.len() + b.len()];
+    for (i, &x) in b.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in a.iter().enumerate() {
+            let partial = x as u128 * y as u128 + product[i + j] as u128 + carry;
+            product[i + j] = partial as u64;
+            carry = partial >> 64;
+        }
+        product[i + a.len()] = carry as u64;
+    }
+    product
+}
+
+// the product with an operand of at most half the length, in pieces of its length
+fn unbalanced(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = Vec::with_capacity(a.len() + b.len());
+    for (i, piece) in a.chunks(b.len()).enumerate() {
+        add_at(&mut product, &mul(piece, b), i * b.len());
+    }
+    product
+}
+
+// (a₁x + a₀)(b₁x + b₀) = a₁b₁x² + ((a₀ + a₁)(b₀ + b₁) − a₀b₀ − a₁b₁)x + a₀b₀
+fn karatsuba(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let half = a.len().div_ceil(2);
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half.min(b.len()));
+    let low = mul(a0, b0);
+    let high = mul(a1, b1);
+    let mut middle = mul(&add(a0, a1), &add(b0, b1));
+    sub_assign(&mut middle, &low);
+    sub_assign(&mut middle, &high);
+    let mut product = low;
+    add_at(&mut product, &middle, half);
+    add_at(&mut product, &high, 2 * half);
+    product
+}
+
+// evaluates both polynomials of three pieces at 0, 1, −1, −2 and ∞, and interpolates as Bodrato
+fn toom3(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let third = a.len().div_ceil(3);
+    let split = |limbs: &[u64]| -> [BigInt;3] {
+        let piece = |i: usize| limbs[(i * third).min(limbs.len())..((i + 1) * third).min(limbs.len())].to_vec();
+        [0, 1, 2].map(|i| BigInt::from(BigUint::from_limbs(piece(i))))
+    };
+    let evaluate = |[x0, x1, x2]: [BigInt;3]| -> [BigInt;5] {
+        let even = &x0 + &x2;
+        let minus_one = &even - &x1;
+        let minus_two = ((&minus_one + &x2) << 1) - &x0;
+        [x0, even + &x1, minus_one, minus_two, x2]
+    };
+    let (p, q) = (evaluate(split(a)), evaluate(split(b)));
+    let [r0, r1, minus_one, minus_two, infinity] = [0, 1, 2, 3, 4].map(|i| &p[i] * &q[i]);
+    let mut r3 = (minus_two - &r1) / BigInt::from(3);
+    let r1 = (r1 - &minus_one) >> 1;
+    let mut r2 = minus_one - &r0;
+    r3 = ((&r2 - r3) >> 1) + (&infinity << 1);
+    r2 = r2 + &r1 - &infinity;
+    let r1 = r1 - &r3;
+    let mut product = r0.magnitude.limbs;
+    for (i, coefficient) in [(1, r1), (2, r2), (3, r3), (4, infinity)] {
+        add_at(&mut product, &coefficient.magnitude.limbs, i * third);
+    }
+    product
+}
+
+// multiplies in place and adds
+fn mul_small(limbs: &mut Vec<u64>, factor: u64, addend: u64) {
+    let mut carry = addend as u128;
+    for limb in limbs.iter_mut() {
+        let partial = *limb as u128 * factor as u128 + carry;
+        *limb = partial as u64;
+        carry = partial >> 64;
+    }
+    if carry != 0 {
+        limbs.push(carry as u64);
+    }
+    normalize(limbs);
+}
+
+// divides in place and returns the remainder
+fn div_small(limbs: &mut Vec<u64>, divisor: u64) -> u64 {
+    let mut remainder = 0u128;
+    for limb in limbs.iter_mut().rev() {
+        let dividend = remainder << 64 | *limb as u128;
+        *limb = (dividend / divisor as u128) as u64;
+        remainder = dividend % divisor as u128;
+    }
+    normalize(limbs);
+    remainder as u64
+}
+
+// the quotient and remainder for a non-zero divisor without leading zero limbs, by Knuth's algorithm D
+fn div_rem(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    if compare(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec())
+    }
+    if b.len() == 1 {
+        let mut quotient = a.to_vec();
+        let remainder = div_small(&mut quotient, b[0]);
+        return (quotient, vec![remainder])
+    }
+    // the divisor is shifted until its top bit is set
+    let shift = b[b.len() - 1].leading_zeros() as u64;
+    let v = shl(b, shift);
+    let mut u = shl(a, shift);
+    if u.len() == a.len() {
+        u.push(0);
+    }
+    let n = v.len();
+    let mut quotient = vec![0u64; u.len() - n];
+    let (top, second) = (v[n - 1] as u128, v[n - 2] as u128);
+    for j in (0..quotient.len()).rev() {
+        let dividend = (u[j + n] as u128) << 64 | u[j + n - 1] as u128;
+        let mut estimate = dividend / top;
+        let mut rest = dividend % top;
+        while estimate >> 64 != 0 || estimate * second > (rest << 64 | u[j + n - 2] as u128) {
+            estimate -= 1;
+            rest += top;
+            if rest >> 64 != 0 {
+                break
+            }
+        }
+        let mut carry = 0u128;
+        let mut borrow = false;
+        for i in 0..n {
+            let product = estimate * v[i] as u128 + carry;
+            carry = product >> 64;
+            let (partial, first) = u[i + j].overflowing_sub(product as u64);
+            let (partial, second) = partial.overflowing_sub(borrow as u64);
+            u[i + j] = partial;
+            borrow = first || second;
+        }
+        let (partial, first) = u[j + n].overflowing_sub(carry as u64);
+        let (partial, second) = partial.overflowing_sub(borrow as u64);
+        u[j + n] = partial;
+        if first || second {
+            // the estimate was one too large
+            estimate -= 1;
+            let mut carry = false;
+            for i in 0..n {
+                let (partial, first) = u[i + j].overflowing_add(v[i]);
+                let (partial, second) = partial.overflowing_add(carry as u64);
+                u[i + j] = partial;
+                carry = first || second;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u64);
+        }
+        quotient[j] = estimate as u64;
+    }
+    (quotient, shr(&u[..n], shift))
+}
+
+fn check_radix(radix: u32) {
+    if !(2..=36).contains(&radix) {
+        panic!("radix {} not in 2..=36", radix)
+    }
+}
+
+// the greatest power of the radix which fits a limb, and its exponent
+fn radix_chunk(radix: u32) -> (u64, usize) {
+    let (mut power, mut digits) = (radix as u64, 1);
+    while let Some(next) = power.checked_mul(radix as u64) {
+        power = next;
+        digits += 1;
+    }
+    (power, digits)
+}
+
+// the digits without sign, at `offset` in the original text
+fn parse_digits(text: &str, offset: usize, radix: u32) -> Result<BigUint, BigIntError> {
+    if text.is_empty() {
+        return Err(BigIntError::UnexpectedEnd(offset))
+    }
+    let (_, digits) = radix_chunk(radix);
+    let mut limbs = Vec::new();
+    let (mut chunk, mut chunk_digits) = (0u64, 0);
+    for (i, c) in text.char_indices() {
+        let digit = c.to_digit(radix).ok_or(BigIntError::Unexpected(offset + i, c))?;
+        chunk = chunk * radix as u64 + digit as u64;
+        chunk_digits += 1;
+        if chunk_digits == digits {
+            mul_small(&mut limbs, (radix as u64).pow(digits as u32), chunk);
+            chunk = 0;
+            chunk_digits = 0;
+        }
+    }
+    if chunk_digits > 0 {
+        mul_small(&mut limbs, (radix as u64).pow(chunk_digits as u32), chunk);
+    }
+    Ok(BigUint { limbs })
+}
+
+impl std::cmp::PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(&self.limbs, &other.limbs)
+    }
+}
+impl std::cmp::PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl std::cmp::Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false)  =>  self.magnitude.cmp(&other.magnitude),
+            (true, true)    =>  other.magnitude.cmp(&self.magnitude),
+            (false, true)   =>  Ordering::Greater,
+            (true, false)   =>  Ordering::Less
+        }
+    }
+}
+
+impl std::fmt::Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(true, "", &self.to_str_radix(10))
+    }
+}
+impl std::fmt::Debug for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+impl std::fmt::Binary for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(true, "0b", &self.to_str_radix(2))
+    }
+}
+impl std::fmt::Octal for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(true, "0o", &self.to_str_radix(8))
+    }
+}
+impl std::fmt::LowerHex for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(true, "0x", &self.to_str_radix(16))
+    }
+}
+impl std::fmt::UpperHex for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(true, "0x", &self.to_str_radix(16).to_uppercase())
+    }
+}
+impl std::fmt::Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_str_radix(10))
+    }
+}
+impl std::fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+impl std::fmt::Binary for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(!self.negative, "0b", &self.magnitude.to_str_radix(2))
+    }
+}
+impl std::fmt::Octal for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(!self.negative, "0o", &self.magnitude.to_str_radix(8))
+    }
+}
+impl std::fmt::LowerHex for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(!self.negative, "0x", &self.magnitude.to_str_radix(16))
+    }
+}
+impl std::fmt::UpperHex for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.pad_integral(!self.negative, "0x", &self.magnitude.to_str_radix(16).to_uppercase())
+    }
+}
+
+impl std::str::FromStr for BigUint {
+    type Err = BigIntError;
+    fn from_str(text: &str) -> Result<Self, BigIntError> {
+        BigUint::from_str_radix(text, 10)
+    }
+}
+impl std::str::FromStr for BigInt {
+    type Err = BigIntError;
+    fn from_str(text: &str) -> Result<Self, BigIntError> {
+        BigInt::from_str_radix(text, 10)
+    }
+}
+
+// the operators for owned and borrowed numbers
+macro_rules! impl_big_ops {
+    ($big:ty; $($trait:ident, $method:ident, $assign:ident, $assign_method:ident, $core:ident;)*) => {
+        $(
+            impl std::ops::$trait<&$big> for &$big {
+                type Output = $big;
+                fn $method(self, other: &$big) -> $big {
+                    self.$core(other)
+                }
+            }
+            impl std::ops::$trait<$big> for &$big {
+                type Output = $big;
+                fn $method(self, other: $big) -> $big {
+                    self.$core(&other)
+                }
+            }
+            impl std::ops::$trait<&$big> for $big {
+                type Output = $big;
+                fn $method(self, other: &$big) -> $big {
+                    self.$core(other)
+                }
+            }
+            impl std::ops::$trait for $big {
+                type Output = $big;
+                fn $method(self, other: $big) -> $big {
+                    self.$core(&other)
+                }
+            }
+            impl std::ops::$assign<&$big> for $big {
+                fn $assign_method(&mut self, other: &$big) {
+                    *self = self.$core(other);
+                }
+            }
+            impl std::ops::$assign for $big {
+                fn $assign_method(&mut self, other: $big) {
+                    *self = self.$core(&other);
+                }
+            }
+        )*
+    };
+}
+impl_big_ops! {
+    BigUint;
+    Add, add, AddAssign, add_assign, add_big;
+    Sub, sub, SubAssign, sub_assign, sub_big;
+    Mul, mul, MulAssign, mul_assign, mul_big;
+    Div, div, DivAssign, div_assign, div_big;
+    Rem, rem, RemAssign, rem_assign, rem_big;
+    BitAnd, bitand, BitAndAssign, bitand_assign, and_big;
+    BitOr, bitor, BitOrAssign, bitor_assign, or_big;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, xor_big;
+}
+impl_big_ops! {
+    BigInt;
+    Add, add, AddAssign, add_assign, add_big;
+    Sub, sub, SubAssign, sub_assign, sub_big;
+    Mul, mul, MulAssign, mul_assign, mul_big;
+    Div, div, DivAssign, div_assign, div_big;
+    Rem, rem, RemAssign, rem_assign, rem_big;
+    BitAnd, bitand, BitAndAssign, bitand_assign, and_big;
+    BitOr, bitor, BitOrAssign, bitor_assign, or_big;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, xor_big;
+}
+
+impl std::ops::Shl<u64> for &BigUint {
+    type Output = BigUint;
+    fn shl(self, bits: u64) -> BigUint {
+        BigUint { limbs: shl(&self.limbs, bits) }
+    }
+}
+impl std::ops::Shl<u64> for BigUint {
+    type Output = BigUint;
+    fn shl(self, bits: u64) -> BigUint {
+        &self << bits
+    }
+}
+impl std::ops::Shr<u64> for &BigUint {
+    type Output = BigUint;
+    fn shr(self, bits: u64) -> BigUint {
+        BigUint { limbs: shr(&self.limbs, bits) }
+    }
+}
+impl std::ops::Shr<u64> for BigUint {
+    type Output = BigUint;
+    fn shr(self, bits: u64) -> BigUint {
+        &self >> bits
+    }
+}
+impl std::ops::ShlAssign<u64> for BigUint {
+    fn shl_assign(&mut self, bits: u64) {
+        *self = &*self << bits;
+    }
+}
+impl std::ops::ShrAssign<u64> for BigUint {
+    fn shr_assign(&mut self, bits: u64) {
+        *self = &*self >> bits;
+    }
+}
+impl std::ops::Shl<u64> for &BigInt {
+    type Output = BigInt;
+    fn shl(self, bits: u64) -> BigInt {
+        BigInt { negative: self.negative, magnitude: &self.magnitude << bits }
+    }
+}
+impl std::ops::Shl<u64> for BigInt {
+    type Output = BigInt;
+    fn shl(self, bits: u64) -> BigInt {
+        &self << bits
+    }
+}
+impl std::ops::Shr<u64> for &BigInt {
+    type Output = BigInt;
+    fn shr(self, bits: u64) -> BigInt {
+        if !self.negative {
+            return BigInt { negative: false, magnitude: &self.magnitude >> bits }
+        }
+        // rounds toward negative infinity: −((|x| − 1) >> bits) − 1
+        let magnitude = (&self.magnitude - BigUint::one()) >> bits;
+        BigInt::from_biguint(true, magnitude + BigUint::one())
+    }
+}
+impl std::ops::Shr<u64> for BigInt {
+    type Output = BigInt;
+    fn shr(self, bits: u64) -> BigInt {
+        &self >> bits
+    }
+}
+impl std::ops::ShlAssign<u64> for BigInt {
+    fn shl_assign(&mut self, bits: u64) {
+        *self = &*self << bits;
+    }
+}
+impl std::ops::ShrAssign<u64> for BigInt {
+    fn shr_assign(&mut self, bits: u64) {
+        *self = &*self >> bits;
+    }
+}
+impl std::ops::Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_biguint(!self.negative, self.magnitude.clone())
+    }
+}
+impl std::ops::Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_biguint(!self.negative, self.magnitude)
+    }
+}
+impl std::ops::Not for &BigInt {
+    type Output = BigInt;
+    fn not(self) -> BigInt {
+        -self - BigInt::one()
+    }
+}
+impl std::ops::Not for BigInt {
+    type Output = BigInt;
+    fn not(self) -> BigInt {
+        -self - BigInt::one()
+    }
+}
+impl std::iter::Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::zero(), |sum, x| sum + x)
+    }
+}
+impl std::iter::Product for BigUint {
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::one(), |product, x| product * x)
+    }
+}
+impl std::iter::Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::zero(), |sum, x| sum + x)
+    }
+}
+impl std::iter::Product for BigInt {
+    fn product<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::one(), |product, x| product * x)
+    }
+}
+
+impl Sealed for BigUint {}
+impl Integer for BigUint {
+    fn zero() -> Self {
+        BigUint::zero()
+    }
+    fn one() -> Self {
+        BigUint::one()
+    }
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        BigUint::checked_sub(self, other)
+    }
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        BigUint::checked_div(self, other)
+    }
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        BigUint::checked_rem(self, other)
+    }
+    fn checked_neg(&self) -> Option<Self> {
+        if self.is_zero() { Some(BigUint::zero()) } else { None }
+    }
+    fn from_u128(value: u128) -> Option<Self> {
+        Some(BigUint::from_wide(value))
+    }
+    fn to_u128(&self) -> Option<u128> {
+        self.to_wide()
+    }
+    fn to_f64(&self) -> f64 {
+        BigUint::to_f64(self)
+    }
+}
+impl Sealed for BigInt {}
+impl Integer for BigInt {
+    fn zero() -> Self {
+        BigInt::zero()
+    }
+    fn one() -> Self {
+        BigInt::one()
+    }
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        BigInt::checked_div(self, other)
+    }
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        BigInt::checked_rem(self, other)
+    }
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+    fn from_u128(value: u128) -> Option<Self> {
+        Some(BigInt::from(BigUint::from_wide(value)))
+    }
+    fn to_u128(&self) -> Option<u128> {
+        if self.negative { None } else { self.magnitude.to_wide() }
+    }
+    fn to_f64(&self) -> f64 {
+        BigInt::to_f64(self)
+    }
+}
+
+impl std::convert::From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        BigInt { negative: false, magnitude }
+    }
+}
+impl std::convert::TryFrom<BigInt> for BigUint {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        if n.negative {
+            return Err(BigIntError::OutOfRange)
+        }
+        Ok(n.magnitude)
+    }
+}
+
+impl std::convert::From<u8> for BigUint {
+    fn from(p: u8) -> Self {
+        BigUint::from_wide(p as u128)
+    }
+}
+impl std::convert::From<u16> for BigUint {
+    fn from(p: u16) -> Self {
+        BigUint::from_wide(p as u128)
+    }
+}
+impl std::convert::From<u32> for BigUint {
+    fn from(p: u32) -> Self {
+        BigUint::from_wide(p as u128)
+    }
+}
+impl std::convert::From<u64> for BigUint {
+    fn from(p: u64) -> Self {
+        BigUint::from_wide(p as u128)
+    }
+}
+impl std::convert::From<u128> for BigUint {
+    fn from(p: u128) -> Self {
+        BigUint::from_wide(p)
+    }
+}
+impl std::convert::From<usize> for BigUint {
+    fn from(p: usize) -> Self {
+        BigUint::from_wide(p as u128)
+    }
+}
+
+impl std::convert::TryFrom<i8> for BigUint {
+    type Error = BigIntError;
+    fn try_from(p: i8) -> Result<Self, BigIntError> {
+        if p < 0 {
+            return Err(BigIntError::OutOfRange)
+        }
+        Ok(BigUint::from_wide(p as u128))
+    }
+}
+impl std::convert::TryFrom<i16> for BigUint {
+    type Error = BigIntError;
+    fn try_from(p: i16) -> Result<Self, BigIntError> {
+        if p < 0 {
+            return Err(BigIntError::OutOfRange)
+        }
+        Ok(BigUint::from_wide(p as u128))
+    }
+}
+impl std::convert::TryFrom<i32> for BigUint {
+    type Error = BigIntError;
+    fn try_from(p: i32) -> Result<Self, BigIntError> {
+        if p < 0 {
+            return Err(BigIntError::OutOfRange)
+        }
+        Ok(BigUint::from_wide(p as u128))
+    }
+}
+impl std::convert::TryFrom<i64> for BigUint {
+    type Error = BigIntError;
+    fn try_from(p: i64) -> Result<Self, BigIntError> {
+        if p < 0 {
+            return Err(BigIntError::OutOfRange)
+        }
+        Ok(BigUint::from_wide(p as u128))
+    }
+}
+impl std::convert::TryFrom<i128> for BigUint {
+    type Error = BigIntError;
+    fn try_from(p: i128) -> Result<Self, BigIntError> {
+        if p < 0 {
+            return Err(BigIntError::OutOfRange)
+        }
+        Ok(BigUint::from_wide(p as u128))
+    }
+}
+impl std::convert::TryFrom<isize> for BigUint {
+    type Error = BigIntError;
+    fn try_from(p: isize) -> Result<Self, BigIntError> {
+        if p < 0 {
+            return Err(BigIntError::OutOfRange)
+        }
+        Ok(BigUint::from_wide(p as u128))
+    }
+}
+
+impl std::convert::TryFrom<BigUint> for u8 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| u8::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for u16 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| u16::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for u32 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| u32::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for u64 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| u64::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for u128 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for usize {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| usize::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for i8 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| i8::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for i16 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| i16::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for i32 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| i32::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for i64 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| i64::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for i128 {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| i128::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigUint> for isize {
+    type Error = BigIntError;
+    fn try_from(n: BigUint) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| isize::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+
+impl std::convert::From<u8> for BigInt {
+    fn from(p: u8) -> Self {
+        BigInt::from(BigUint::from_wide(p as u128))
+    }
+}
+impl std::convert::From<u16> for BigInt {
+    fn from(p: u16) -> Self {
+        BigInt::from(BigUint::from_wide(p as u128))
+    }
+}
+impl std::convert::From<u32> for BigInt {
+    fn from(p: u32) -> Self {
+        BigInt::from(BigUint::from_wide(p as u128))
+    }
+}
+impl std::convert::From<u64> for BigInt {
+    fn from(p: u64) -> Self {
+        BigInt::from(BigUint::from_wide(p as u128))
+    }
+}
+impl std::convert::From<u128> for BigInt {
+    fn from(p: u128) -> Self {
+        BigInt::from(BigUint::from_wide(p))
+    }
+}
+impl std::convert::From<usize> for BigInt {
+    fn from(p: usize) -> Self {
+        BigInt::from(BigUint::from_wide(p as u128))
+    }
+}
+
+impl std::convert::From<i8> for BigInt {
+    fn from(p: i8) -> Self {
+        BigInt::from_wide(p as i128)
+    }
+}
+impl std::convert::From<i16> for BigInt {
+    fn from(p: i16) -> Self {
+        BigInt::from_wide(p as i128)
+    }
+}
+impl std::convert::From<i32> for BigInt {
+    fn from(p: i32) -> Self {
+        BigInt::from_wide(p as i128)
+    }
+}
+impl std::convert::From<i64> for BigInt {
+    fn from(p: i64) -> Self {
+        BigInt::from_wide(p as i128)
+    }
+}
+impl std::convert::From<i128> for BigInt {
+    fn from(p: i128) -> Self {
+        BigInt::from_wide(p)
+    }
+}
+impl std::convert::From<isize> for BigInt {
+    fn from(p: isize) -> Self {
+        BigInt::from_wide(p as i128)
+    }
+}
+
+impl std::convert::TryFrom<BigInt> for u8 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        u8::try_from(BigUint::try_from(n)?)
+    }
+}
+impl std::convert::TryFrom<BigInt> for u16 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        u16::try_from(BigUint::try_from(n)?)
+    }
+}
+impl std::convert::TryFrom<BigInt> for u32 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        u32::try_from(BigUint::try_from(n)?)
+    }
+}
+impl std::convert::TryFrom<BigInt> for u64 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        u64::try_from(BigUint::try_from(n)?)
+    }
+}
+impl std::convert::TryFrom<BigInt> for u128 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        u128::try_from(BigUint::try_from(n)?)
+    }
+}
+impl std::convert::TryFrom<BigInt> for usize {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        usize::try_from(BigUint::try_from(n)?)
+    }
+}
+
+impl std::convert::TryFrom<BigInt> for i8 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| i8::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigInt> for i16 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| i16::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigInt> for i32 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| i32::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigInt> for i64 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| i64::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigInt> for i128 {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        n.to_wide().ok_or(BigIntError::OutOfRange)
+    }
+}
+impl std::convert::TryFrom<BigInt> for isize {
+    type Error = BigIntError;
+    fn try_from(n: BigInt) -> Result<Self, BigIntError> {
+        n.to_wide().and_then(|p| isize::try_from(p).ok()).ok_or(BigIntError::OutOfRange)
+    }
+}
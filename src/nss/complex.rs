@@ -0,0 +1,962 @@
+use super::rational::sealed::Sealed;
+
+/// a floating-point type which can be the parts of a [`Complex`]
+///
+/// The trait is implemented for `f32` and `f64` and cannot be implemented outside of this crate.
+/// Its methods have the names and meanings of the inherent methods of the floating-point types.
+pub trait Real:
+    Copy + PartialOrd + Default + std::fmt::Debug + std::fmt::Display + std::str::FromStr
+    + std::ops::Add<Output = Self> + std::ops::Sub<Output = Self> + std::ops::Mul<Output = Self>
+    + std::ops::Div<Output = Self> + std::ops::Rem<Output = Self> + std::ops::Neg<Output = Self> + Sealed
+{
+    /// returns `0`
+    fn zero() -> Self;
+    /// returns `1`
+    fn one() -> Self;
+    /// returns π
+    fn pi() -> Self;
+    /// returns the difference between `1` and the next larger number
+    fn epsilon() -> Self;
+    /// returns positive infinity
+    fn infinity() -> Self;
+    /// returns the smallest positive normal number
+    fn min_positive() -> Self;
+    /// returns not a number
+    fn nan() -> Self;
+    /// returns the nearest number to an `f64`
+    fn from_f64(value: f64) -> Self;
+    /// returns the number as `f64`
+    fn to_f64(self) -> f64;
+    /// returns whether the number is not a number
+    fn is_nan(self) -> bool;
+    /// returns whether the number is an infinity
+    fn is_infinite(self) -> bool;
+    /// returns whether the number is neither an infinity nor not a number
+    fn is_finite(self) -> bool;
+    /// returns whether the sign bit is set, also for `-0`
+    fn is_sign_negative(self) -> bool;
+    /// returns the largest integer less than or equal to the number
+    fn floor(self) -> Self;
+    /// returns the smallest integer greater than or equal to the number
+    fn ceil(self) -> Self;
+    /// returns the nearest integer, rounding half away from zero
+    fn round(self) -> Self;
+    /// returns the integer part
+    fn trunc(self) -> Self;
+    /// returns the least number greater than the number, the identity for positive infinity and not a number
+    fn next_up(self) -> Self;
+    /// returns the greatest number less than the number, the identity for negative infinity and not a number
+    fn next_down(self) -> Self;
+    /// returns the absolute value
+    fn abs(self) -> Self;
+    /// returns `1` or `-1` by the sign bit
+    fn signum(self) -> Self;
+    /// returns the magnitude of the number with the sign of `sign`
+    fn copysign(self, sign: Self) -> Self;
+    /// returns the larger number, ignoring not a number
+    fn max(self, other: Self) -> Self;
+    /// returns the smaller number, ignoring not a number
+    fn min(self, other: Self) -> Self;
+    /// returns `self * a + b` with one rounding
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    /// returns the number raised to an integer power
+    fn powi(self, exponent: i32) -> Self;
+    /// returns the number raised to a power
+    fn powf(self, exponent: Self) -> Self;
+    /// returns the square root
+    fn sqrt(self) -> Self;
+    /// returns the cube root
+    fn cbrt(self) -> Self;
+    /// returns `√(self² + other²)` without overflow
+    fn hypot(self, other: Self) -> Self;
+    /// returns `e^self`
+    fn exp(self) -> Self;
+    /// returns `e^self - 1`, accurate near zero
+    fn exp_m1(self) -> Self;
+    /// returns the natural logarithm
+    fn ln(self) -> Self;
+    /// returns `ln(1 + self)`, accurate near zero
+    fn ln_1p(self) -> Self;
+    /// returns the logarithm to base 10
+    fn log10(self) -> Self;
+    /// returns the sine of radians
+    fn sin(self) -> Self;
+    /// returns the cosine of radians
+    fn cos(self) -> Self;
+    /// returns the tangent of radians
+    fn tan(self) -> Self;
+    /// returns the arcsine in radians
+    fn asin(self) -> Self;
+    /// returns the arccosine in radians
+    fn acos(self) -> Self;
+    /// returns the arctangent in radians
+    fn atan(self) -> Self;
+    /// returns the angle of the point `(other, self)` in radians
+    fn atan2(self, other: Self) -> Self;
+    /// returns the hyperbolic sine
+    fn sinh(self) -> Self;
+    /// returns the hyperbolic cosine
+    fn cosh(self) -> Self;
+    /// returns the hyperbolic tangent
+    fn tanh(self) -> Self;
+    /// returns the inverse hyperbolic sine
+    fn asinh(self) -> Self;
+    /// returns the inverse hyperbolic cosine
+    fn acosh(self) -> Self;
+    /// returns the inverse hyperbolic tangent
+    fn atanh(self) -> Self;
+    /// converts radians to degrees
+    fn to_degrees(self) -> Self;
+    /// converts degrees to radians
+    fn to_radians(self) -> Self;
+}
+
+macro_rules! impl_real {
+    ($($float:ident),*) => {
+        $(
+            impl Sealed for $float {}
+            impl Real for $float {
+                fn zero() -> Self {
+                    0.0
+                }
+                fn one() -> Self {
+                    1.0
+                }
+                fn pi() -> Self {
+                    std::$float::consts::PI
+                }
+                fn epsilon() -> Self {
+                    $float::EPSILON
+                }
+                fn infinity() -> Self {
+                    $float::INFINITY
+                }
+                fn min_positive() -> Self {
+                    $float::MIN_POSITIVE
+                }
+                fn nan() -> Self {
+                    $float::NAN
+                }
+                fn from_f64(value: f64) -> Self {
+                    value as $float
+                }
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                fn is_nan(self) -> bool {
+                    $float::is_nan(self)
+                }
+                fn is_infinite(self) -> bool {
+                    $float::is_infinite(self)
+                }
+                fn is_finite(self) -> bool {
+                    $float::is_finite(self)
+                }
+                fn is_sign_negative(self) -> bool {
+                    $float::is_sign_negative(self)
+                }
+                fn floor(self) -> Self {
+                    $float::floor(self)
+                }
+                fn ceil(self) -> Self {
+                    $float::ceil(self)
+                }
+                fn round(self) -> Self {
+                    $float::round(self)
+                }
+                fn trunc(self) -> Self {
+                    $float::trunc(self)
+                }
+                fn next_up(self) -> Self {
+                    $float::next_up(self)
+                }
+                fn next_down(self) -> Self {
+                    $float::next_down(self)
+                }
+                fn abs(self) -> Self {
+                    $float::abs(self)
+                }
+                fn signum(self) -> Self {
+                    $float::signum(self)
+                }
+                fn copysign(self, sign: Self) -> Self {
+                    $float::copysign(self, sign)
+                }
+                fn max(self, other: Self) -> Self {
+                    $float::max(self, other)
+                }
+                fn min(self, other: Self) -> Self {
+                    $float::min(self, other)
+                }
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    $float::mul_add(self, a, b)
+                }
+                fn powi(self, exponent: i32) -> Self {
+                    $float::powi(self, exponent)
+                }
+                fn powf(self, exponent: Self) -> Self {
+                    $float::powf(self, exponent)
+                }
+                fn sqrt(self) -> Self {
+                    $float::sqrt(self)
+                }
+                fn cbrt(self) -> Self {
+                    $float::cbrt(self)
+                }
+                fn hypot(self, other: Self) -> Self {
+                    $float::hypot(self, other)
+                }
+                fn exp(self) -> Self {
+                    $float::exp(self)
+                }
+                fn exp_m1(self) -> Self {
+                    $float::exp_m1(self)
+                }
+                fn ln(self) -> Self {
+                    $float::ln(self)
+                }
+                fn ln_1p(self) -> Self {
+                    $float::ln_1p(self)
+                }
+                fn log10(self) -> Self {
+                    $float::log10(self)
+                }
+                fn sin(self) -> Self {
+                    $float::sin(self)
+                }
+                fn cos(self) -> Self {
+                    $float::cos(self)
+                }
+                fn tan(self) -> Self {
+                    $float::tan(self)
+                }
+                fn asin(self) -> Self {
+                    $float::asin(self)
+                }
+                fn acos(self) -> Self {
+                    $float::acos(self)
+                }
+                fn atan(self) -> Self {
+                    $float::atan(self)
+                }
+                fn atan2(self, other: Self) -> Self {
+                    $float::atan2(self, other)
+                }
+                fn sinh(self) -> Self {
+                    $float::sinh(self)
+                }
+                fn cosh(self) -> Self {
+                    $float::cosh(self)
+                }
+                fn tanh(self) -> Self {
+                    $float::tanh(self)
+                }
+                fn asinh(self) -> Self {
+                    $float::asinh(self)
+                }
+                fn acosh(self) -> Self {
+                    $float::acosh(self)
+                }
+                fn atanh(self) -> Self {
+                    $float::atanh(self)
+                }
+                fn to_degrees(self) -> Self {
+                    $float::to_degrees(self)
+                }
+                fn to_radians(self) -> Self {
+                    $float::to_radians(self)
+                }
+            }
+        )*
+    };
+}
+impl_real!(f32, f64);
+
+/// an error of parsing a [`Complex`]
+///
+/// The offsets are byte offsets into the text.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum ComplexError {
+    /// the text is empty or only whitespace
+    Empty,
+    /// a character which does not belong to a complex number, as `(offset, char)`
+    Unexpected(usize, char),
+    /// the text ends before a digit or the imaginary unit, as `offset`
+    UnexpectedEnd(usize),
+}
+
+impl std::fmt::Display for ComplexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            ComplexError::Empty                 =>  write!(f,"empty text"),
+            ComplexError::Unexpected(offset, c) =>  write!(f,"unexpected {:?} at {}",c,offset),
+            ComplexError::UnexpectedEnd(offset) =>  write!(f,"unexpected end at {}",offset)
+        }
+    }
+}
+impl std::error::Error for ComplexError {}
+
+/// a complex number `re + im·i`
+///
+/// The inverse functions and the logarithm follow the branch cuts of C99 and Kahan:
+/// their results are continuous with the side of the cut where the sign of zero of the imaginary part points,
+/// so `ln(-1 + 0i)` is `πi` and `ln(-1 - 0i)` is `-πi`.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Complex;
+/// let z = Complex::new(3.0, -4.0);
+/// assert_eq!(5.0, z.norm());
+/// assert_eq!(Complex::new(-7.0, -24.0), z * z);
+/// assert_eq!(Complex::new(0.12, 0.16), z.recip());
+/// assert_eq!("3 - 4i", z.to_string());
+/// assert_eq!(Ok(z), "3-4i".parse());
+/// assert_eq!(Complex::new(0.0, 2.0), Complex::new(-4.0, 0.0).sqrt());
+/// assert_eq!(Complex::new(0.0, -2.0), Complex::new(-4.0, -0.0).sqrt());
+/// ```
+# [derive(Clone,Copy,PartialEq,Debug,Default)]
+pub struct Complex<T: Real> {
+    re: T,
+    im: T,
+}
+
+impl<T: Real> Complex<T> {
+    /// returns `re + im·i`
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+    /// returns `0`
+    pub fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+    /// returns `1`
+    pub fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+    /// returns the imaginary unit `i`
+    pub fn i() -> Self {
+        Complex::new(T::zero(), T::one())
+    }
+    /// returns the number `r·e^(iθ)` of a norm and an argument in radians
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+    /// returns the real part
+    pub fn re(&self) -> T {
+        self.re
+    }
+    /// returns the imaginary part
+    pub fn im(&self) -> T {
+        self.im
+    }
+    /// returns whether a part is not a number
+    pub fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+    /// returns whether both parts are finite
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+    /// returns the complex conjugate `re - im·i`
+    pub fn conj(&self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+    /// returns the absolute value `|z|` without overflow
+    pub fn norm(&self) -> T {
+        self.re.hypot(self.im)
+    }
+    /// returns `|z|²`
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+    /// returns the argument in `-π..=π` radians
+    pub fn arg(&self) -> T {
+        self.im.atan2(self.re)
+    }
+    /// returns the norm and the argument in radians
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Complex;
+    /// let (r, theta) = Complex::new(-1.0, 1.0).to_polar();
+    /// assert_eq!(std::f64::consts::SQRT_2, r);
+    /// assert_eq!(135.0, theta.to_degrees());
+    /// ```
+    pub fn to_polar(&self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+    /// returns `1 / z`
+    pub fn recip(&self) -> Self {
+        Complex::one() / *self
+    }
+    // 1 + z and 1 - z with the sign of zero of the imaginary part kept
+    fn one_plus_minus(&self) -> (Self, Self) {
+        (Complex::new(T::one() + self.re, self.im), Complex::new(T::one() - self.re, -self.im))
+    }
+    // z·i and z·(-i) with the sign of zero kept
+    fn mul_i(&self) -> Self {
+        Complex::new(-self.im, self.re)
+    }
+    fn mul_minus_i(&self) -> Self {
+        Complex::new(self.im, -self.re)
+    }
+
+    /// returns `e^z`
+    pub fn exp(&self) -> Self {
+        let scale = self.re.exp();
+        if self.im == T::zero() {
+            return Complex::new(scale, self.im)
+        }
+        Complex::new(scale * self.im.cos(), scale * self.im.sin())
+    }
+    /// returns the principal natural logarithm, with the branch cut along the negative real axis
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Complex;
+    /// let pi = std::f64::consts::PI;
+    /// assert_eq!(Complex::new(0.0, pi), Complex::new(-1.0, 0.0).ln());
+    /// assert_eq!(Complex::new(0.0, -pi), Complex::new(-1.0, -0.0).ln());
+    /// let near_one = Complex::new(1.0f64, 1e-10).ln();
+    /// assert!((near_one.re() / 5e-21 - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn ln(&self) -> Self {
+        let norm = self.norm();
+        let half = T::from_f64(0.5);
+        // ln |z| = ln(1 + (|z|² - 1)) / 2 near the unit circle, with the larger part in the product
+        let re = if norm > half && norm < T::one() + T::one() {
+            let (large, small) = if self.re.abs() >= self.im.abs() { (self.re, self.im) } else { (self.im, self.re) };
+            ((large - T::one()) * (large + T::one()) + small * small).ln_1p() * half
+        } else {
+            norm.ln()
+        };
+        Complex::new(re, self.arg())
+    }
+    /// returns the principal logarithm to base 10
+    pub fn log10(&self) -> Self {
+        let ln = self.ln();
+        let ln10 = T::from_f64(std::f64::consts::LN_10);
+        Complex::new(ln.re / ln10, ln.im / ln10)
+    }
+    /// returns the principal square root, with a non-negative real part
+    pub fn sqrt(&self) -> Self {
+        if self.re == T::zero() && self.im == T::zero() {
+            return Complex::new(T::zero(), self.im)
+        }
+        if self.im.is_infinite() {
+            return Complex::new(T::infinity(), self.im)
+        }
+        let two = T::one() + T::one();
+        let t = ((self.re.abs() + self.norm()) / two).sqrt();
+        if self.re >= T::zero() {
+            Complex::new(t, self.im / (t + t))
+        } else {
+            Complex::new(self.im.abs() / (t + t), t.copysign(self.im))
+        }
+    }
+    /// returns the principal value of `z^exponent`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Complex;
+    /// let i : Complex<f64> = Complex::i();
+    /// let power = i.pow(&i);
+    /// assert_eq!((-std::f64::consts::FRAC_PI_2).exp(), power.re());
+    /// assert_eq!(0.0, power.im());
+    /// ```
+    pub fn pow(&self, exponent: &Self) -> Self {
+        if *exponent == Complex::zero() {
+            return Complex::one()
+        }
+        if *self == Complex::zero() && exponent.re > T::zero() {
+            return Complex::zero()
+        }
+        (*exponent * self.ln()).exp()
+    }
+    /// returns the principal value of `z^exponent` for a real exponent
+    pub fn powf(&self, exponent: T) -> Self {
+        if exponent == T::zero() {
+            return Complex::one()
+        }
+        if *self == Complex::zero() && exponent > T::zero() {
+            return Complex::zero()
+        }
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.powf(exponent), theta * exponent)
+    }
+    /// returns `z^exponent` by repeated multiplication
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Complex;
+    /// assert_eq!(Complex::new(-4.0, 0.0), Complex::new(1.0, 1.0).powi(4));
+    /// assert_eq!(Complex::new(0.0, -0.5), Complex::new(1.0, 1.0).powi(-2));
+    /// ```
+    pub fn powi(&self, exponent: i32) -> Self {
+        let mut power = Complex::one();
+        let mut base = *self;
+        let mut rest = exponent.unsigned_abs();
+        while rest > 0 {
+            if rest & 1 == 1 {
+                power *= base;
+            }
+            base = base * base;
+            rest >>= 1;
+        }
+        if exponent < 0 { power.recip() } else { power }
+    }
+
+    /// returns the sine
+    pub fn sin(&self) -> Self {
+        Complex::new(self.re.sin() * self.im.cosh(), self.re.cos() * self.im.sinh())
+    }
+    /// returns the cosine
+    pub fn cos(&self) -> Self {
+        Complex::new(self.re.cos() * self.im.cosh(), -(self.re.sin() * self.im.sinh()))
+    }
+    /// returns the tangent, which does not overflow for large imaginary parts
+    pub fn tan(&self) -> Self {
+        // tan z = -i·tanh(iz)
+        self.mul_i().tanh().mul_minus_i()
+    }
+    /// returns the hyperbolic sine
+    pub fn sinh(&self) -> Self {
+        Complex::new(self.re.sinh() * self.im.cos(), self.re.cosh() * self.im.sin())
+    }
+    /// returns the hyperbolic cosine
+    pub fn cosh(&self) -> Self {
+        Complex::new(self.re.cosh() * self.im.cos(), self.re.sinh() * self.im.sin())
+    }
+    /// returns the hyperbolic tangent, which does not overflow for large real parts
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Complex;
+    /// assert_eq!(Complex::new(1.0, 0.0), Complex::new(1000.0, 0.0).tanh());
+    /// assert_eq!(Complex::new(0.0, 1.0), Complex::new(0.0, 1000.0).tan());
+    /// ```
+    pub fn tanh(&self) -> Self {
+        let one = T::one();
+        // e^-44 is below the precision of an f64
+        if self.re.abs() > T::from_f64(22.0) {
+            let four = T::from_f64(4.0);
+            let small = four * self.im.sin() * self.im.cos() * (-(self.re.abs() + self.re.abs())).exp();
+            return Complex::new(one.copysign(self.re), small)
+        }
+        // Kahan's formula with t = tan(im) and s = sinh(re)
+        let t = self.im.tan();
+        let beta = one + t * t;
+        let s = self.re.sinh();
+        let rho = (one + s * s).sqrt();
+        let denominator = one + beta * s * s;
+        Complex::new(beta * rho * s / denominator, t / denominator)
+    }
+    /// returns the principal arcsine, with branch cuts along the real axis outside `-1..=1`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Complex;
+    /// let asin = Complex::new(2.0, 0.0).asin();
+    /// assert_eq!(std::f64::consts::FRAC_PI_2, asin.re());
+    /// assert_eq!(1.3169578969248166, asin.im());
+    /// assert_eq!(-1.3169578969248166, Complex::new(2.0, -0.0).asin().im());
+    /// ```
+    pub fn asin(&self) -> Self {
+        // Kahan: asin z = atan(re z / re(√(1 - z)·√(1 + z))) + i·asinh(im(conj(√(1 - z))·√(1 + z)))
+        let (plus, minus) = self.one_plus_minus();
+        let (plus, minus) = (plus.sqrt(), minus.sqrt());
+        Complex::new(
+            self.re.atan2(minus.re * plus.re - minus.im * plus.im),
+            (minus.re * plus.im - minus.im * plus.re).asinh()
+        )
+    }
+    /// returns the principal arccosine, with branch cuts along the real axis outside `-1..=1`
+    pub fn acos(&self) -> Self {
+        // Kahan: acos z = 2·atan(re √(1 - z) / re √(1 + z)) + i·asinh(im(conj(√(1 + z))·√(1 - z)))
+        let (plus, minus) = self.one_plus_minus();
+        let (plus, minus) = (plus.sqrt(), minus.sqrt());
+        let two = T::one() + T::one();
+        Complex::new(two * minus.re.atan2(plus.re), (plus.re * minus.im - plus.im * minus.re).asinh())
+    }
+    /// returns the principal arctangent, with branch cuts along the imaginary axis outside `-i..=i`
+    pub fn atan(&self) -> Self {
+        // atan z = -i·atanh(iz)
+        self.mul_i().atanh().mul_minus_i()
+    }
+    /// returns the principal inverse hyperbolic sine, with branch cuts along the imaginary axis outside `-i..=i`
+    pub fn asinh(&self) -> Self {
+        // asinh z = -i·asin(iz)
+        self.mul_i().asin().mul_minus_i()
+    }
+    /// returns the principal inverse hyperbolic cosine, with a branch cut along the real axis below `1`
+    pub fn acosh(&self) -> Self {
+        // Kahan: acosh z = asinh(re(conj(√(z - 1))·√(z + 1))) + 2i·atan(im √(z - 1) / re √(z + 1))
+        let minus = Complex::new(self.re - T::one(), self.im).sqrt();
+        let plus = Complex::new(self.re + T::one(), self.im).sqrt();
+        let two = T::one() + T::one();
+        Complex::new((minus.re * plus.re + minus.im * plus.im).asinh(), two * minus.im.atan2(plus.re))
+    }
+    /// returns the principal inverse hyperbolic tangent, with branch cuts along the real axis outside `-1..=1`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Complex;
+    /// let atanh = Complex::new(2.0, 0.0).atanh();
+    /// assert_eq!(0.5493061443340549, atanh.re());
+    /// assert_eq!(std::f64::consts::FRAC_PI_2, atanh.im());
+    /// assert_eq!(-std::f64::consts::FRAC_PI_2, Complex::new(2.0, -0.0).atanh().im());
+    /// assert_eq!(Complex::new(1e-10, 0.0), Complex::new(1e-10, 0.0).atanh());
+    /// ```
+    pub fn atanh(&self) -> Self {
+        let one = T::one();
+        let half_pi = T::pi() * T::from_f64(0.5);
+        // atanh z is 1/z ± πi/2 up to 1/(3z³), below the precision
+        if self.norm() * T::epsilon().sqrt() > one {
+            return Complex::new(self.recip().re, half_pi.copysign(self.im))
+        }
+        // atanh z = (ln(1 + z) - ln(1 - z)) / 2, where the real parts are subtracted in one logarithm
+        // ln(|1 + z|² / |1 - z|²) = ln(1 + t) unless the quotient is far from 1
+        let four = T::from_f64(4.0);
+        let t = four * self.re / ((one - self.re) * (one - self.re) + self.im * self.im);
+        let re = if t > T::from_f64(-0.5) && t < one {
+            t.ln_1p() / four
+        } else {
+            ((one + self.re).hypot(self.im).ln() - (one - self.re).hypot(self.im).ln()) * T::from_f64(0.5)
+        };
+        let im = self.im.atan2(one + self.re) - (-self.im).atan2(one - self.re);
+        Complex::new(re, im * T::from_f64(0.5))
+    }
+
+    fn add_complex(self, other: Self) -> Self {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+    fn sub_complex(self, other: Self) -> Self {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+    fn mul_complex(self, other: Self) -> Self {
+        Complex::new(self.re * other.re - self.im * other.im, self.re * other.im + self.im * other.re)
+    }
+    fn add_real(self, other: T) -> Self {
+        Complex::new(self.re + other, self.im)
+    }
+    fn sub_real(self, other: T) -> Self {
+        Complex::new(self.re - other, self.im)
+    }
+    fn mul_real(self, other: T) -> Self {
+        Complex::new(self.re * other, self.im * other)
+    }
+    fn div_real(self, other: T) -> Self {
+        Complex::new(self.re / other, self.im / other)
+    }
+    // Smith's algorithm, which does not overflow for large divisors
+    fn div_complex(self, other: Self) -> Self {
+        if other.re.abs() >= other.im.abs() {
+            let ratio = other.im / other.re;
+            let denominator = other.re + other.im * ratio;
+            Complex::new((self.re + self.im * ratio) / denominator, (self.im - self.re * ratio) / denominator)
+        } else {
+            let ratio = other.re / other.im;
+            let denominator = other.re * ratio + other.im;
+            Complex::new((self.re * ratio + self.im) / denominator, (self.im * ratio - self.re) / denominator)
+        }
+    }
+}
+
+impl<T: Real> std::fmt::Display for Complex<T> {
+    /// writes `a + bi`, or the polar form `r∠θ°` with `{:#}`, a precision applies to each number
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Complex;
+    /// assert_eq!("1.50 - 0.00i", format!("{:.2}", Complex::new(1.5, -0.0)));
+    /// assert_eq!("2∠90°", format!("{:#}", Complex::new(0.0, 2.0)));
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        if f.alternate() {
+            let (r, theta) = self.to_polar();
+            return match f.precision() {
+                Some(precision) =>  write!(f,"{:.*}∠{:.*}°",precision,r,precision,theta.to_degrees()),
+                None            =>  write!(f,"{}∠{}°",r,theta.to_degrees())
+            }
+        }
+        let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+        match f.precision() {
+            Some(precision) =>  write!(f,"{:.*} {} {:.*}i",precision,self.re,sign,precision,self.im.abs()),
+            None            =>  write!(f,"{} {} {}i",self.re,sign,self.im.abs())
+        }
+    }
+}
+
+impl<T: Real> std::str::FromStr for Complex<T> {
+    type Err = ComplexError;
+    /// parses the forms `3-4i`, `3 - 4i`, `-2.5j`, `2∠45°`, `2∠0.785` in radians and `2·e^(iπ)`,
+    /// where `·` may also be `*` or nothing
+    ///
+    /// An angle is in radians, a multiple of π like `π`, `2π` or `3π/4`, or in degrees like `45°`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Complex, ComplexError};
+    /// assert_eq!(Ok(Complex::new(3.0, -4.0)), "3 - 4i".parse());
+    /// assert_eq!(Ok(Complex::new(0.0, -1.0)), "-i".parse());
+    /// assert_eq!(Ok(Complex::new(2.5e-3, 1.0)), "2.5e-3+j".parse());
+    /// assert_eq!(Ok(Complex::from_polar(2.0, std::f64::consts::FRAC_PI_4)), "2∠45°".parse());
+    /// assert_eq!(Ok(Complex::from_polar(2.0, -0.5)), "2·e^(-i0.5)".parse());
+    /// assert_eq!(Ok(Complex::from_polar(1.0, 0.5)), "e^(0.5i)".parse());
+    /// assert_eq!(Ok(Complex::from_polar(2.0, std::f64::consts::PI)), "2e^(iπ)".parse());
+    /// assert_eq!(Ok(Complex::from_polar(1.0, -3.0 * std::f64::consts::FRAC_PI_4)), "e^(-i3π/4)".parse());
+    /// assert_eq!(Ok(Complex::from_polar(3.0, std::f64::consts::FRAC_PI_2)), "3·e^(i90°)".parse());
+    /// assert_eq!(Ok(Complex::from_polar(2.0, std::f64::consts::FRAC_PI_4)), "2∠π/4".parse());
+    /// assert_eq!(Err(ComplexError::UnexpectedEnd(3)), "3+4".parse::<Complex<f64>>());
+    /// assert_eq!(Err(ComplexError::Unexpected(1, 'x')), "3x".parse::<Complex<f64>>());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, ComplexError> {
+        let start = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(ComplexError::Empty)
+        }
+        let mut parser = ComplexParser { text: trimmed, offset: 0, start };
+        let value = parser.complex()?;
+        if parser.offset < trimmed.len() {
+            return Err(parser.unexpected())
+        }
+        Ok(value)
+    }
+}
+
+struct ComplexParser<'a> {
+    text: &'a str,
+    offset: usize,
+    // the offset of the trimmed text in the original text
+    start: usize,
+}
+impl<'a> ComplexParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+    fn eat(&mut self, expected: &[char]) -> bool {
+        match self.rest().chars().next() {
+            Some(c) if expected.contains(&c)    =>  {
+                self.offset += c.len_utf8();
+                true
+            },
+            _                                   =>  false
+        }
+    }
+    fn spaces(&mut self) {
+        self.offset = self.text.len() - self.rest().trim_start().len();
+    }
+    // a sign as whether it is negative, or `None`
+    fn sign(&mut self) -> Option<bool> {
+        if self.eat(&['-', '−']) {
+            Some(true)
+        } else if self.eat(&['+']) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+    fn unit(&mut self) -> bool {
+        self.eat(&['i', 'j'])
+    }
+    fn is_exponential(&self) -> bool {
+        self.rest().starts_with("e^(")
+    }
+    // an unsigned number with an optional fraction and exponent
+    fn number<T: Real>(&mut self) -> Result<T, ComplexError> {
+        let begin = self.offset;
+        let rest = self.rest();
+        let digit_count = |text: &str| text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len());
+        let whole = digit_count(rest);
+        let fraction = rest[whole..].strip_prefix('.').map_or(0, |fraction| 1 + digit_count(fraction));
+        if whole == 0 && fraction < 2 {
+            return Err(self.unexpected())
+        }
+        self.offset += whole + fraction;
+        // an exponent needs digits, so `2e^(i)` is no exponent
+        let exponent = self.rest().strip_prefix(['e', 'E']).map(|exponent| exponent.strip_prefix(['+', '-']).unwrap_or(exponent));
+        if let Some(digits) = exponent.filter(|digits| digits.starts_with(|c: char| c.is_ascii_digit())) {
+            self.offset = self.text.len() - digits.len() + digit_count(digits);
+        }
+        let number = &self.text[begin..self.offset];
+        number.parse().map_err(|_| ComplexError::Unexpected(self.start + begin, '.'))
+    }
+    // an angle with an optional sign in radians, a multiple of π with an optional divisor or in degrees
+    fn angle<T: Real>(&mut self) -> Result<T, ComplexError> {
+        let negative = self.sign().unwrap_or(false);
+        let mut angle : T = if self.rest().starts_with('π') { T::one() } else { self.number()? };
+        if self.eat(&['π']) {
+            angle = angle * T::pi();
+            if self.eat(&['/']) {
+                let divisor : T = self.number()?;
+                angle = angle / divisor;
+            }
+        } else if self.eat(&['°']) {
+            angle = angle.to_radians();
+        }
+        Ok(if negative { -angle } else { angle })
+    }
+    // the angle of `e^(iθ)`, `e^(-iθ)` or `e^(θi)`
+    fn phase<T: Real>(&mut self) -> Result<T, ComplexError> {
+        self.offset += "e^(".len();
+        self.spaces();
+        let negative = self.sign().unwrap_or(false);
+        let phase : T = if self.unit() {
+            self.angle()?
+        } else {
+            let phase = self.angle()?;
+            if !self.unit() {
+                return Err(self.unexpected())
+            }
+            phase
+        };
+        self.spaces();
+        if !self.eat(&[')']) {
+            return Err(self.unexpected())
+        }
+        Ok(if negative { -phase } else { phase })
+    }
+    fn complex<T: Real>(&mut self) -> Result<Complex<T>, ComplexError> {
+        let negative = self.sign().unwrap_or(false);
+        let signed = |value: T| if negative { -value } else { value };
+        if self.is_exponential() {
+            return Ok(Complex::from_polar(signed(T::one()), self.phase()?))
+        }
+        if self.unit() {
+            return Ok(Complex::new(T::zero(), signed(T::one())))
+        }
+        let first = signed(self.number()?);
+        self.spaces();
+        if self.eat(&['∠']) {
+            self.spaces();
+            return Ok(Complex::from_polar(first, self.angle()?))
+        }
+        if self.eat(&['·', '*']) {
+            self.spaces();
+            if !self.is_exponential() {
+                return Err(self.unexpected())
+            }
+        }
+        if self.is_exponential() {
+            return Ok(Complex::from_polar(first, self.phase()?))
+        }
+        if self.unit() {
+            return Ok(Complex::new(T::zero(), first))
+        }
+        let negative = match self.sign() {
+            Some(negative)  =>  negative,
+            None            =>  return Ok(Complex::new(first, T::zero()))
+        };
+        self.spaces();
+        let im = if self.unit() {
+            T::one()
+        } else {
+            let im = self.number()?;
+            if !self.unit() {
+                return Err(self.unexpected())
+            }
+            im
+        };
+        Ok(Complex::new(first, if negative { -im } else { im }))
+    }
+    fn unexpected(&self) -> ComplexError {
+        match self.rest().chars().next() {
+            Some(c) =>  ComplexError::Unexpected(self.start + self.offset, c),
+            None    =>  ComplexError::UnexpectedEnd(self.start + self.offset)
+        }
+    }
+}
+
+// the operators for complex numbers and real numbers on the right
+macro_rules! impl_complex_ops {
+    ($($trait:ident, $method:ident, $assign:ident, $assign_method:ident, $core:ident, $real:ident;)*) => {
+        $(
+            impl<T: Real> std::ops::$trait for Complex<T> {
+                type Output = Complex<T>;
+                fn $method(self, other: Complex<T>) -> Complex<T> {
+                    self.$core(other)
+                }
+            }
+            impl<T: Real> std::ops::$trait<T> for Complex<T> {
+                type Output = Complex<T>;
+                fn $method(self, other: T) -> Complex<T> {
+                    self.$real(other)
+                }
+            }
+            impl<T: Real> std::ops::$assign for Complex<T> {
+                fn $assign_method(&mut self, other: Complex<T>) {
+                    *self = self.$core(other);
+                }
+            }
+            impl<T: Real> std::ops::$assign<T> for Complex<T> {
+                fn $assign_method(&mut self, other: T) {
+                    *self = self.$real(other);
+                }
+            }
+        )*
+    };
+}
+impl_complex_ops! {
+    Add, add, AddAssign, add_assign, add_complex, add_real;
+    Sub, sub, SubAssign, sub_assign, sub_complex, sub_real;
+    Mul, mul, MulAssign, mul_assign, mul_complex, mul_real;
+    Div, div, DivAssign, div_assign, div_complex, div_real;
+}
+
+// the operators with real numbers on the left
+macro_rules! impl_real_complex_ops {
+    ($($float:ty),*) => {
+        $(
+            impl std::ops::Add<Complex<$float>> for $float {
+                type Output = Complex<$float>;
+                fn add(self, other: Complex<$float>) -> Complex<$float> {
+                    Complex::from(self).add_complex(other)
+                }
+            }
+            impl std::ops::Sub<Complex<$float>> for $float {
+                type Output = Complex<$float>;
+                fn sub(self, other: Complex<$float>) -> Complex<$float> {
+                    Complex::from(self).sub_complex(other)
+                }
+            }
+            impl std::ops::Mul<Complex<$float>> for $float {
+                type Output = Complex<$float>;
+                fn mul(self, other: Complex<$float>) -> Complex<$float> {
+                    other.mul_real(self)
+                }
+            }
+            impl std::ops::Div<Complex<$float>> for $float {
+                type Output = Complex<$float>;
+                fn div(self, other: Complex<$float>) -> Complex<$float> {
+                    Complex::from(self).div_complex(other)
+                }
+            }
+        )*
+    };
+}
+impl_real_complex_ops!(f32, f64);
+
+impl<T: Real> std::ops::Neg for Complex<T> {
+    type Output = Complex<T>;
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Real> std::iter::Sum for Complex<T> {
+    fn sum<I: Iterator<Item = Complex<T>>>(iter: I) -> Self {
+        iter.fold(Complex::zero(), |sum, value| sum + value)
+    }
+}
+impl<T: Real> std::iter::Product for Complex<T> {
+    fn product<I: Iterator<Item = Complex<T>>>(iter: I) -> Self {
+        iter.fold(Complex::one(), |product, value| product * value)
+    }
+}
+
+impl<T: Real> std::convert::From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Complex::new(re, T::zero())
+    }
+}
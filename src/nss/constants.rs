@@ -0,0 +1,223 @@
+//! mathematical and physical constants named after `GreekAlphabet` letters
+//!
+//! Every constant is a [`Constant`] with its symbol, name, LaTeX and unit.
+//! The values of the physical constants are the CODATA 2018 recommended values.
+//!
+//! # Examples
+//! ```rust
+//! # use math_sd::nss::GreekAlphabet;
+//! # use math_sd::nss::constants::{self, Exactness};
+//! assert_eq!(1.618033988749895, constants::GOLDEN_RATIO.value());
+//! assert_eq!("ε₀", constants::VACUUM_PERMITTIVITY.symbol());
+//! assert_eq!(Exactness::Uncertain(1.3e-21), constants::VACUUM_PERMITTIVITY.exactness());
+//! let phi : Vec<&str> = constants::by_letter(GreekAlphabet::Phi).iter().map(|c| c.name()).collect();
+//! assert_eq!(vec!["golden ratio", "magnetic flux quantum"], phi);
+//! ```
+use super::GreekAlphabet;
+
+/// how exact the value of a [`Constant`] is
+# [derive(Clone,Copy,PartialEq,Debug)]
+pub enum Exactness {
+    /// a mathematical constant, the value is the closest `f64`
+    Mathematical,
+    /// a physical constant which is exact by the definition of the SI units
+    Exact,
+    /// a measured physical constant with its standard uncertainty
+    Uncertain(f64),
+}
+
+/// a mathematical or physical constant
+# [derive(Clone,Copy,PartialEq,Debug)]
+pub struct Constant {
+    letter: GreekAlphabet,
+    symbol: &'static str,
+    name: &'static str,
+    latex: &'static str,
+    value: f64,
+    unit: &'static str,
+    exactness: Exactness,
+}
+
+impl Constant {
+    /// returns the `GreekAlphabet` letter of the symbol
+    pub const fn letter(&self) -> GreekAlphabet {
+        self.letter
+    }
+    /// returns the symbol, like `π` or `ε₀`
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+    /// returns the English name
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+    /// returns the LaTeX of the symbol, like `\pi` or `\varepsilon_0`
+    pub const fn latex(&self) -> &'static str {
+        self.latex
+    }
+    /// returns the SI unit, empty for a dimensionless constant
+    pub const fn unit(&self) -> &'static str {
+        self.unit
+    }
+    /// returns the value as `f64`
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+    /// returns the value as `f32`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::constants;
+    /// assert_eq!(std::f32::consts::PI, constants::PI.value_f32());
+    /// ```
+    pub const fn value_f32(&self) -> f32 {
+        self.value as f32
+    }
+    /// returns how exact the value is
+    pub const fn exactness(&self) -> Exactness {
+        self.exactness
+    }
+    /// returns the standard uncertainty, `0.0` for an exact or mathematical constant
+    pub fn uncertainty(&self) -> f64 {
+        match self.exactness {
+            Exactness::Uncertain(uncertainty)   =>  uncertainty,
+            _                                   =>  0.0
+        }
+    }
+    /// returns the standard uncertainty relative to the value
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::constants;
+    /// let relative = constants::FINE_STRUCTURE.relative_uncertainty();
+    /// assert!((relative - 1.5e-10).abs() < 1e-12);
+    /// ```
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty() / self.value.abs()
+    }
+}
+
+/// π, the ratio of the circumference of a circle to its diameter
+pub const PI : Constant = Constant {
+    letter: GreekAlphabet::Pi, symbol: "π", name: "pi", latex: "\\pi",
+    value: std::f64::consts::PI, unit: "", exactness: Exactness::Mathematical,
+};
+/// τ = 2π, the ratio of the circumference of a circle to its radius
+pub const TAU : Constant = Constant {
+    letter: GreekAlphabet::Tau, symbol: "τ", name: "tau", latex: "\\tau",
+    value: std::f64::consts::TAU, unit: "", exactness: Exactness::Mathematical,
+};
+/// φ = (1 + √5) / 2, the golden ratio
+pub const GOLDEN_RATIO : Constant = Constant {
+    letter: GreekAlphabet::Phi, symbol: "φ", name: "golden ratio", latex: "\\varphi",
+    value: 1.618033988749895, unit: "", exactness: Exactness::Mathematical,
+};
+/// γ, the Euler–Mascheroni constant
+pub const EULER_GAMMA : Constant = Constant {
+    letter: GreekAlphabet::Gamma, symbol: "γ", name: "Euler–Mascheroni constant", latex: "\\gamma",
+    value: 0.5772156649015329, unit: "", exactness: Exactness::Mathematical,
+};
+/// ζ(3), Apéry's constant
+pub const APERY : Constant = Constant {
+    letter: GreekAlphabet::Zeta, symbol: "ζ(3)", name: "Apéry's constant", latex: "\\zeta(3)",
+    value: 1.2020569031595942, unit: "", exactness: Exactness::Mathematical,
+};
+/// δ, the first Feigenbaum constant
+pub const FEIGENBAUM_DELTA : Constant = Constant {
+    letter: GreekAlphabet::Delta, symbol: "δ", name: "first Feigenbaum constant", latex: "\\delta",
+    value: 4.66920160910299, unit: "", exactness: Exactness::Mathematical,
+};
+/// α, the second Feigenbaum constant
+pub const FEIGENBAUM_ALPHA : Constant = Constant {
+    letter: GreekAlphabet::Alpha, symbol: "α", name: "second Feigenbaum constant", latex: "\\alpha",
+    value: 2.502907875095893, unit: "", exactness: Exactness::Mathematical,
+};
+/// ϖ, the lemniscate constant
+pub const LEMNISCATE : Constant = Constant {
+    letter: GreekAlphabet::Pi, symbol: "ϖ", name: "lemniscate constant", latex: "\\varpi",
+    value: 2.622057554292119, unit: "", exactness: Exactness::Mathematical,
+};
+/// ρ, the plastic number, the real root of x³ = x + 1
+pub const PLASTIC_NUMBER : Constant = Constant {
+    letter: GreekAlphabet::Rho, symbol: "ρ", name: "plastic number", latex: "\\rho",
+    value: 1.324717957244746, unit: "", exactness: Exactness::Mathematical,
+};
+/// Ω, the omega constant, the solution of Ω·e^Ω = 1
+pub const OMEGA : Constant = Constant {
+    letter: GreekAlphabet::Omega, symbol: "Ω", name: "omega constant", latex: "\\Omega",
+    value: 0.5671432904097838, unit: "", exactness: Exactness::Mathematical,
+};
+
+/// α, the fine-structure constant
+pub const FINE_STRUCTURE : Constant = Constant {
+    letter: GreekAlphabet::Alpha, symbol: "α", name: "fine-structure constant", latex: "\\alpha",
+    value: 7.2973525693e-3, unit: "", exactness: Exactness::Uncertain(1.1e-12),
+};
+/// ε₀, the vacuum electric permittivity
+pub const VACUUM_PERMITTIVITY : Constant = Constant {
+    letter: GreekAlphabet::Epsilon, symbol: "ε₀", name: "vacuum electric permittivity", latex: "\\varepsilon_0",
+    value: 8.8541878128e-12, unit: "F m⁻¹", exactness: Exactness::Uncertain(1.3e-21),
+};
+/// μ₀, the vacuum magnetic permeability
+pub const VACUUM_PERMEABILITY : Constant = Constant {
+    letter: GreekAlphabet::My, symbol: "μ₀", name: "vacuum magnetic permeability", latex: "\\mu_0",
+    value: 1.25663706212e-6, unit: "N A⁻²", exactness: Exactness::Uncertain(1.9e-16),
+};
+/// σ, the Stefan–Boltzmann constant
+pub const STEFAN_BOLTZMANN : Constant = Constant {
+    letter: GreekAlphabet::Sigma, symbol: "σ", name: "Stefan–Boltzmann constant", latex: "\\sigma",
+    value: 5.670374419e-8, unit: "W m⁻² K⁻⁴", exactness: Exactness::Exact,
+};
+/// μ_B, the Bohr magneton
+pub const BOHR_MAGNETON : Constant = Constant {
+    letter: GreekAlphabet::My, symbol: "μ_B", name: "Bohr magneton", latex: "\\mu_B",
+    value: 9.2740100783e-24, unit: "J T⁻¹", exactness: Exactness::Uncertain(2.8e-33),
+};
+/// μ_N, the nuclear magneton
+pub const NUCLEAR_MAGNETON : Constant = Constant {
+    letter: GreekAlphabet::My, symbol: "μ_N", name: "nuclear magneton", latex: "\\mu_N",
+    value: 5.0507837461e-27, unit: "J T⁻¹", exactness: Exactness::Uncertain(1.5e-36),
+};
+/// λ_C, the Compton wavelength of the electron
+pub const COMPTON_WAVELENGTH : Constant = Constant {
+    letter: GreekAlphabet::Lambda, symbol: "λ_C", name: "Compton wavelength", latex: "\\lambda_C",
+    value: 2.42631023867e-12, unit: "m", exactness: Exactness::Uncertain(7.3e-22),
+};
+/// Φ₀, the magnetic flux quantum
+pub const MAGNETIC_FLUX_QUANTUM : Constant = Constant {
+    letter: GreekAlphabet::Phi, symbol: "Φ₀", name: "magnetic flux quantum", latex: "\\Phi_0",
+    value: 2.067833848e-15, unit: "Wb", exactness: Exactness::Exact,
+};
+
+/// every constant of this module, the mathematical constants first
+pub static ALL : [Constant;18] = [
+    PI, TAU, GOLDEN_RATIO, EULER_GAMMA, APERY, FEIGENBAUM_DELTA, FEIGENBAUM_ALPHA, LEMNISCATE, PLASTIC_NUMBER, OMEGA,
+    FINE_STRUCTURE, VACUUM_PERMITTIVITY, VACUUM_PERMEABILITY, STEFAN_BOLTZMANN, BOHR_MAGNETON, NUCLEAR_MAGNETON,
+    COMPTON_WAVELENGTH, MAGNETIC_FLUX_QUANTUM,
+];
+
+/// returns every constant whose symbol is the given letter, in the order of [`ALL`]
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::GreekAlphabet;
+/// # use math_sd::nss::constants;
+/// let my : Vec<&str> = constants::by_letter(GreekAlphabet::My).iter().map(|c| c.symbol()).collect();
+/// assert_eq!(vec!["μ₀", "μ_B", "μ_N"], my);
+/// assert!(constants::by_letter(GreekAlphabet::Empty).is_empty());
+/// ```
+pub fn by_letter(letter: GreekAlphabet) -> Vec<&'static Constant> {
+    ALL.iter().filter(|constant| constant.letter == letter).collect()
+}
+
+/// returns the constant with the given symbol
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::constants;
+/// assert_eq!(Some(&constants::STEFAN_BOLTZMANN), constants::by_symbol("σ"));
+/// assert_eq!(None, constants::by_symbol("x"));
+/// ```
+pub fn by_symbol(symbol: &str) -> Option<&'static Constant> {
+    ALL.iter().find(|constant| constant.symbol == symbol)
+}
@@ -0,0 +1,416 @@
+use super::{BigUint, SiPrefix};
+
+use std::convert::TryFrom;
+use std::time::Duration;
+
+// the SI prefixes for data sizes, from kilo to quetta
+const SI_PREFIXES : [SiPrefix;10] = [
+    SiPrefix::Kilo, SiPrefix::Mega, SiPrefix::Giga, SiPrefix::Tera, SiPrefix::Peta,
+    SiPrefix::Exa, SiPrefix::Zetta, SiPrefix::Yotta, SiPrefix::Ronna, SiPrefix::Quetta,
+];
+// the IEC binary prefixes, the n-th stands for 1024^(n + 1)
+const IEC_PREFIXES : [&str;8] = ["Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"];
+// the decimal places which are kept when parsing, a half bit of the largest unit 8 · 1024⁸ bits is 2⁻⁸⁴,
+// which has 84 decimal places, so a sticky digit after them rounds as all digits would
+const FRACTION_DIGITS : usize = 90;
+
+/// the prefixes a [`DataSize`] is formatted with
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub enum PrefixSystem {
+    /// the decimal SI prefixes, `kB` is 1000 bytes
+    #[default]
+    Si,
+    /// the binary IEC prefixes, `KiB` is 1024 bytes
+    Iec,
+}
+
+impl PrefixSystem {
+    // (symbol, factor) of the prefixes, from the largest to the smallest
+    fn prefixes(&self) -> Vec<(&'static str, u128)> {
+        match self {
+            PrefixSystem::Si    =>  SI_PREFIXES.iter().rev().map(|prefix| {
+                let symbol = if *prefix == SiPrefix::Kilo { "k" } else { prefix.symbol() };
+                (symbol, 10u128.pow(prefix.exponent() as u32))
+            }).collect(),
+            PrefixSystem::Iec   =>  IEC_PREFIXES.iter().enumerate().rev().map(|(i, symbol)| (*symbol, 1u128 << (10 * (i + 1)))).collect()
+        }
+    }
+}
+
+/// an error of parsing a [`DataSize`]
+///
+/// The offsets are byte offsets into the text.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum DataSizeError {
+    /// the text is empty or only whitespace
+    Empty,
+    /// a character where a number was expected, as `(offset, char)`
+    Unexpected(usize, char),
+    /// a unit which is neither bytes nor bits with a prefix, as `(offset, unit)`
+    UnknownUnit(usize, String),
+    /// the size does not fit into `u128` bits
+    Overflow,
+}
+
+impl std::fmt::Display for DataSizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            DataSizeError::Empty                        =>  write!(f,"empty text"),
+            DataSizeError::Unexpected(offset, c)        =>  write!(f,"unexpected {:?} at {}",c,offset),
+            DataSizeError::UnknownUnit(offset, unit)    =>  write!(f,"unknown unit {:?} at {}",unit,offset),
+            DataSizeError::Overflow                     =>  write!(f,"data size too large")
+        }
+    }
+}
+impl std::error::Error for DataSizeError {}
+
+/// an amount of digital data, stored exactly as a number of bits
+///
+/// `Display` uses SI prefixes and two decimal places at most, the precision sets the decimal places
+/// and the alternate flag `#` switches to IEC prefixes.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::DataSize;
+/// let size : DataSize = "1.5 GiB".parse().unwrap();
+/// assert_eq!(1610612736, size.bytes());
+/// assert_eq!("1.61 GB", size.to_string());
+/// assert_eq!("1.5 GiB", format!("{:#}", size));
+/// assert_eq!("1.6106 GB", format!("{:.4}", size));
+/// assert_eq!(DataSize::from_bytes(300_000_000), "300MB".parse().unwrap());
+/// assert_eq!(DataSize::from_bits(12), "1.5 B".parse().unwrap());
+/// ```
+# [derive(Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Debug,Default)]
+pub struct DataSize {
+    bits: u128,
+}
+
+impl DataSize {
+    /// the size of nothing
+    pub const ZERO : DataSize = DataSize { bits: 0 };
+
+    /// returns the `DataSize` of a number of bits
+    pub const fn from_bits(bits: u128) -> Self {
+        DataSize { bits }
+    }
+    /// returns the `DataSize` of a number of bytes
+    ///
+    /// # Panics
+    /// Panics when the number of bits does not fit into `u128`.
+    pub const fn from_bytes(bytes: u128) -> Self {
+        match bytes.checked_mul(8) {
+            Some(bits)  =>  DataSize { bits },
+            None        =>  panic!("data size too large")
+        }
+    }
+    /// returns the number of bits
+    pub const fn bits(&self) -> u128 {
+        self.bits
+    }
+    /// returns the number of whole bytes
+    pub const fn bytes(&self) -> u128 {
+        self.bits / 8
+    }
+    /// returns `true` if the size is a whole number of bytes
+    pub const fn is_whole_bytes(&self) -> bool {
+        self.bits.is_multiple_of(8)
+    }
+    /// returns the number of bytes as `f64`, with the remaining bits as fraction
+    pub fn bytes_f64(&self) -> f64 {
+        self.bits as f64 / 8.0
+    }
+    /// returns the sum, or `None` on overflow
+    pub fn checked_add(self, other: DataSize) -> Option<DataSize> {
+        self.bits.checked_add(other.bits).map(DataSize::from_bits)
+    }
+    /// returns the difference, or `None` when `other` is larger
+    pub fn checked_sub(self, other: DataSize) -> Option<DataSize> {
+        self.bits.checked_sub(other.bits).map(DataSize::from_bits)
+    }
+    /// returns the time to transfer the size at the rate,
+    /// or `None` if the rate is not positive and finite or the time does not fit a `Duration`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{DataRate, DataSize};
+    /// # use std::time::Duration;
+    /// let size = DataSize::from_bytes(1_000_000);
+    /// assert_eq!(Some(Duration::from_secs(4)), size.checked_div(DataRate::from_bytes_per_second(250_000.0)));
+    /// assert_eq!(None, size.checked_div(DataRate::from_bits_per_second(0.0)));
+    /// assert_eq!(None, size.checked_div(DataRate::from_bits_per_second(f64::NAN)));
+    /// ```
+    pub fn checked_div(self, rate: DataRate) -> Option<Duration> {
+        if !(rate.bits_per_second > 0.0 && rate.bits_per_second.is_finite()) {
+            return None
+        }
+        Duration::try_from_secs_f64(self.bits as f64 / rate.bits_per_second).ok()
+    }
+    /// returns the size in bytes with the largest prefix which gives at least 1 and `precision` decimal places at most
+    ///
+    /// The value is rounded half up, trailing zeros of the decimal places are dropped.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{DataSize, PrefixSystem};
+    /// let size = DataSize::from_bytes(1_234_567);
+    /// assert_eq!("1.23 MB", size.format(PrefixSystem::Si, 2));
+    /// assert_eq!("1.1774 MiB", size.format(PrefixSystem::Iec, 4));
+    /// assert_eq!("2 kB", DataSize::from_bytes(1_999).format(PrefixSystem::Si, 2));
+    /// assert_eq!("999 B", DataSize::from_bytes(999).format(PrefixSystem::Si, 2));
+    /// assert_eq!("1 MB", DataSize::from_bytes(999_999).to_string());
+    /// assert_eq!("1 MiB", DataSize::from_bytes(1024 * 1024 - 1).format(PrefixSystem::Iec, 2));
+    /// ```
+    pub fn format(&self, system: PrefixSystem, precision: usize) -> String {
+        format_with(self.bits, 8, system, precision, "B")
+    }
+    /// returns the size in bits like [`DataSize::format`], with the units `bit`, `kbit`, `Kibit`, ...
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{DataSize, PrefixSystem};
+    /// assert_eq!("8 kbit", DataSize::from_bytes(1000).format_bits(PrefixSystem::Si, 2));
+    /// ```
+    pub fn format_bits(&self, system: PrefixSystem, precision: usize) -> String {
+        format_with(self.bits, 1, system, precision, "bit")
+    }
+}
+
+impl std::fmt::Display for DataSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let system = if f.alternate() { PrefixSystem::Iec } else { PrefixSystem::Si };
+        write!(f,"{}",self.format(system, f.precision().unwrap_or(2)))
+    }
+}
+
+impl std::str::FromStr for DataSize {
+    type Err = DataSizeError;
+    /// parses a decimal number with a unit, like `1.5 GiB`, `300MB` or `10 kbit`
+    ///
+    /// The unit is `B` for bytes or `b` or `bit` for bits, with an SI prefix (`k` or `K` for kilo) or an IEC prefix.
+    /// A size which is no whole number of bits is rounded half up, also for any number of decimal places.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::DataSize;
+    /// assert_eq!(Ok(DataSize::from_bits(1_500_000)), "1.5 Mbit".parse());
+    /// assert_eq!(Ok(DataSize::from_bytes(1)), "1.0000000000000000000000000000000000000001 B".parse());
+    /// assert_eq!(Ok(DataSize::ZERO), format!("0.0624{} B", "9".repeat(100)).parse());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, DataSizeError> {
+        let start = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(DataSizeError::Empty)
+        }
+        let integer_length = trimmed.find(|c: char| !c.is_ascii_digit()).unwrap_or(trimmed.len());
+        let (fraction, rest) = match trimmed[integer_length..].strip_prefix('.') {
+            Some(after) =>  {
+                let length = after.find(|c: char| !c.is_ascii_digit()).unwrap_or(after.len());
+                (&after[..length], &after[length..])
+            }
+            None        =>  ("", &trimmed[integer_length..])
+        };
+        if integer_length == 0 && fraction.is_empty() {
+            return Err(DataSizeError::Unexpected(start, trimmed.chars().next().unwrap()))
+        }
+        let unit = rest.trim_start();
+        let unit_offset = start + trimmed.len() - unit.len();
+        let unit_bits = unit_bits(unit).ok_or_else(|| DataSizeError::UnknownUnit(unit_offset, unit.to_string()))?;
+        let integer = trimmed[..integer_length].trim_start_matches('0');
+        if integer.len() > 40 {
+            return Err(DataSizeError::Overflow)
+        }
+        // the significant decimal places, with a sticky 1 for the dropped digits which are not all zero
+        let mut fraction = fraction.trim_end_matches('0').to_string();
+        if fraction.len() > FRACTION_DIGITS {
+            fraction.truncate(FRACTION_DIGITS);
+            fraction.push('1');
+        }
+        let digits = BigUint::from_str_radix(&format!("0{}{}", integer, fraction), 10).map_err(|_| DataSizeError::Overflow)?;
+        let scale = BigUint::from(10u8).pow(fraction.len() as u32);
+        let (quotient, remainder) = (digits * BigUint::from(unit_bits)).div_rem(&scale);
+        let bits = if remainder << 1 >= scale { quotient + BigUint::one() } else { quotient };
+        u128::try_from(bits).map(DataSize::from_bits).map_err(|_| DataSizeError::Overflow)
+    }
+}
+
+impl std::ops::Add for DataSize {
+    type Output = DataSize;
+    fn add(self, other: DataSize) -> DataSize {
+        DataSize::from_bits(self.bits + other.bits)
+    }
+}
+impl std::ops::Sub for DataSize {
+    type Output = DataSize;
+    fn sub(self, other: DataSize) -> DataSize {
+        DataSize::from_bits(self.bits - other.bits)
+    }
+}
+impl std::ops::AddAssign for DataSize {
+    fn add_assign(&mut self, other: DataSize) {
+        self.bits += other.bits;
+    }
+}
+impl std::ops::SubAssign for DataSize {
+    fn sub_assign(&mut self, other: DataSize) {
+        self.bits -= other.bits;
+    }
+}
+impl std::ops::Mul<u128> for DataSize {
+    type Output = DataSize;
+    fn mul(self, factor: u128) -> DataSize {
+        DataSize::from_bits(self.bits * factor)
+    }
+}
+impl std::ops::Div<u128> for DataSize {
+    type Output = DataSize;
+    fn div(self, divisor: u128) -> DataSize {
+        DataSize::from_bits(self.bits / divisor)
+    }
+}
+impl std::iter::Sum for DataSize {
+    fn sum<I: Iterator<Item = DataSize>>(iter: I) -> Self {
+        iter.fold(DataSize::ZERO, |sum, size| sum + size)
+    }
+}
+
+/// a transfer rate of digital data, in bits per second
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::DataSize;
+/// # use std::time::Duration;
+/// let size : DataSize = "100 MB".parse().unwrap();
+/// let rate = size / Duration::from_secs(8);
+/// assert_eq!("12.5 MB/s", rate.to_string());
+/// assert_eq!("100 Mbit/s", rate.format_bits(Default::default(), 2));
+/// assert_eq!(Duration::from_secs(16), DataSize::from_bytes(200_000_000) / rate);
+/// assert_eq!(DataSize::from_bytes(25_000_000), rate * Duration::from_secs(2));
+/// ```
+# [derive(Clone,Copy,PartialEq,PartialOrd,Debug,Default)]
+pub struct DataRate {
+    bits_per_second: f64,
+}
+
+impl DataRate {
+    /// returns the `DataRate` of a number of bits per second
+    pub fn from_bits_per_second(bits_per_second: f64) -> Self {
+        DataRate { bits_per_second }
+    }
+    /// returns the `DataRate` of a number of bytes per second
+    pub fn from_bytes_per_second(bytes_per_second: f64) -> Self {
+        DataRate { bits_per_second: 8.0 * bytes_per_second }
+    }
+    /// returns the number of bits per second
+    pub fn bits_per_second(&self) -> f64 {
+        self.bits_per_second
+    }
+    /// returns the number of bytes per second
+    pub fn bytes_per_second(&self) -> f64 {
+        self.bits_per_second / 8.0
+    }
+    /// returns the rate in bytes per second like [`DataSize::format`]
+    pub fn format(&self, system: PrefixSystem, precision: usize) -> String {
+        format!("{}/s",format_with(self.bits_per_second.round() as u128, 8, system, precision, "B"))
+    }
+    /// returns the rate in bits per second like [`DataSize::format_bits`]
+    pub fn format_bits(&self, system: PrefixSystem, precision: usize) -> String {
+        format!("{}/s",format_with(self.bits_per_second.round() as u128, 1, system, precision, "bit"))
+    }
+}
+
+impl std::fmt::Display for DataRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let system = if f.alternate() { PrefixSystem::Iec } else { PrefixSystem::Si };
+        write!(f,"{}",self.format(system, f.precision().unwrap_or(2)))
+    }
+}
+
+impl std::ops::Div<Duration> for DataSize {
+    type Output = DataRate;
+    fn div(self, duration: Duration) -> DataRate {
+        DataRate::from_bits_per_second(self.bits as f64 / duration.as_secs_f64())
+    }
+}
+impl std::ops::Div<DataRate> for DataSize {
+    type Output = Duration;
+    /// returns the time to transfer the size at the rate
+    ///
+    /// # Panics
+    /// if the rate is not positive and finite or the time does not fit a `Duration`,
+    /// [`DataSize::checked_div`] returns `None` instead
+    fn div(self, rate: DataRate) -> Duration {
+        self.checked_div(rate).expect("the data rate is not positive and finite or the duration overflows")
+    }
+}
+impl std::ops::Mul<Duration> for DataRate {
+    type Output = DataSize;
+    fn mul(self, duration: Duration) -> DataSize {
+        DataSize::from_bits((self.bits_per_second * duration.as_secs_f64()).round() as u128)
+    }
+}
+
+// the number of bits of a unit like `MB`, `Kibit` or `b`
+fn unit_bits(unit: &str) -> Option<u128> {
+    let (prefix, bits) = if let Some(prefix) = unit.strip_suffix("bit") {
+        (prefix, 1)
+    } else if let Some(prefix) = unit.strip_suffix('B') {
+        (prefix, 8)
+    } else if let Some(prefix) = unit.strip_suffix('b') {
+        (prefix, 1)
+    } else {
+        return None
+    };
+    let factor = match prefix {
+        ""  =>  1,
+        "K" =>  1000,
+        _   =>  PrefixSystem::Si.prefixes().into_iter().chain(PrefixSystem::Iec.prefixes())
+                    .find(|(symbol, _)| *symbol == prefix)?.1
+    };
+    factor.checked_mul(bits)
+}
+
+// formats `bits` in units of `unit_bits` with the largest prefix which gives at least 1
+fn format_with(bits: u128, unit_bits: u128, system: PrefixSystem, precision: usize, unit: &str) -> String {
+    let (prefix, divisor) = system.prefixes().into_iter()
+        .map(|(symbol, factor)| (symbol, factor * unit_bits))
+        .find(|(_, divisor)| bits >= *divisor)
+        .unwrap_or(("", unit_bits));
+    let mut whole = bits / divisor;
+    let mut remainder = bits % divisor;
+    let mut decimals = Vec::with_capacity(precision);
+    for _ in 0..precision {
+        remainder *= 10;
+        decimals.push((remainder / divisor) as u8);
+        remainder %= divisor;
+    }
+    // rounding half up, carrying into the whole number
+    if 2 * remainder >= divisor {
+        let mut carry = true;
+        for decimal in decimals.iter_mut().rev() {
+            *decimal += 1;
+            carry = *decimal == 10;
+            if !carry {
+                break
+            }
+            *decimal = 0;
+        }
+        if carry {
+            whole += 1;
+        }
+    }
+    // a carry up to the next prefix, like 999.9996 kB to 1 MB
+    let (prefix, whole) = match system.prefixes().into_iter().rev().find(|(_, factor)| factor * unit_bits > divisor) {
+        Some((next, factor)) if whole * divisor == factor * unit_bits   =>  (next, 1),
+        _                                                               =>  (prefix, whole)
+    };
+    while decimals.last() == Some(&0) {
+        decimals.pop();
+    }
+    let mut number = whole.to_string();
+    if !decimals.is_empty() {
+        number.push('.');
+        number.extend(decimals.iter().map(|decimal| char::from(b'0' + decimal)));
+    }
+    format!("{} {}{}",number,prefix,unit)
+}
@@ -0,0 +1,188 @@
+use super::{BigUint, GreekAlphabet};
+
+/// an irrational constant whose digits can be generated
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Irrational;
+/// let e : Vec<u8> = Irrational::E.digits().take(8).collect();
+/// assert_eq!(vec![2, 7, 1, 8, 2, 8, 1, 8], e);
+/// let pi : Vec<u8> = Irrational::Pi.hex_digits().take(6).collect();
+/// assert_eq!(vec![0x3, 0x2, 0x4, 0x3, 0xF, 0x6], pi);
+/// ```
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum Irrational {
+    /// π, from the Bailey–Borwein–Plouffe formula
+    Pi,
+    /// τ = 2π
+    Tau,
+    /// Euler's number, from the series of 1/k!
+    E,
+    /// √2, from the integer square root
+    Sqrt2,
+    /// φ = (1 + √5) / 2, the golden ratio
+    Phi,
+    /// ln 2 = 2 artanh(1/3)
+    Ln2,
+}
+
+impl Irrational {
+    /// returns the decimal digits, starting with the integer part
+    pub fn digits(&self) -> Digits {
+        self.digits_radix(10)
+    }
+    /// returns the hexadecimal digits, starting with the integer part
+    pub fn hex_digits(&self) -> Digits {
+        self.digits_radix(16)
+    }
+    /// returns the digits in the given radix, starting with the integer part
+    ///
+    /// # Panics
+    /// Panics when `radix` is not in `2..=36`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Irrational;
+    /// let sqrt2 : Vec<u8> = Irrational::Sqrt2.digits_radix(2).take(8).collect();
+    /// assert_eq!(vec![1, 0, 1, 1, 0, 1, 0, 1], sqrt2);
+    /// let tau : Vec<u8> = Irrational::Tau.digits_radix(2).take(4).collect();
+    /// assert_eq!(vec![1, 1, 0, 0], tau);
+    /// ```
+    pub fn digits_radix(&self, radix: u32) -> Digits {
+        if !(2..=36).contains(&radix) {
+            panic!("radix {} not in 2..=36", radix)
+        }
+        Digits { irrational: *self, radix, precision: 0, digits: Vec::new(), position: 0 }
+    }
+
+    // floor(self · radix^precision), up to an error of a few units
+    fn scaled(&self, radix: u32, precision: usize) -> BigUint {
+        let one = BigUint::from(radix).pow(precision as u32);
+        match self {
+            Irrational::Pi      =>  bbp(&one),
+            Irrational::Tau     =>  bbp(&one) << 1,
+            Irrational::E       =>  {
+                let mut e = one.clone();
+                let mut term = one;
+                let mut k = 1u32;
+                while !term.is_zero() {
+                    term /= BigUint::from(k);
+                    e += &term;
+                    k += 1;
+                }
+                e
+            }
+            Irrational::Sqrt2   =>  (&one * &one * BigUint::from(2u8)).sqrt(),
+            Irrational::Phi     =>  ((&one * &one * BigUint::from(5u8)).sqrt() + &one) >> 1,
+            Irrational::Ln2     =>  {
+                let mut ln2 = BigUint::zero();
+                let mut power = (one << 1) / BigUint::from(3u8);
+                let mut k = 0u32;
+                while !power.is_zero() {
+                    ln2 += &power / BigUint::from(2 * k + 1);
+                    power /= BigUint::from(9u8);
+                    k += 1;
+                }
+                ln2
+            }
+        }
+    }
+}
+
+impl GreekAlphabet {
+    /// returns the decimal digits of the constant named by `GreekAlphabet`, starting with the integer part
+    ///
+    /// `Pi` gives π, `Tau` gives τ = 2π and `Phi` gives the golden ratio φ.
+    ///
+    /// # Panics
+    /// Panics when called on another letter.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::GreekAlphabet;
+    /// let pi : String = GreekAlphabet::Pi.digits().take(10).map(|d| char::from(b'0' + d)).collect();
+    /// assert_eq!("3141592653", pi);
+    /// let phi : Vec<u8> = GreekAlphabet::Phi.digits().take(6).collect();
+    /// assert_eq!(vec![1, 6, 1, 8, 0, 3], phi);
+    /// ```
+    pub fn digits(&self) -> Digits {
+        match self {
+            GreekAlphabet::Pi   =>  Irrational::Pi.digits(),
+            GreekAlphabet::Tau  =>  Irrational::Tau.digits(),
+            GreekAlphabet::Phi  =>  Irrational::Phi.digits(),
+            _                   =>  panic!("no digits for {:?}", self)
+        }
+    }
+}
+
+/// an endless iterator over the digits of an [`Irrational`]
+///
+/// The digits are computed in blocks, each block with twice the precision of the one before,
+/// so taking `n` digits costs about as much as computing `n` digits at once.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::GreekAlphabet;
+/// let pi : Vec<u8> = GreekAlphabet::Pi.digits().skip(995).take(7).collect();
+/// assert_eq!(vec![2, 0, 1, 9, 8, 9, 3], pi);
+/// ```
+# [derive(Clone,Debug)]
+pub struct Digits {
+    irrational: Irrational,
+    radix: u32,
+    precision: usize,
+    digits: Vec<u8>,
+    position: usize,
+}
+
+impl Digits {
+    /// returns the constant of the digits
+    pub fn irrational(&self) -> Irrational {
+        self.irrational
+    }
+    /// returns the radix of the digits
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+    // computes the digits again with twice the precision
+    fn refine(&mut self) {
+        self.precision = (2 * self.precision).max(64);
+        let guard = 64 / (31 - self.radix.leading_zeros() as usize) + 1;
+        let scaled = self.irrational.scaled(self.radix, self.precision + guard) / BigUint::from(self.radix).pow(guard as u32);
+        let scaled_digits = scaled.radix_digits(self.radix);
+        // the integer part has at least one digit
+        let mut digits = vec![0; (self.precision + 1).saturating_sub(scaled_digits.len())];
+        digits.extend(scaled_digits);
+        self.digits = digits;
+    }
+}
+
+impl std::iter::Iterator for Digits {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> {
+        if self.position >= self.digits.len() {
+            self.refine();
+        }
+        self.position += 1;
+        Some(self.digits[self.position - 1])
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+// π · one = Σ (4/(8k+1) − 2/(8k+4) − 1/(8k+5) − 1/(8k+6)) · one / 16^k
+fn bbp(one: &BigUint) -> BigUint {
+    let mut pi = BigUint::zero();
+    let mut power = one.clone();
+    let mut k = 0u32;
+    while !power.is_zero() {
+        pi += (&power << 2) / BigUint::from(8 * k + 1);
+        for (shift, denominator) in [(1, 8 * k + 4), (0, 8 * k + 5), (0, 8 * k + 6)] {
+            pi -= (&power << shift) / BigUint::from(denominator);
+        }
+        power >>= 4;
+        k += 1;
+    }
+    pi
+}
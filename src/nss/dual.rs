@@ -0,0 +1,600 @@
+use super::Real;
+
+/// a dual number `a + bε` with `ε² = 0` for forward mode automatic differentiation
+///
+/// A function of [`Dual::var(x)`](Dual::var) returns the value at `x` in the real part
+/// and the exact derivative at `x` in the part of `ε`.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Dual;
+/// let f = |x: Dual<f64>| x.powi(3) + x * 2.0 - 1.0;
+/// let y = f(Dual::var(2.0));
+/// assert_eq!(11.0, y.value());
+/// assert_eq!(14.0, y.derivative());
+/// let g = |x: Dual<f64>| (x * x).sin() / x;
+/// let y = g(Dual::var(1.5));
+/// assert!((y.derivative() - (2.0 * 2.25f64.cos() - 2.25f64.sin() / 2.25)).abs() < 1e-15);
+/// assert_eq!("3 + 2ε", Dual::new(3.0, 2.0).to_string());
+/// ```
+# [derive(Clone,Copy,PartialEq,Debug,Default)]
+pub struct Dual<T: Real> {
+    value: T,
+    derivative: T,
+}
+
+impl<T: Real> Dual<T> {
+    /// returns `value + derivative·ε`
+    pub fn new(value: T, derivative: T) -> Self {
+        Dual { value, derivative }
+    }
+    /// returns a constant, of which the derivative is `0`
+    pub fn constant(value: T) -> Self {
+        Dual::new(value, T::zero())
+    }
+    /// returns the variable of the differentiation, of which the derivative is `1`
+    pub fn var(value: T) -> Self {
+        Dual::new(value, T::one())
+    }
+    /// returns the value
+    pub fn value(&self) -> T {
+        self.value
+    }
+    /// returns the derivative
+    pub fn derivative(&self) -> T {
+        self.derivative
+    }
+
+    // applies a function with the value and the first two derivatives at the value
+    fn chain(&self, value: T, first: T, _second: T) -> Self {
+        Dual::new(value, first * self.derivative)
+    }
+    fn add_dual(self, other: Self) -> Self {
+        Dual::new(self.value + other.value, self.derivative + other.derivative)
+    }
+    fn sub_dual(self, other: Self) -> Self {
+        Dual::new(self.value - other.value, self.derivative - other.derivative)
+    }
+    fn mul_dual(self, other: Self) -> Self {
+        Dual::new(self.value * other.value, self.value * other.derivative + self.derivative * other.value)
+    }
+    fn div_dual(self, other: Self) -> Self {
+        let quotient = self.value / other.value;
+        Dual::new(quotient, (self.derivative - quotient * other.derivative) / other.value)
+    }
+    fn add_real(self, other: T) -> Self {
+        Dual::new(self.value + other, self.derivative)
+    }
+    fn sub_real(self, other: T) -> Self {
+        Dual::new(self.value - other, self.derivative)
+    }
+    fn mul_real(self, other: T) -> Self {
+        Dual::new(self.value * other, self.derivative * other)
+    }
+    fn div_real(self, other: T) -> Self {
+        Dual::new(self.value / other, self.derivative / other)
+    }
+}
+
+/// a hyper-dual number `a + bε₁ + cε₂ + dε₁ε₂` with `ε₁² = ε₂² = 0` for exact second derivatives
+///
+/// A function of [`HyperDual::var(x)`](HyperDual::var) returns the value, the first and the second derivative at `x`.
+/// Mixed partial derivatives `∂²f/∂x∂y` are the part of `ε₁ε₂`
+/// when `x` has the part `1` of `ε₁` and `y` the part `1` of `ε₂`.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::HyperDual;
+/// let f = |x: HyperDual<f64>| x.powi(4) - x * 3.0;
+/// let y = f(HyperDual::var(2.0));
+/// assert_eq!((10.0, 29.0, 48.0), (y.value(), y.derivative(), y.second_derivative()));
+/// let x = HyperDual::new(3.0, 1.0, 0.0, 0.0);
+/// let y = HyperDual::new(0.5, 0.0, 1.0, 0.0);
+/// let g = x * x * y.exp();
+/// assert_eq!(2.0 * 3.0 * 0.5f64.exp(), g.eps1eps2());
+/// ```
+# [derive(Clone,Copy,PartialEq,Debug,Default)]
+pub struct HyperDual<T: Real> {
+    value: T,
+    eps1: T,
+    eps2: T,
+    eps1eps2: T,
+}
+
+impl<T: Real> HyperDual<T> {
+    /// returns `value + eps1·ε₁ + eps2·ε₂ + eps1eps2·ε₁ε₂`
+    pub fn new(value: T, eps1: T, eps2: T, eps1eps2: T) -> Self {
+        HyperDual { value, eps1, eps2, eps1eps2 }
+    }
+    /// returns a constant, of which the derivatives are `0`
+    pub fn constant(value: T) -> Self {
+        HyperDual::new(value, T::zero(), T::zero(), T::zero())
+    }
+    /// returns the variable of the differentiation, with the parts `1` of `ε₁` and `ε₂`
+    pub fn var(value: T) -> Self {
+        HyperDual::new(value, T::one(), T::one(), T::zero())
+    }
+    /// returns the value
+    pub fn value(&self) -> T {
+        self.value
+    }
+    /// returns the part of `ε₁`
+    pub fn eps1(&self) -> T {
+        self.eps1
+    }
+    /// returns the part of `ε₂`
+    pub fn eps2(&self) -> T {
+        self.eps2
+    }
+    /// returns the part of `ε₁ε₂`
+    pub fn eps1eps2(&self) -> T {
+        self.eps1eps2
+    }
+    /// returns the first derivative, the part of `ε₁`
+    pub fn derivative(&self) -> T {
+        self.eps1
+    }
+    /// returns the second derivative, the part of `ε₁ε₂`
+    pub fn second_derivative(&self) -> T {
+        self.eps1eps2
+    }
+
+    // applies a function with the value and the first two derivatives at the value
+    fn chain(&self, value: T, first: T, second: T) -> Self {
+        HyperDual::new(
+            value,
+            first * self.eps1,
+            first * self.eps2,
+            first * self.eps1eps2 + second * self.eps1 * self.eps2
+        )
+    }
+    fn add_dual(self, other: Self) -> Self {
+        HyperDual::new(self.value + other.value, self.eps1 + other.eps1, self.eps2 + other.eps2, self.eps1eps2 + other.eps1eps2)
+    }
+    fn sub_dual(self, other: Self) -> Self {
+        HyperDual::new(self.value - other.value, self.eps1 - other.eps1, self.eps2 - other.eps2, self.eps1eps2 - other.eps1eps2)
+    }
+    fn mul_dual(self, other: Self) -> Self {
+        HyperDual::new(
+            self.value * other.value,
+            self.value * other.eps1 + self.eps1 * other.value,
+            self.value * other.eps2 + self.eps2 * other.value,
+            self.value * other.eps1eps2 + self.eps1 * other.eps2 + self.eps2 * other.eps1 + self.eps1eps2 * other.value
+        )
+    }
+    fn div_dual(self, other: Self) -> Self {
+        self.mul_dual(other.recip())
+    }
+    fn add_real(self, other: T) -> Self {
+        HyperDual::new(self.value + other, self.eps1, self.eps2, self.eps1eps2)
+    }
+    fn sub_real(self, other: T) -> Self {
+        HyperDual::new(self.value - other, self.eps1, self.eps2, self.eps1eps2)
+    }
+    fn mul_real(self, other: T) -> Self {
+        HyperDual::new(self.value * other, self.eps1 * other, self.eps2 * other, self.eps1eps2 * other)
+    }
+    fn div_real(self, other: T) -> Self {
+        HyperDual::new(self.value / other, self.eps1 / other, self.eps2 / other, self.eps1eps2 / other)
+    }
+}
+
+/// a dual number with a gradient of `N` variables for forward mode automatic differentiation
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::DualN;
+/// // the Rosenbrock function
+/// let f = |[x, y]: [DualN<f64, 2>; 2]| (-x + 1.0).powi(2) + (y - x * x).powi(2) * 100.0;
+/// let z = f(DualN::variables([2.0, 3.0]));
+/// assert_eq!(101.0, z.value());
+/// assert_eq!([802.0, -200.0], z.gradient());
+/// assert_eq!("1 + [0, 1]ε", DualN::<f64, 2>::var(1.0, 1).to_string());
+/// ```
+# [derive(Clone,Copy,PartialEq,Debug)]
+pub struct DualN<T: Real, const N: usize> {
+    value: T,
+    gradient: [T; N],
+}
+
+impl<T: Real, const N: usize> DualN<T, N> {
+    /// returns `value + gradient·ε`
+    pub fn new(value: T, gradient: [T; N]) -> Self {
+        DualN { value, gradient }
+    }
+    /// returns a constant, of which the gradient is `0`
+    pub fn constant(value: T) -> Self {
+        DualN::new(value, [T::zero(); N])
+    }
+    /// returns the variable with an index, of which the gradient is `1` at the index and `0` elsewhere
+    ///
+    /// # Panics
+    /// if the index is not less than `N`
+    pub fn var(value: T, index: usize) -> Self {
+        let mut gradient = [T::zero(); N];
+        gradient[index] = T::one();
+        DualN::new(value, gradient)
+    }
+    /// returns the variables of the values, of which the indices are the indices of the values
+    pub fn variables(values: [T; N]) -> [Self; N] {
+        std::array::from_fn(|index| DualN::var(values[index], index))
+    }
+    /// returns the value
+    pub fn value(&self) -> T {
+        self.value
+    }
+    /// returns the gradient, the partial derivatives of the variables
+    pub fn gradient(&self) -> [T; N] {
+        self.gradient
+    }
+
+    // applies a function with the value and the first two derivatives at the value
+    fn chain(&self, value: T, first: T, _second: T) -> Self {
+        DualN::new(value, self.gradient.map(|part| first * part))
+    }
+    // applies a function to the parts of the gradients
+    fn zip(&self, other: &Self, function: impl Fn(T, T) -> T) -> [T; N] {
+        std::array::from_fn(|index| function(self.gradient[index], other.gradient[index]))
+    }
+    fn add_dual(self, other: Self) -> Self {
+        DualN::new(self.value + other.value, self.zip(&other, |a, b| a + b))
+    }
+    fn sub_dual(self, other: Self) -> Self {
+        DualN::new(self.value - other.value, self.zip(&other, |a, b| a - b))
+    }
+    fn mul_dual(self, other: Self) -> Self {
+        DualN::new(self.value * other.value, self.zip(&other, |a, b| self.value * b + a * other.value))
+    }
+    fn div_dual(self, other: Self) -> Self {
+        let quotient = self.value / other.value;
+        DualN::new(quotient, self.zip(&other, |a, b| (a - quotient * b) / other.value))
+    }
+    fn add_real(self, other: T) -> Self {
+        DualN::new(self.value + other, self.gradient)
+    }
+    fn sub_real(self, other: T) -> Self {
+        DualN::new(self.value - other, self.gradient)
+    }
+    fn mul_real(self, other: T) -> Self {
+        DualN::new(self.value * other, self.gradient.map(|part| part * other))
+    }
+    fn div_real(self, other: T) -> Self {
+        DualN::new(self.value / other, self.gradient.map(|part| part / other))
+    }
+}
+
+impl<T: Real, const N: usize> std::default::Default for DualN<T, N> {
+    fn default() -> Self {
+        DualN::constant(T::zero())
+    }
+}
+
+// the elementary functions by the chain rule
+macro_rules! impl_dual_functions {
+    ($([$($generics:tt)*] $dual:ty;)*) => {
+        $(
+            impl<$($generics)*> $dual {
+                /// returns `1/x`
+                pub fn recip(&self) -> Self {
+                    let recip = self.value.recip_real();
+                    self.chain(recip, -recip * recip, (recip + recip) * recip * recip)
+                }
+                /// returns the absolute value, with the derivative `1` or `-1` at `±0` by the sign of zero
+                pub fn abs(&self) -> Self {
+                    self.chain(self.value.abs(), T::one().copysign(self.value), T::zero())
+                }
+                /// returns `x` to an integer power
+                pub fn powi(&self, n: i32) -> Self {
+                    let power = T::from_f64(n as f64);
+                    let a = self.value;
+                    let first = if n == 0 { T::zero() } else { power * n.checked_sub(1).map_or_else(|| a.powi(n) / a, |m| a.powi(m)) };
+                    let second = if n == 0 || n == 1 {
+                        T::zero()
+                    } else {
+                        power * (power - T::one()) * n.checked_sub(2).map_or_else(|| a.powi(n) / a / a, |m| a.powi(m))
+                    };
+                    self.chain(self.value.powi(n), first, second)
+                }
+                /// returns `x` to a real power
+                pub fn powf(&self, n: T) -> Self {
+                    let first = n * self.value.powf(n - T::one());
+                    let second = n * (n - T::one()) * self.value.powf(n - T::one() - T::one());
+                    self.chain(self.value.powf(n), first, second)
+                }
+                /// returns `x` to the power of a dual number, for positive `x`
+                pub fn pow(&self, n: &Self) -> Self {
+                    (self.ln() * *n).exp()
+                }
+                /// returns the square root
+                pub fn sqrt(&self) -> Self {
+                    let root = self.value.sqrt();
+                    let first = (root + root).recip_real();
+                    self.chain(root, first, -first / (self.value + self.value))
+                }
+                /// returns the cube root
+                pub fn cbrt(&self) -> Self {
+                    let root = self.value.cbrt();
+                    let three = T::from_f64(3.0);
+                    let first = (three * root * root).recip_real();
+                    self.chain(root, first, -(first + first) / (three * self.value))
+                }
+                /// returns `eˣ`
+                pub fn exp(&self) -> Self {
+                    let exp = self.value.exp();
+                    self.chain(exp, exp, exp)
+                }
+                /// returns `eˣ - 1`, which is accurate near `0`
+                pub fn exp_m1(&self) -> Self {
+                    let exp = self.value.exp();
+                    self.chain(self.value.exp_m1(), exp, exp)
+                }
+                /// returns the natural logarithm
+                pub fn ln(&self) -> Self {
+                    let recip = self.value.recip_real();
+                    self.chain(self.value.ln(), recip, -recip * recip)
+                }
+                /// returns `ln(1 + x)`, which is accurate near `0`
+                pub fn ln_1p(&self) -> Self {
+                    let recip = (T::one() + self.value).recip_real();
+                    self.chain(self.value.ln_1p(), recip, -recip * recip)
+                }
+                /// returns the logarithm to the base 10
+                pub fn log10(&self) -> Self {
+                    let recip = (self.value * T::from_f64(std::f64::consts::LN_10)).recip_real();
+                    self.chain(self.value.log10(), recip, -recip / self.value)
+                }
+                /// returns the sine
+                pub fn sin(&self) -> Self {
+                    let (sin, cos) = (self.value.sin(), self.value.cos());
+                    self.chain(sin, cos, -sin)
+                }
+                /// returns the cosine
+                pub fn cos(&self) -> Self {
+                    let (sin, cos) = (self.value.sin(), self.value.cos());
+                    self.chain(cos, -sin, -cos)
+                }
+                /// returns the tangent
+                pub fn tan(&self) -> Self {
+                    let tan = self.value.tan();
+                    let first = T::one() + tan * tan;
+                    self.chain(tan, first, (tan + tan) * first)
+                }
+                /// returns the arcsine
+                pub fn asin(&self) -> Self {
+                    let first = (T::one() - self.value * self.value).sqrt().recip_real();
+                    self.chain(self.value.asin(), first, self.value * first * first * first)
+                }
+                /// returns the arccosine
+                pub fn acos(&self) -> Self {
+                    let first = (T::one() - self.value * self.value).sqrt().recip_real();
+                    self.chain(self.value.acos(), -first, -self.value * first * first * first)
+                }
+                /// returns the arctangent
+                pub fn atan(&self) -> Self {
+                    let first = (T::one() + self.value * self.value).recip_real();
+                    self.chain(self.value.atan(), first, -(self.value + self.value) * first * first)
+                }
+                /// returns the angle of the point `(other, self)` in `-π..=π`
+                pub fn atan2(&self, other: &Self) -> Self {
+                    let angle = self.value.atan2(other.value);
+                    // the arctangent of the smaller quotient differs from the angle by a constant
+                    let base = if other.value.abs() >= self.value.abs() {
+                        (*self / *other).atan()
+                    } else {
+                        -(*other / *self).atan()
+                    };
+                    base + (angle - base.value)
+                }
+                /// returns `√(x² + y²)` without an overflow of the squares
+                pub fn hypot(&self, other: &Self) -> Self {
+                    let scale = self.value.abs().max(other.value.abs());
+                    if scale == T::zero() {
+                        return (*self * *self + *other * *other).sqrt()
+                    }
+                    let (x, y) = (*self / scale, *other / scale);
+                    (x * x + y * y).sqrt() * scale
+                }
+                /// returns the hyperbolic sine
+                pub fn sinh(&self) -> Self {
+                    let (sinh, cosh) = (self.value.sinh(), self.value.cosh());
+                    self.chain(sinh, cosh, sinh)
+                }
+                /// returns the hyperbolic cosine
+                pub fn cosh(&self) -> Self {
+                    let (sinh, cosh) = (self.value.sinh(), self.value.cosh());
+                    self.chain(cosh, sinh, cosh)
+                }
+                /// returns the hyperbolic tangent
+                pub fn tanh(&self) -> Self {
+                    let tanh = self.value.tanh();
+                    let first = T::one() - tanh * tanh;
+                    self.chain(tanh, first, -(tanh + tanh) * first)
+                }
+                /// returns the inverse hyperbolic sine
+                pub fn asinh(&self) -> Self {
+                    let first = (self.value * self.value + T::one()).sqrt().recip_real();
+                    self.chain(self.value.asinh(), first, -self.value * first * first * first)
+                }
+                /// returns the inverse hyperbolic cosine
+                pub fn acosh(&self) -> Self {
+                    let first = (self.value * self.value - T::one()).sqrt().recip_real();
+                    self.chain(self.value.acosh(), first, -self.value * first * first * first)
+                }
+                /// returns the inverse hyperbolic tangent
+                pub fn atanh(&self) -> Self {
+                    let first = (T::one() - self.value * self.value).recip_real();
+                    self.chain(self.value.atanh(), first, (self.value + self.value) * first * first)
+                }
+            }
+        )*
+    };
+}
+impl_dual_functions! {
+    [T: Real] Dual<T>;
+    [T: Real] HyperDual<T>;
+    [T: Real, const N: usize] DualN<T, N>;
+}
+
+// `1/x` of the real numbers, which have no such method
+trait Recip {
+    fn recip_real(self) -> Self;
+}
+impl<T: Real> Recip for T {
+    fn recip_real(self) -> Self {
+        T::one() / self
+    }
+}
+
+impl<T: Real> std::fmt::Display for Dual<T> {
+    /// writes `a + bε`, a precision applies to each number
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write_real(f, self.value)?;
+        write_part(f, self.derivative, "ε")
+    }
+}
+impl<T: Real> std::fmt::Display for HyperDual<T> {
+    /// writes `a + bε₁ + cε₂ + dε₁ε₂`, a precision applies to each number
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write_real(f, self.value)?;
+        write_part(f, self.eps1, "ε₁")?;
+        write_part(f, self.eps2, "ε₂")?;
+        write_part(f, self.eps1eps2, "ε₁ε₂")
+    }
+}
+impl<T: Real, const N: usize> std::fmt::Display for DualN<T, N> {
+    /// writes `a + [b, c]ε`, a precision applies to each number
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write_real(f, self.value)?;
+        write!(f," + [")?;
+        for (index, &part) in self.gradient.iter().enumerate() {
+            if index > 0 {
+                write!(f,", ")?;
+            }
+            write_real(f, part)?;
+        }
+        write!(f,"]ε")
+    }
+}
+
+// writes a number with the precision of the formatter
+fn write_real<T: Real>(f: &mut std::fmt::Formatter<'_>, value: T) -> std::result::Result<(), std::fmt::Error> {
+    match f.precision() {
+        Some(precision) =>  write!(f,"{:.*}",precision,value),
+        None            =>  write!(f,"{}",value)
+    }
+}
+// writes ` + bε` or ` - bε`
+fn write_part<T: Real>(f: &mut std::fmt::Formatter<'_>, part: T, unit: &str) -> std::result::Result<(), std::fmt::Error> {
+    write!(f," {} ",if part.is_sign_negative() { '-' } else { '+' })?;
+    write_real(f, part.abs())?;
+    write!(f,"{}",unit)
+}
+
+// the operators of dual numbers with each other and with real numbers
+macro_rules! impl_dual_ops {
+    ($([$($generics:tt)*] $dual:ty;)*) => {
+        $(
+            impl_dual_ops!(@ops [$($generics)*] $dual;
+                Add, add, AddAssign, add_assign, add_dual, add_real;
+                Sub, sub, SubAssign, sub_assign, sub_dual, sub_real;
+                Mul, mul, MulAssign, mul_assign, mul_dual, mul_real;
+                Div, div, DivAssign, div_assign, div_dual, div_real;
+            );
+            impl<$($generics)*> std::ops::Neg for $dual {
+                type Output = $dual;
+                fn neg(self) -> $dual {
+                    self.mul_real(-T::one())
+                }
+            }
+            impl<$($generics)*> std::iter::Sum for $dual {
+                fn sum<I: Iterator<Item = $dual>>(iter: I) -> Self {
+                    iter.fold(<$dual>::constant(T::zero()), |sum, value| sum + value)
+                }
+            }
+            impl<$($generics)*> std::iter::Product for $dual {
+                fn product<I: Iterator<Item = $dual>>(iter: I) -> Self {
+                    iter.fold(<$dual>::constant(T::one()), |product, value| product * value)
+                }
+            }
+            impl<$($generics)*> std::convert::From<T> for $dual {
+                fn from(value: T) -> Self {
+                    <$dual>::constant(value)
+                }
+            }
+        )*
+    };
+    (@ops $generics:tt $dual:ty; $($trait:ident, $method:ident, $assign:ident, $assign_method:ident, $core:ident, $real:ident;)*) => {
+        $(
+            impl_dual_ops!(@op $generics $dual; $trait, $method, $assign, $assign_method, $core, $real);
+        )*
+    };
+    (@op [$($generics:tt)*] $dual:ty; $trait:ident, $method:ident, $assign:ident, $assign_method:ident, $core:ident, $real:ident) => {
+        impl<$($generics)*> std::ops::$trait for $dual {
+            type Output = $dual;
+            fn $method(self, other: $dual) -> $dual {
+                self.$core(other)
+            }
+        }
+        impl<$($generics)*> std::ops::$trait<T> for $dual {
+            type Output = $dual;
+            fn $method(self, other: T) -> $dual {
+                self.$real(other)
+            }
+        }
+        impl<$($generics)*> std::ops::$assign for $dual {
+            fn $assign_method(&mut self, other: $dual) {
+                *self = self.$core(other);
+            }
+        }
+        impl<$($generics)*> std::ops::$assign<T> for $dual {
+            fn $assign_method(&mut self, other: T) {
+                *self = self.$real(other);
+            }
+        }
+    };
+}
+impl_dual_ops! {
+    [T: Real] Dual<T>;
+    [T: Real] HyperDual<T>;
+    [T: Real, const N: usize] DualN<T, N>;
+}
+
+// the operators of real numbers with dual numbers
+macro_rules! impl_real_dual_ops {
+    ($([$($generics:tt)*] $float:ty, $dual:ty;)*) => {
+        $(
+            impl<$($generics)*> std::ops::Add<$dual> for $float {
+                type Output = $dual;
+                fn add(self, other: $dual) -> $dual {
+                    other.add_real(self)
+                }
+            }
+            impl<$($generics)*> std::ops::Sub<$dual> for $float {
+                type Output = $dual;
+                fn sub(self, other: $dual) -> $dual {
+                    (-other).add_real(self)
+                }
+            }
+            impl<$($generics)*> std::ops::Mul<$dual> for $float {
+                type Output = $dual;
+                fn mul(self, other: $dual) -> $dual {
+                    other.mul_real(self)
+                }
+            }
+            impl<$($generics)*> std::ops::Div<$dual> for $float {
+                type Output = $dual;
+                fn div(self, other: $dual) -> $dual {
+                    other.recip().mul_real(self)
+                }
+            }
+        )*
+    };
+}
+impl_real_dual_ops! {
+    [] f32, Dual<f32>;
+    [] f64, Dual<f64>;
+    [] f32, HyperDual<f32>;
+    [] f64, HyperDual<f64>;
+    [const N: usize] f32, DualN<f32, N>;
+    [const N: usize] f64, DualN<f64, N>;
+}
@@ -0,0 +1,703 @@
+use super::rational::sealed::Sealed;
+use super::wide::wide_mul;
+
+/// a signed integer which stores the bits of a [`Fixed`]
+///
+/// The trait is implemented for `i8`, `i16`, `i32` and `i64`, and cannot be implemented outside of this crate.
+pub trait FixedInt: Copy + Ord + std::hash::Hash + std::fmt::Debug + Default + Sealed {
+    /// the number of bits
+    const BITS: u32;
+    /// returns the value as `i128`
+    fn to_i128(self) -> i128;
+    /// returns the lowest `BITS` bits of the value
+    fn wrapping_from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_fixed_int {
+    ($($integer:ty),*) => {
+        $(
+            impl FixedInt for $integer {
+                const BITS: u32 = <$integer>::BITS;
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+                fn wrapping_from_i128(value: i128) -> Self {
+                    value as $integer
+                }
+            }
+        )*
+    };
+}
+impl_fixed_int!(i8, i16, i32, i64);
+
+/// an error of parsing a [`Fixed`]
+///
+/// The offsets are byte offsets into the text.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum FixedError {
+    /// the text is empty or only whitespace
+    Empty,
+    /// a character which does not fit the notation, as `(offset, char)`
+    Unexpected(usize, char),
+    /// the text ends before a digit, as `offset`
+    UnexpectedEnd(usize),
+    /// the number does not fit the fixed point type
+    Overflow,
+}
+
+impl std::fmt::Display for FixedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            FixedError::Empty                   =>  write!(f,"empty text"),
+            FixedError::Unexpected(offset, c)   =>  write!(f,"unexpected {:?} at {}",c,offset),
+            FixedError::UnexpectedEnd(offset)   =>  write!(f,"expected a digit at {}",offset),
+            FixedError::Overflow                =>  write!(f,"number too large for the fixed point type")
+        }
+    }
+}
+impl std::error::Error for FixedError {}
+
+/// a binary fixed point number, stored as the integer `bits` with the value `bits / 2^FRAC`
+///
+/// `Fixed<i32, 16>` has 15 integer bits and 16 fraction bits, a range of `-32768` to `32767.99998`
+/// and a resolution of `2^-16`.
+/// `FRAC` must be less than the number of bits of the integer.
+/// All operations, including `sqrt`, `sin`, `cos` and `atan2`, use only integer arithmetic,
+/// so they return the same bits on every platform.
+/// Multiplication, division, the square root and the conversions round to the nearest with ties to even,
+/// the trigonometric functions are accurate to one unit of the last bit.
+/// The operators panic on overflow, in release builds too,
+/// the `checked_*`, `saturating_*` and `wrapping_*` methods handle overflow instead.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Fixed;
+/// type Q16 = Fixed<i32, 16>;
+/// let a = Q16::from_f64(1.5).unwrap();
+/// let b: Q16 = "0.25".parse().unwrap();
+/// assert_eq!(Q16::from_bits(0x1_4000), a - b);
+/// assert_eq!("0.375", (a * b).to_string());
+/// assert_eq!(Q16::from_int(6), Some(a / b));
+/// let large = Q16::from_int(30000).unwrap();
+/// assert_eq!(None, a.checked_mul(large));
+/// assert_eq!(Q16::max_value(), a.saturating_mul(large));
+/// assert_eq!("1.2247", format!("{:.4}", a.sqrt()));
+/// assert_eq!(0.5, Q16::from_f64(std::f64::consts::FRAC_PI_6).unwrap().sin().to_f64());
+/// ```
+# [derive(Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Debug,Default)]
+pub struct Fixed<I: FixedInt, const FRAC: u32> {
+    bits: I,
+}
+
+impl<I: FixedInt, const FRAC: u32> Fixed<I, FRAC> {
+    const FRAC_FITS: () = assert!(FRAC < I::BITS, "the fraction bits do not fit the integer");
+    /// returns the number `bits / 2^FRAC`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Fixed;
+    /// assert_eq!(-1.25, Fixed::<i16, 8>::from_bits(-320).to_f64());
+    /// ```
+    pub fn from_bits(bits: I) -> Self {
+        let () = Self::FRAC_FITS;
+        Fixed { bits }
+    }
+    /// returns the integer which stores the number
+    pub fn to_bits(self) -> I {
+        self.bits
+    }
+    /// returns `0`
+    pub fn zero() -> Self {
+        Self::from_bits(I::default())
+    }
+    /// returns the smallest positive number `2^-FRAC`
+    pub fn epsilon() -> Self {
+        Self::from_bits(I::wrapping_from_i128(1))
+    }
+    /// returns the smallest number
+    pub fn min_value() -> Self {
+        Self::from_bits(I::wrapping_from_i128(Self::min_wide()))
+    }
+    /// returns the largest number
+    pub fn max_value() -> Self {
+        Self::from_bits(I::wrapping_from_i128(Self::max_wide()))
+    }
+    /// returns the integer as fixed point number, or `None` if it does not fit
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Fixed;
+    /// assert_eq!(Some(Fixed::from_bits(0x300)), Fixed::<i16, 8>::from_int(3));
+    /// assert_eq!(None, Fixed::<i16, 8>::from_int(128));
+    /// ```
+    pub fn from_int(value: i64) -> Option<Self> {
+        Self::checked((value as i128) << FRAC)
+    }
+    /// returns the number rounded toward negative infinity as integer
+    pub fn to_int(self) -> i64 {
+        (self.bits.to_i128() >> FRAC) as i64
+    }
+    /// returns the nearest fixed point number with ties to even, or `None` if the number is not a number or does not fit
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Fixed;
+    /// type Q8 = Fixed<i16, 8>;
+    /// assert_eq!(Some(Q8::from_bits(26)), Q8::from_f64(0.1));
+    /// assert_eq!(Some(Q8::from_bits(2)), Q8::from_f64(0.005859375));
+    /// assert_eq!(Some(Q8::min_value()), Q8::from_f64(-128.0));
+    /// assert_eq!(None, Q8::from_f64(128.0));
+    /// assert_eq!(None, Q8::from_f64(f64::NAN));
+    /// ```
+    pub fn from_f64(value: f64) -> Option<Self> {
+        // the scaling by a power of two is exact, so the number is rounded once
+        let scaled = (value * power_of_two(FRAC as i32)).round_ties_even();
+        let limit = power_of_two(I::BITS as i32 - 1);
+        if scaled >= -limit && scaled < limit {
+            return Some(Self::from_bits(I::wrapping_from_i128(scaled as i128)))
+        }
+        None
+    }
+    /// returns the nearest fixed point number with ties to even, or `None` if the number is not a number or does not fit
+    pub fn from_f32(value: f32) -> Option<Self> {
+        Self::from_f64(value as f64)
+    }
+    /// returns the nearest `f64` with ties to even
+    pub fn to_f64(self) -> f64 {
+        self.bits.to_i128() as f64 / power_of_two(FRAC as i32)
+    }
+    /// returns the nearest `f32` with ties to even
+    pub fn to_f32(self) -> f32 {
+        self.bits.to_i128() as f32 / power_of_two(FRAC as i32) as f32
+    }
+    /// returns `true` if the number is less than zero
+    pub fn is_negative(self) -> bool {
+        self.bits.to_i128() < 0
+    }
+    /// returns the absolute value
+    ///
+    /// # Panics
+    /// if the number is the smallest number, whose absolute value does not fit
+    pub fn abs(self) -> Self {
+        Self::checked(self.bits.to_i128().abs()).expect("attempt to take the absolute value with overflow")
+    }
+    /// returns the largest integer less than or equal to the number
+    pub fn floor(self) -> Self {
+        Self::from_bits(I::wrapping_from_i128(self.bits.to_i128() >> FRAC << FRAC))
+    }
+    /// returns the smallest integer greater than or equal to the number
+    ///
+    /// # Panics
+    /// if the integer does not fit
+    pub fn ceil(self) -> Self {
+        let ceil = ((self.bits.to_i128() - 1) >> FRAC) + 1;
+        Self::checked(ceil << FRAC).expect("attempt to round with overflow")
+    }
+    /// returns the nearest integer, with ties away from zero
+    ///
+    /// # Panics
+    /// if the integer does not fit
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Fixed;
+    /// type Q8 = Fixed<i16, 8>;
+    /// assert_eq!(Q8::from_int(-3), Q8::from_f64(-2.5).map(Q8::round));
+    /// assert_eq!(Q8::from_int(2), Q8::from_f64(2.49).map(Q8::round));
+    /// ```
+    pub fn round(self) -> Self {
+        let bits = self.bits.to_i128();
+        let magnitude = (bits.abs() + (1 << FRAC >> 1)) >> FRAC << FRAC;
+        Self::checked(if bits < 0 { -magnitude } else { magnitude }).expect("attempt to round with overflow")
+    }
+    /// returns `-self`, or `None` on overflow
+    pub fn checked_neg(self) -> Option<Self> {
+        Self::checked(-self.bits.to_i128())
+    }
+    /// returns `-self`, clamped to the range of the type
+    pub fn saturating_neg(self) -> Self {
+        Self::saturated(-self.bits.to_i128())
+    }
+    /// returns `-self` modulo the range of the type
+    pub fn wrapping_neg(self) -> Self {
+        Self::wrapped(-self.bits.to_i128())
+    }
+    /// returns `self / other` rounded to the nearest with ties to even, or `None` on overflow or division by zero
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.div_wide(other).and_then(Self::checked)
+    }
+    /// returns `self / other` rounded to the nearest with ties to even, clamped to the range of the type
+    ///
+    /// # Panics
+    /// if `other` is zero
+    pub fn saturating_div(self, other: Self) -> Self {
+        Self::saturated(self.div_wide(other).expect("attempt to divide by zero"))
+    }
+    /// returns `self / other` rounded to the nearest with ties to even, modulo the range of the type
+    ///
+    /// # Panics
+    /// if `other` is zero
+    pub fn wrapping_div(self, other: Self) -> Self {
+        Self::wrapped(self.div_wide(other).expect("attempt to divide by zero"))
+    }
+    /// returns the square root rounded to the nearest
+    ///
+    /// # Panics
+    /// if the number is negative
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Fixed;
+    /// type Q16 = Fixed<i32, 16>;
+    /// assert_eq!(Q16::from_int(3), Q16::from_int(9).map(Q16::sqrt));
+    /// assert_eq!(Q16::from_bits(92682), Q16::from_int(2).unwrap().sqrt());
+    /// ```
+    pub fn sqrt(self) -> Self {
+        let bits = self.bits.to_i128();
+        assert!(bits >= 0, "square root of a negative number");
+        let (root, rest) = integer_sqrt((bits as u128) << FRAC);
+        // the root is rounded up if the value is at least root² + root + 1/4
+        Self::saturated((root + (rest > root) as u128) as i128)
+    }
+    /// returns the sine of the angle in radians
+    ///
+    /// The angle is reduced exactly, so the sine of large angles is accurate too.
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+    /// returns the cosine of the angle in radians, or the largest number if `1` does not fit
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+    /// returns the sine and the cosine of the angle in radians, which is faster than two calls
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Fixed;
+    /// type Q16 = Fixed<i32, 16>;
+    /// let (sin, cos) = Q16::from_int(1).unwrap().sin_cos();
+    /// assert_eq!((Q16::from_bits(55147), Q16::from_bits(35409)), (sin, cos));
+    /// let (sin, cos) = Q16::from_int(-10000).unwrap().sin_cos();
+    /// assert_eq!((Q16::from_bits(20029), Q16::from_bits(-62400)), (sin, cos));
+    /// assert_eq!(Fixed::<i8, 7>::max_value(), Fixed::<i8, 7>::zero().cos());
+    /// ```
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = sin_cos_wide(self.bits.to_i128(), FRAC);
+        (Self::saturated(round_shift(sin, 124 - FRAC)), Self::saturated(round_shift(cos, 124 - FRAC)))
+    }
+    /// returns the angle of the point `(other, self)` in radians, from `-π` to `π`,
+    /// or the number nearest to `π` if it does not fit
+    ///
+    /// The angle of `(0, 0)` is `0`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Fixed;
+    /// type Q16 = Fixed<i32, 16>;
+    /// let one = Q16::from_int(1).unwrap();
+    /// assert_eq!(Q16::from_bits(51472), one.atan2(one));
+    /// assert_eq!(Q16::from_bits(205887), Q16::zero().atan2(-one));
+    /// assert_eq!(Q16::from_bits(-102944), (-one).atan2(Q16::zero()));
+    /// ```
+    pub fn atan2(self, other: Self) -> Self {
+        let angle = atan2_wide(self.bits.to_i128(), other.bits.to_i128(), FRAC + 8);
+        Self::saturated(round_shift(angle, 124 - FRAC))
+    }
+    // the smallest and largest bits as `i128`
+    fn min_wide() -> i128 {
+        -1 << (I::BITS - 1)
+    }
+    fn max_wide() -> i128 {
+        (1 << (I::BITS - 1)) - 1
+    }
+    // the number of the bits, or `None` if they do not fit
+    fn checked(bits: i128) -> Option<Self> {
+        if bits < Self::min_wide() || bits > Self::max_wide() {
+            return None
+        }
+        Some(Self::from_bits(I::wrapping_from_i128(bits)))
+    }
+    fn saturated(bits: i128) -> Self {
+        Self::from_bits(I::wrapping_from_i128(bits.clamp(Self::min_wide(), Self::max_wide())))
+    }
+    fn wrapped(bits: i128) -> Self {
+        Self::from_bits(I::wrapping_from_i128(bits))
+    }
+    // the exact or rounded bits of the results, the bits are at most 2^63, so the results fit into i128
+    fn add_wide(self, other: Self) -> i128 {
+        self.bits.to_i128() + other.bits.to_i128()
+    }
+    fn sub_wide(self, other: Self) -> i128 {
+        self.bits.to_i128() - other.bits.to_i128()
+    }
+    fn mul_wide(self, other: Self) -> i128 {
+        round_shift(self.bits.to_i128() * other.bits.to_i128(), FRAC)
+    }
+    fn div_wide(self, other: Self) -> Option<i128> {
+        let (numerator, denominator) = (self.bits.to_i128() << FRAC, other.bits.to_i128());
+        if denominator == 0 {
+            return None
+        }
+        let (quotient, rest) = (numerator / denominator, numerator % denominator);
+        let (twice, divisor) = (2 * rest.abs(), denominator.abs());
+        if twice > divisor || (twice == divisor && quotient % 2 != 0) {
+            return Some(if (numerator < 0) == (denominator < 0) { quotient + 1 } else { quotient - 1 })
+        }
+        Some(quotient)
+    }
+}
+
+macro_rules! impl_fixed_arithmetic {
+    ($($checked_doc:literal $checked:ident, $saturating_doc:literal $saturating:ident, $wrapping_doc:literal $wrapping:ident => $core:ident;)*) => {
+        impl<I: FixedInt, const FRAC: u32> Fixed<I, FRAC> {
+            $(
+                #[doc = $checked_doc]
+                pub fn $checked(self, other: Self) -> Option<Self> {
+                    Self::checked(self.$core(other))
+                }
+                #[doc = $saturating_doc]
+                pub fn $saturating(self, other: Self) -> Self {
+                    Self::saturated(self.$core(other))
+                }
+                #[doc = $wrapping_doc]
+                pub fn $wrapping(self, other: Self) -> Self {
+                    Self::wrapped(self.$core(other))
+                }
+            )*
+        }
+    };
+}
+impl_fixed_arithmetic! {
+    "returns `self + other`, or `None` on overflow" checked_add,
+    "returns `self + other`, clamped to the range of the type" saturating_add,
+    "returns `self + other` modulo the range of the type" wrapping_add => add_wide;
+    "returns `self - other`, or `None` on overflow" checked_sub,
+    "returns `self - other`, clamped to the range of the type" saturating_sub,
+    "returns `self - other` modulo the range of the type" wrapping_sub => sub_wide;
+    "returns `self * other` rounded to the nearest with ties to even, or `None` on overflow" checked_mul,
+    "returns `self * other` rounded to the nearest with ties to even, clamped to the range of the type" saturating_mul,
+    "returns `self * other` rounded to the nearest with ties to even, modulo the range of the type" wrapping_mul => mul_wide;
+}
+
+// 2^exponent, built from the bits, for the exponents of normal numbers
+fn power_of_two(exponent: i32) -> f64 {
+    f64::from_bits(((1023 + exponent) as u64) << 52)
+}
+
+// value / 2^shift rounded to the nearest with ties to even
+fn round_shift(value: i128, shift: u32) -> i128 {
+    if shift == 0 {
+        return value
+    }
+    let floor = value >> shift;
+    let (rest, half) = (value - (floor << shift), 1 << (shift - 1));
+    if rest > half || (rest == half && floor % 2 != 0) {
+        return floor + 1
+    }
+    floor
+}
+
+// the integer square root and the rest `value - root²`, digit by digit
+fn integer_sqrt(value: u128) -> (u128, u128) {
+    if value == 0 {
+        return (0, 0)
+    }
+    let (mut root, mut rest) = (0u128, value);
+    let mut bit = 1u128 << ((127 - value.leading_zeros()) & !1);
+    while bit != 0 {
+        if rest >= root + bit {
+            rest -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    (root, rest)
+}
+
+// 2/π in units of 2^-192, as the highest 64 bits and the lower 128 bits
+const TWO_OVER_PI_HIGH: u128 = 0xa2f9836e4e441529;
+const TWO_OVER_PI_LOW: u128 = 0xfc2757d1f534ddc0db6295993c439041;
+// π/2 in units of 2^-127 and π in units of 2^-124
+const HALF_PI: u128 = 0xc90fdaa22168c234c4c6628b80dc1cd1;
+const PI: i128 = 0x3243f6a8885a308d313198a2e0370734;
+// the product of 1/√(1 + 2^-2i) over all steps of the CORDIC in units of 2^-124
+const CORDIC_GAIN: i128 = 0x09b74eda8435e5a67f5f9092bd7fd40f;
+// atan(2^-i) in units of 2^-124, which is 2^(124 - i) after these
+const ARCTANGENTS: [i128; 42] = [
+    0x0c90fdaa22168c234c4c6628b80dc1cd, 0x076b19c1586ed3da2b7f222f65e1d468,
+    0x03eb6ebf25901bac55b71e7bd7de8860, 0x01fd5ba9aac2f6dc65912f313e7d111e,
+    0x00ffaaddb967ef4e36cb2792dc0e2e0d, 0x007ff556eea5d892a13bcebbb6ed4631,
+    0x003ffeaab776e5356ef9e31590057dd8, 0x001fffd555bbba972d00c46a3f77cc16,
+    0x000ffffaaaaddddb94bb12afb6b6d4f8, 0x0007ffff55556eeeea5ca6adeab02252,
+    0x0003ffffeaaaab77776e52e5a019fbcf, 0x0001fffffd55555bbbbba97297625625,
+    0x0000ffffffaaaaaaddddddb94b94d5bd, 0x00007ffffff5555556eeeeeea5ca5cb4,
+    0x00003ffffffeaaaaaab7777776e52e53, 0x00001fffffffd5555555bbbbbbba9729,
+    0x00000ffffffffaaaaaaaaddddddddb95, 0x000007ffffffff555555556eeeeeeeea,
+    0x000003ffffffffeaaaaaaaab77777777, 0x000001fffffffffd555555555bbbbbbc,
+    0x000000ffffffffffaaaaaaaaaaddddde, 0x0000007ffffffffff55555555556eeef,
+    0x0000003ffffffffffeaaaaaaaaaab777, 0x0000001fffffffffffd55555555555bc,
+    0x0000000ffffffffffffaaaaaaaaaaaae, 0x00000007ffffffffffff555555555555,
+    0x00000003ffffffffffffeaaaaaaaaaab, 0x00000001fffffffffffffd5555555555,
+    0x00000000ffffffffffffffaaaaaaaaab, 0x000000007ffffffffffffff555555555,
+    0x000000003ffffffffffffffeaaaaaaab, 0x000000001fffffffffffffffd5555555,
+    0x000000000ffffffffffffffffaaaaaab, 0x0000000007ffffffffffffffff555555,
+    0x0000000003ffffffffffffffffeaaaab, 0x0000000001fffffffffffffffffd5555,
+    0x0000000000ffffffffffffffffffaaab, 0x00000000007ffffffffffffffffff555,
+    0x00000000003ffffffffffffffffffeab, 0x00000000001fffffffffffffffffffd5,
+    0x00000000000ffffffffffffffffffffb, 0x000000000007ffffffffffffffffffff,
+];
+
+fn arctangent(step: u32) -> i128 {
+    match ARCTANGENTS.get(step as usize) {
+        Some(angle) =>  *angle,
+        None        =>  1 << (124 - step)
+    }
+}
+
+// the sine and cosine of `bits / 2^frac` in units of 2^-124
+fn sin_cos_wide(bits: i128, frac: u32) -> (i128, i128) {
+    // the magnitude times 2/π in units of 2^-(192 + frac) has at most 255 bits,
+    // the integer part gives the quadrant and the fraction the rest of the angle
+    let magnitude = bits.unsigned_abs();
+    let (high, low) = wide_mul(magnitude, TWO_OVER_PI_LOW);
+    let high = high + magnitude * TWO_OVER_PI_HIGH;
+    let mut quadrant = (high >> (64 + frac)) & 3;
+    let mut fraction = (high << (64 - frac)) | (low >> (64 + frac));
+    // a fraction above 1/2 is a negative rest of the next quadrant
+    let negative = fraction >> 127 == 1;
+    if negative {
+        quadrant += 1;
+        fraction = fraction.wrapping_neg();
+    }
+    let rest = (wide_mul(fraction, HALF_PI).0 >> 3) as i128;
+    let (sin, cos) = cordic(if negative { -rest } else { rest }, frac + 8);
+    let (sin, cos) = match quadrant & 3 {
+        0   =>  (sin, cos),
+        1   =>  (cos, -sin),
+        2   =>  (-sin, -cos),
+        _   =>  (-cos, sin)
+    };
+    if bits < 0 { (-sin, cos) } else { (sin, cos) }
+}
+
+// rotates (1, 0) by the angle of at most π/4 in units of 2^-124 and returns (sin, cos) in the same units,
+// each step adds one bit of accuracy
+fn cordic(angle: i128, steps: u32) -> (i128, i128) {
+    let (mut x, mut y, mut z) = (CORDIC_GAIN, 0i128, angle);
+    for step in 0..steps {
+        let (dx, dy) = (y >> step, x >> step);
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= arctangent(step);
+        } else {
+            x += dx;
+            y -= dy;
+            z += arctangent(step);
+        }
+    }
+    (y, x)
+}
+
+// the angle of (x, y) in units of 2^-124, by rotating the point onto the positive x axis
+fn atan2_wide(y: i128, x: i128, steps: u32) -> i128 {
+    let largest = y.unsigned_abs().max(x.unsigned_abs());
+    if largest == 0 {
+        return 0
+    }
+    // the largest coordinate gets 121 bits, so the steps keep the accuracy
+    let shift = largest.leading_zeros() - 7;
+    let (mut x, mut y, mut z) = (x << shift, y << shift, 0i128);
+    if x < 0 {
+        z = if y < 0 { -PI } else { PI };
+        x = -x;
+        y = -y;
+    }
+    for step in 0..steps {
+        let (dx, dy) = (y >> step, x >> step);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            z += arctangent(step);
+        } else {
+            x -= dx;
+            y += dy;
+            z -= arctangent(step);
+        }
+    }
+    z
+}
+
+macro_rules! impl_fixed_ops {
+    ($($trait:ident, $method:ident, $assign:ident, $assign_method:ident, $checked:ident, $message:literal;)*) => {
+        $(
+            impl<I: FixedInt, const FRAC: u32> std::ops::$trait for Fixed<I, FRAC> {
+                type Output = Fixed<I, FRAC>;
+                fn $method(self, other: Fixed<I, FRAC>) -> Fixed<I, FRAC> {
+                    self.$checked(other).expect($message)
+                }
+            }
+            impl<I: FixedInt, const FRAC: u32> std::ops::$assign for Fixed<I, FRAC> {
+                fn $assign_method(&mut self, other: Fixed<I, FRAC>) {
+                    *self = self.$checked(other).expect($message);
+                }
+            }
+        )*
+    };
+}
+impl_fixed_ops! {
+    Add, add, AddAssign, add_assign, checked_add, "attempt to add with overflow";
+    Sub, sub, SubAssign, sub_assign, checked_sub, "attempt to subtract with overflow";
+    Mul, mul, MulAssign, mul_assign, checked_mul, "attempt to multiply with overflow";
+    Div, div, DivAssign, div_assign, checked_div, "attempt to divide by zero or with overflow";
+}
+
+impl<I: FixedInt, const FRAC: u32> std::ops::Neg for Fixed<I, FRAC> {
+    type Output = Fixed<I, FRAC>;
+    fn neg(self) -> Fixed<I, FRAC> {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> std::iter::Sum for Fixed<I, FRAC> {
+    fn sum<J: Iterator<Item = Fixed<I, FRAC>>>(iter: J) -> Self {
+        iter.fold(Fixed::zero(), |sum, value| sum + value)
+    }
+}
+impl<I: FixedInt, const FRAC: u32> std::iter::Product for Fixed<I, FRAC> {
+    /// returns the product, starting from `1`
+    ///
+    /// # Panics
+    /// if `1` does not fit or the product overflows
+    fn product<J: Iterator<Item = Fixed<I, FRAC>>>(iter: J) -> Self {
+        let one = Fixed::from_int(1).expect("1 does not fit the fixed point type");
+        iter.fold(one, |product, value| product * value)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> std::fmt::Display for Fixed<I, FRAC> {
+    /// writes the exact decimal number, or the number rounded to the precision with ties to even,
+    /// padded to the width with the fill and alignment, or with zeros after the sign for `0`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Fixed;
+    /// let x = Fixed::<i32, 16>::from_bits(-0x1_2000);
+    /// assert_eq!("-1.125", x.to_string());
+    /// assert_eq!("-1.12", format!("{:.2}", x));
+    /// assert_eq!("-1.1250", format!("{:.4}", x));
+    /// assert_eq!("0.0000152587890625", Fixed::<i32, 16>::epsilon().to_string());
+    /// assert_eq!("  -1.125|+1.12", format!("{:>8}|{:+.2}", x, -x));
+    /// assert_eq!("-0001.12", format!("{:08.2}", x));
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let bits = self.bits.to_i128();
+        let (one, magnitude) = (1u128 << FRAC, bits.unsigned_abs());
+        let (mut integer, mut fraction) = (magnitude >> FRAC, magnitude & (one - 1));
+        // each digit is exact, a binary fraction has at most FRAC decimal digits
+        let mut digits = Vec::new();
+        while fraction != 0 && f.precision().is_none_or(|precision| digits.len() < precision) {
+            fraction *= 10;
+            digits.push((fraction >> FRAC) as u8);
+            fraction &= one - 1;
+        }
+        if let Some(precision) = f.precision() {
+            let odd = digits.last().map_or(integer as u8, |digit| *digit) % 2 == 1;
+            if fraction != 0 && (2 * fraction > one || (2 * fraction == one && odd)) {
+                match digits.iter().rposition(|digit| *digit != 9) {
+                    Some(position)  =>  {
+                        digits[position] += 1;
+                        digits[position + 1..].iter_mut().for_each(|digit| *digit = 0);
+                    }
+                    None            =>  {
+                        integer += 1;
+                        digits.iter_mut().for_each(|digit| *digit = 0);
+                    }
+                }
+            }
+            digits.resize(precision, 0);
+        }
+        let mut text = integer.to_string();
+        if !digits.is_empty() {
+            text.push('.');
+            text.extend(digits.iter().map(|digit| (b'0' + digit) as char));
+        }
+        f.pad_integral(bits >= 0, "", &text)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> std::str::FromStr for Fixed<I, FRAC> {
+    type Err = FixedError;
+    /// parses a decimal like `-1.125`, rounded to the nearest with ties to even
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Fixed, FixedError};
+    /// type Q8 = Fixed<i16, 8>;
+    /// assert_eq!(Ok(Q8::from_bits(-288)), "-1.125".parse());
+    /// assert_eq!(Ok(Q8::from_bits(26)), "0.1".parse());
+    /// assert_eq!(Ok(Q8::from_bits(2)), "0.005859375".parse());
+    /// assert_eq!(Ok(Q8::from_bits(2)), "0.0058593750000000000000001".parse());
+    /// assert_eq!(Ok(Q8::min_value()), "-128".parse());
+    /// assert_eq!(Err(FixedError::Overflow), "128".parse::<Q8>());
+    /// assert_eq!(Err(FixedError::UnexpectedEnd(2)), "1.".parse::<Q8>());
+    /// assert_eq!(Err(FixedError::Unexpected(1, 'e')), "1e3".parse::<Q8>());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, FixedError> {
+        let start = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(FixedError::Empty)
+        }
+        let unexpected = |offset: usize| match trimmed[offset..].chars().next() {
+            Some(c) =>  FixedError::Unexpected(start + offset, c),
+            None    =>  FixedError::UnexpectedEnd(start + offset)
+        };
+        let digits = |offset: usize| &trimmed[offset..offset + trimmed[offset..].bytes().take_while(u8::is_ascii_digit).count()];
+        let sign = trimmed.chars().next().filter(|c| matches!(c, '-' | '−' | '+'));
+        let mut offset = sign.map_or(0, char::len_utf8);
+        let whole = digits(offset);
+        if whole.is_empty() {
+            return Err(unexpected(offset))
+        }
+        offset += whole.len();
+        let mut fraction = "";
+        if trimmed[offset..].starts_with('.') {
+            fraction = digits(offset + 1);
+            if fraction.is_empty() {
+                return Err(unexpected(offset + 1))
+            }
+            offset += 1 + fraction.len();
+        }
+        if offset < trimmed.len() {
+            return Err(unexpected(offset))
+        }
+        let integer = whole.bytes()
+            .try_fold(0u128, |integer, digit| integer.checked_mul(10)?.checked_add((digit - b'0') as u128))
+            .filter(|integer| *integer >> 63 == 0)
+            .ok_or(FixedError::Overflow)?;
+        let magnitude = ((integer << FRAC) + fraction_bits(fraction, FRAC)) as i128;
+        Self::checked(if sign.is_some_and(|c| c != '+') { -magnitude } else { magnitude }).ok_or(FixedError::Overflow)
+    }
+}
+
+// the decimal fraction in units of 2^-frac rounded to the nearest with ties to even,
+// by doubling the decimal digits, which moves the next binary digit before the point
+fn fraction_bits(digits: &str, frac: u32) -> u128 {
+    let mut decimal: Vec<u8> = digits.trim_end_matches('0').bytes().map(|digit| digit - b'0').collect();
+    let mut bits = 0u128;
+    for _ in 0..=frac {
+        let mut carry = 0;
+        for digit in decimal.iter_mut().rev() {
+            let doubled = *digit * 2 + carry;
+            *digit = doubled % 10;
+            carry = doubled / 10;
+        }
+        bits = (bits << 1) | carry as u128;
+    }
+    let sticky = decimal.iter().any(|digit| *digit != 0);
+    let (bits, half) = (bits >> 1, bits % 2 == 1);
+    if half && (sticky || bits % 2 == 1) { bits + 1 } else { bits }
+}
@@ -0,0 +1,320 @@
+use super::greek_letter::from_accented;
+use super::GreekAlphabet;
+
+/// an error of the Morse and Braille codes of `GreekAlphabet`
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum GreekCodeError {
+    /// a character without a code, as `(offset, char)` where `offset` is the byte offset into the text
+    Unencodable(usize, char),
+    /// a code which stands for no character, as `(offset, code)` where `offset` is the byte offset into the code
+    UnknownCode(usize, String),
+}
+
+impl std::fmt::Display for GreekCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            GreekCodeError::Unencodable(offset, c)      =>  write!(f,"no code for {:?} at {}",c,offset),
+            GreekCodeError::UnknownCode(offset, code)   =>  write!(f,"unknown code {:?} at {}",code,offset)
+        }
+    }
+}
+impl std::error::Error for GreekCodeError {}
+
+const BRAILLE_CAPITAL : char = '⠨';
+const BRAILLE_NUMBER : char = '⠼';
+const BRAILLE_LETTER : char = '⠰';
+const BRAILLE_SPACE : char = '⠀';
+const BRAILLE_DIGITS : [char;10] = ['⠚', '⠁', '⠃', '⠉', '⠙', '⠑', '⠋', '⠛', '⠓', '⠊'];
+
+// the Greek diphthongs which have their own Morse code
+const MORSE_DIPHTHONGS : [(&str, GreekAlphabet, GreekAlphabet);4] = [
+    ("..--", GreekAlphabet::Alpha, GreekAlphabet::Ypsilon),
+    ("...-", GreekAlphabet::Epsilon, GreekAlphabet::Ypsilon),
+    ("---.", GreekAlphabet::Omicron, GreekAlphabet::Iota),
+    (".---", GreekAlphabet::Ypsilon, GreekAlphabet::Iota),
+];
+
+impl GreekAlphabet {
+    /// returns the Greek Morse code of `GreekAlphabet`
+    ///
+    /// # Panics
+    /// Panics when called on `GreekAlphabet::Empty`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::GreekAlphabet;
+    /// let theta = GreekAlphabet::Theta;
+    /// assert_eq!("-.-.", theta.morse());
+    /// ```
+    pub fn morse(&self) -> &'static str {
+        match self {
+            GreekAlphabet::Alpha    =>  ".-",
+            GreekAlphabet::Beta     =>  "-...",
+            GreekAlphabet::Gamma    =>  "--.",
+            GreekAlphabet::Delta    =>  "-..",
+            GreekAlphabet::Epsilon  =>  ".",
+            GreekAlphabet::Zeta     =>  "--..",
+            GreekAlphabet::Eta      =>  "....",
+            GreekAlphabet::Theta    =>  "-.-.",
+            GreekAlphabet::Iota     =>  "..",
+            GreekAlphabet::Kappa    =>  "-.-",
+            GreekAlphabet::Lambda   =>  ".-..",
+            GreekAlphabet::My       =>  "--",
+            GreekAlphabet::Ny       =>  "-.",
+            GreekAlphabet::Xi       =>  "-..-",
+            GreekAlphabet::Omicron  =>  "---",
+            GreekAlphabet::Pi       =>  ".--.",
+            GreekAlphabet::Rho      =>  ".-.",
+            GreekAlphabet::Sigma    =>  "...",
+            GreekAlphabet::Tau      =>  "-",
+            GreekAlphabet::Ypsilon  =>  "-.--",
+            GreekAlphabet::Phi      =>  "..-.",
+            GreekAlphabet::Chi      =>  "----",
+            GreekAlphabet::Psi      =>  "--.-",
+            GreekAlphabet::Omega    =>  ".--",
+            _                       =>  panic!("charackter not exist in GreekAlphabet")
+        }
+    }
+    /// returns the Greek Braille cell of `GreekAlphabet` as a [`char`](https://doc.rust-lang.org/std/char/index.html) of the Unicode Braille patterns
+    ///
+    /// # Panics
+    /// Panics when called on `GreekAlphabet::Empty`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::GreekAlphabet;
+    /// let eta = GreekAlphabet::Eta;
+    /// assert_eq!('⠜', eta.braille());
+    /// ```
+    pub fn braille(&self) -> char {
+        match self {
+            GreekAlphabet::Alpha    =>  '⠁',
+            GreekAlphabet::Beta     =>  '⠃',
+            GreekAlphabet::Gamma    =>  '⠛',
+            GreekAlphabet::Delta    =>  '⠙',
+            GreekAlphabet::Epsilon  =>  '⠑',
+            GreekAlphabet::Zeta     =>  '⠵',
+            GreekAlphabet::Eta      =>  '⠜',
+            GreekAlphabet::Theta    =>  '⠹',
+            GreekAlphabet::Iota     =>  '⠊',
+            GreekAlphabet::Kappa    =>  '⠅',
+            GreekAlphabet::Lambda   =>  '⠇',
+            GreekAlphabet::My       =>  '⠍',
+            GreekAlphabet::Ny       =>  '⠝',
+            GreekAlphabet::Xi       =>  '⠭',
+            GreekAlphabet::Omicron  =>  '⠕',
+            GreekAlphabet::Pi       =>  '⠏',
+            GreekAlphabet::Rho      =>  '⠗',
+            GreekAlphabet::Sigma    =>  '⠎',
+            GreekAlphabet::Tau      =>  '⠞',
+            GreekAlphabet::Ypsilon  =>  '⠥',
+            GreekAlphabet::Phi      =>  '⠋',
+            GreekAlphabet::Chi      =>  '⠓',
+            GreekAlphabet::Psi      =>  '⠽',
+            GreekAlphabet::Omega    =>  '⠚',
+            _                       =>  panic!("charackter not exist in GreekAlphabet")
+        }
+    }
+}
+
+/// encodes `GreekAlphabet`s into Greek Morse code
+///
+/// The codes are separated by a space, `GreekAlphabet::Empty` becomes the word gap `/`.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{GreekAlphabet, to_morse};
+/// let letters = [GreekAlphabet::Pi, GreekAlphabet::Empty, GreekAlphabet::Epsilon];
+/// assert_eq!(".--. / .", to_morse(&letters));
+/// ```
+pub fn to_morse(letters: &[GreekAlphabet]) -> String {
+    letters.iter().map(|letter| match letter {
+        GreekAlphabet::Empty    =>  "/",
+        _                       =>  letter.morse()
+    }).collect::<Vec<&str>>().join(" ")
+}
+
+/// decodes Greek Morse code into `GreekAlphabet`s
+///
+/// Codes are separated by whitespace, the word gap `/` gives `GreekAlphabet::Empty`.
+/// The diphthong codes for `αυ`, `ευ`, `οι` and `υι` give two letters.
+///
+/// # Errors
+/// Returns `GreekCodeError::UnknownCode` for the first code which stands for no letter.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{GreekAlphabet, GreekCodeError, from_morse};
+/// assert_eq!(Ok(vec![GreekAlphabet::Omicron, GreekAlphabet::Iota]), from_morse("---."));
+/// assert_eq!(Err(GreekCodeError::UnknownCode(3, String::from("...---"))), from_morse(".- ...---"));
+/// ```
+pub fn from_morse(code: &str) -> Result<Vec<GreekAlphabet>, GreekCodeError> {
+    let mut letters = Vec::new();
+    for (offset, token) in tokens(code) {
+        if token == "/" {
+            letters.push(GreekAlphabet::Empty);
+        } else if let Some(&(_, first, second)) = MORSE_DIPHTHONGS.iter().find(|(morse, _, _)| *morse == token) {
+            letters.push(first);
+            letters.push(second);
+        } else {
+            match (1..=24u8).map(GreekAlphabet::from).find(|letter| letter.morse() == token) {
+                Some(letter)    =>  letters.push(letter),
+                None            =>  return Err(GreekCodeError::UnknownCode(offset, token.to_string()))
+            }
+        }
+    }
+    Ok(letters)
+}
+
+/// encodes a Greek text into Greek Braille
+///
+/// Capital letters get the capital sign `⠨`, words written completely in capitals get `⠨⠨` once.
+/// Numbers start with the number sign `⠼`, a letter directly after a number gets the letter sign `⠰`.
+/// Whitespace becomes the blank cell `⠀`, accents are dropped.
+///
+/// # Errors
+/// Returns `GreekCodeError::Unencodable` for the first character which is neither a Greek letter, a digit nor whitespace.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::to_braille;
+/// assert_eq!(Ok(String::from("⠨⠁⠀⠼⠁⠃⠰⠁")), to_braille("Α 12α"));
+/// assert_eq!(Ok(String::from("⠨⠨⠏⠊")), to_braille("ΠΙ"));
+/// ```
+pub fn to_braille(text: &str) -> Result<String, GreekCodeError> {
+    let chars : Vec<(usize, char)> = text.char_indices().collect();
+    let mut braille = String::new();
+    let mut number = false;
+    let mut capital_word = false;
+    for (i, &(offset, c)) in chars.iter().enumerate() {
+        if let Some(digit) = c.to_digit(10) {
+            if !number {
+                braille.push(BRAILLE_NUMBER);
+                number = true;
+            }
+            braille.push(BRAILLE_DIGITS[digit as usize]);
+            continue
+        }
+        if c.is_whitespace() {
+            braille.push(BRAILLE_SPACE);
+            number = false;
+            capital_word = false;
+            continue
+        }
+        let letter = from_accented(c);
+        if letter == GreekAlphabet::Empty {
+            return Err(GreekCodeError::Unencodable(offset, c))
+        }
+        let cell = letter.braille();
+        if number && BRAILLE_DIGITS.contains(&cell) {
+            braille.push(BRAILLE_LETTER);
+        }
+        number = false;
+        if c.is_uppercase() && !capital_word {
+            let word : Vec<char> = chars[i..].iter().map(|&(_, c)| c)
+                .take_while(|&c| from_accented(c) != GreekAlphabet::Empty).collect();
+            if word.len() > 1 && word.iter().all(|c| c.is_uppercase()) {
+                braille.push(BRAILLE_CAPITAL);
+                capital_word = true;
+            }
+            braille.push(BRAILLE_CAPITAL);
+        }
+        braille.push(cell);
+    }
+    Ok(braille)
+}
+
+/// decodes Greek Braille into a Greek text
+///
+/// Understands the signs written by [`to_braille`], a `σ` at the end of a word is written as `ς`.
+///
+/// # Errors
+/// Returns `GreekCodeError::UnknownCode` for the first cell which stands for no character.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::from_braille;
+/// assert_eq!(Ok(String::from("Αλφα 12α")), from_braille("⠨⠁⠇⠋⠁⠀⠼⠁⠃⠰⠁"));
+/// assert_eq!(Ok(String::from("ΠΙ ως")), from_braille("⠨⠨⠏⠊⠀⠚⠎"));
+/// ```
+pub fn from_braille(braille: &str) -> Result<String, GreekCodeError> {
+    let mut text = String::new();
+    let mut number = false;
+    let mut capitals = 0;
+    let mut capital_word = false;
+    for (offset, cell) in braille.char_indices() {
+        match cell {
+            BRAILLE_NUMBER  =>  { number = true; continue }
+            BRAILLE_LETTER  =>  { number = false; continue }
+            BRAILLE_CAPITAL =>  { capitals += 1; continue }
+            _               =>  {}
+        }
+        if cell == BRAILLE_SPACE || cell.is_whitespace() {
+            final_sigma(&mut text);
+            text.push(' ');
+            number = false;
+            capital_word = false;
+            continue
+        }
+        if number {
+            if let Some(digit) = BRAILLE_DIGITS.iter().position(|&d| d == cell) {
+                text.push(std::char::from_digit(digit as u32, 10).unwrap());
+                continue
+            }
+            number = false;
+        }
+        let letter = match (1..=24u8).map(GreekAlphabet::from).find(|letter| letter.braille() == cell) {
+            Some(letter)    =>  letter,
+            None            =>  return Err(GreekCodeError::UnknownCode(offset, cell.to_string()))
+        };
+        capital_word = capital_word || capitals > 1;
+        text.push(if capital_word || capitals > 0 { letter.uppercase() } else { letter.lowercase() });
+        capitals = 0;
+    }
+    final_sigma(&mut text);
+    Ok(text)
+}
+
+/// encodes `GreekAlphabet`s into Greek Braille, `GreekAlphabet::Empty` becomes the blank cell `⠀`
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{GreekAlphabet, letters_to_braille};
+/// let letters = [GreekAlphabet::Alpha, GreekAlphabet::Empty, GreekAlphabet::Omega];
+/// assert_eq!("⠁⠀⠚", letters_to_braille(&letters));
+/// ```
+pub fn letters_to_braille(letters: &[GreekAlphabet]) -> String {
+    letters.iter().map(|letter| match letter {
+        GreekAlphabet::Empty    =>  BRAILLE_SPACE,
+        _                       =>  letter.braille()
+    }).collect()
+}
+
+/// decodes Greek Braille into `GreekAlphabet`s
+///
+/// Capital signs are ignored, blank cells and digits give `GreekAlphabet::Empty`.
+///
+/// # Errors
+/// Returns `GreekCodeError::UnknownCode` for the first cell which stands for no character.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{GreekAlphabet, braille_to_letters};
+/// assert_eq!(Ok(vec![GreekAlphabet::Pi, GreekAlphabet::Iota]), braille_to_letters("⠨⠏⠊"));
+/// ```
+pub fn braille_to_letters(braille: &str) -> Result<Vec<GreekAlphabet>, GreekCodeError> {
+    Ok(from_braille(braille)?.chars().map(from_accented).collect())
+}
+
+// splits at whitespace, keeping the byte offset of every token
+fn tokens(code: &str) -> impl Iterator<Item = (usize, &str)> {
+    code.split(char::is_whitespace).filter(|token| !token.is_empty())
+        .map(move |token| (token.as_ptr() as usize - code.as_ptr() as usize, token))
+}
+
+fn final_sigma(text: &mut String) {
+    if text.ends_with('σ') && text.chars().rev().nth(1).is_some_and(|c| from_accented(c) != GreekAlphabet::Empty) {
+        text.pop();
+        text.push('ς');
+    }
+}
@@ -0,0 +1,136 @@
+use super::greek_letter::from_accented;
+use super::GreekAlphabet;
+
+use std::cmp::Ordering;
+
+/// compares two words in Greek alphabetical order
+///
+/// The letters are compared by their position in `GreekAlphabet` first, ignoring accents and case.
+/// Only words which are equal then are compared by their accents (none < tonos < dialytika < both)
+/// and after that by case (lowercase < final sigma < uppercase).
+/// Characters which are no Greek letters come before the Greek letters:
+/// whitespace and punctuation first, then digits, then the other letters.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::collate;
+/// let mut words = vec!["ωμέγα", "Άλφα", "όμως", "ομως", "αλφα", "β"];
+/// words.sort_by(|a, b| collate(a, b));
+/// assert_eq!(vec!["αλφα", "Άλφα", "β", "ομως", "όμως", "ωμέγα"], words);
+/// ```
+pub fn collate(a: &str, b: &str) -> Ordering {
+    let a : Vec<Weights> = a.chars().map(Weights::of).collect();
+    let b : Vec<Weights> = b.chars().map(Weights::of).collect();
+    a.iter().map(|w| w.primary).cmp(b.iter().map(|w| w.primary))
+        .then_with(|| a.iter().map(|w| w.secondary).cmp(b.iter().map(|w| w.secondary)))
+        .then_with(|| a.iter().map(|w| w.tertiary).cmp(b.iter().map(|w| w.tertiary)))
+        .then_with(|| a.iter().map(|w| w.c).cmp(b.iter().map(|w| w.c)))
+}
+
+/// converts a text into uppercase following the Greek orthography
+///
+/// The tonos is dropped, the dialytika is kept. When dropping the tonos splits a diphthong,
+/// the following `ι` or `υ` gets a dialytika (`άυλος` → `ΑΫΛΟΣ`).
+/// The disjunctive `ή` keeps its tonos. Other characters are converted as by [`str::to_uppercase`].
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::to_upper;
+/// assert_eq!("ΚΑΛΗΜΕΡΑ", to_upper("καλημέρα"));
+/// assert_eq!("ΑΫΛΟΣ Ή ΠΡΩΤΕΪΝΗ", to_upper("άυλος ή πρωτεΐνη"));
+/// ```
+pub fn to_upper(text: &str) -> String {
+    let chars : Vec<char> = text.chars().collect();
+    let mut upper = String::with_capacity(text.len());
+    let mut split_diphthong = false;
+    for (i, &c) in chars.iter().enumerate() {
+        let disjunctive = c == 'ή'
+            && (i == 0 || !chars[i - 1].is_alphabetic())
+            && chars.get(i + 1).is_none_or(|c| !c.is_alphabetic());
+        let capital = match c {
+            'ή' if disjunctive  =>  'Ή',
+            'ι' | 'Ι' if split_diphthong    =>  'Ϊ',
+            'υ' | 'Υ' if split_diphthong    =>  'Ϋ',
+            'ά' | 'Ά'   =>  'Α',
+            'έ' | 'Έ'   =>  'Ε',
+            'ή' | 'Ή'   =>  'Η',
+            'ί' | 'Ί'   =>  'Ι',
+            'ό' | 'Ό'   =>  'Ο',
+            'ύ' | 'Ύ'   =>  'Υ',
+            'ώ' | 'Ώ'   =>  'Ω',
+            'ϊ' | 'ΐ'   =>  'Ϊ',
+            'ϋ' | 'ΰ'   =>  'Ϋ',
+            'ς'         =>  'Σ',
+            _           =>  {
+                upper.extend(c.to_uppercase());
+                split_diphthong = false;
+                continue
+            }
+        };
+        split_diphthong = matches!(c, 'ά' | 'έ' | 'ό' | 'ύ' | 'Ά' | 'Έ' | 'Ό' | 'Ύ');
+        upper.push(capital);
+    }
+    upper
+}
+
+/// converts a text into lowercase following the Greek orthography
+///
+/// Accents are kept, a sigma at the end of a word becomes the final sigma `ς`.
+/// Other characters are converted as by [`str::to_lowercase`].
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::to_lower;
+/// assert_eq!("οδυσσέας", to_lower("ΟΔΥΣΣΈΑΣ"));
+/// assert_eq!("σ, ας.", to_lower("Σ, ΑΣ."));
+/// ```
+pub fn to_lower(text: &str) -> String {
+    let chars : Vec<char> = text.chars().collect();
+    let mut lower = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c == 'Σ' || c == 'σ' || c == 'ς' {
+            let after_letter = i > 0 && chars[i - 1].is_alphabetic();
+            let before_letter = chars.get(i + 1).is_some_and(|c| c.is_alphabetic());
+            lower.push(if after_letter && !before_letter { 'ς' } else { 'σ' });
+        } else {
+            lower.extend(c.to_lowercase());
+        }
+    }
+    lower
+}
+
+// the collation weights of a single character
+struct Weights {
+    primary: (u8, u32),
+    secondary: u8,
+    tertiary: u8,
+    c: char,
+}
+impl Weights {
+    fn of(c: char) -> Self {
+        let letter = from_accented(c);
+        let primary = if letter != GreekAlphabet::Empty {
+            (3, letter as u32)
+        } else if let Some(digit) = c.to_digit(10) {
+            (1, digit)
+        } else if c.is_alphanumeric() {
+            (2, c.to_lowercase().next().unwrap_or(c) as u32)
+        } else {
+            (0, c as u32)
+        };
+        let secondary = match c {
+            'ά' | 'έ' | 'ή' | 'ί' | 'ό' | 'ύ' | 'ώ' | 'Ά' | 'Έ' | 'Ή' | 'Ί' | 'Ό' | 'Ύ' | 'Ώ' =>  1,
+            'ϊ' | 'ϋ' | 'Ϊ' | 'Ϋ'   =>  2,
+            'ΐ' | 'ΰ'               =>  3,
+            _                       =>  0
+        };
+        let tertiary = if c == 'ς' {
+            1
+        } else if c.is_uppercase() {
+            2
+        } else {
+            0
+        };
+        Weights { primary, secondary, tertiary, c }
+    }
+}
@@ -0,0 +1,284 @@
+use super::greek_letter::from_accented;
+use super::GreekAlphabet;
+
+/// the legacy 8-bit encodings for Greek text
+///
+/// All of them are ASCII in `0x00..=0x7F` and differ in the upper half.
+# [derive(Clone,Copy,PartialEq,Eq,Debug)]
+pub enum GreekEncoding {
+    /// ISO/IEC 8859-7:2003 (Latin/Greek)
+    Iso8859_7,
+    /// Windows code page 1253
+    Windows1253,
+    /// DOS code page 737
+    Cp737,
+}
+
+/// an error of [`GreekEncoding`]
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum EncodingError {
+    /// bytes without a character in the encoding, as `(offset, byte)`
+    UnmappableBytes(Vec<(usize, u8)>),
+    /// characters without a byte in the encoding, as `(offset, char)` where `offset` is the byte offset into the text
+    UnmappableChars(Vec<(usize, char)>),
+}
+
+impl GreekEncoding {
+    /// decodes bytes into a [`String`](https://doc.rust-lang.org/std/string/struct.String.html)
+    ///
+    /// # Errors
+    /// Returns `EncodingError::UnmappableBytes` with every byte which has no character in the encoding.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{EncodingError, GreekEncoding};
+    /// assert_eq!(Ok(String::from("πί")), GreekEncoding::Iso8859_7.decode(&[0xF0, 0xDF]));
+    /// assert_eq!(Ok(String::from("πί")), GreekEncoding::Cp737.decode(&[0xA7, 0xE5]));
+    /// assert_eq!(Err(EncodingError::UnmappableBytes(vec![(1, 0xFF)])), GreekEncoding::Windows1253.decode(&[0xF0, 0xFF]));
+    /// ```
+    pub fn decode(&self, bytes: &[u8]) -> Result<String, EncodingError> {
+        let mut text = String::with_capacity(bytes.len());
+        let mut unmappable = Vec::new();
+        for (offset, &byte) in bytes.iter().enumerate() {
+            match self.decode_byte(byte) {
+                Some(c) =>  text.push(c),
+                None    =>  unmappable.push((offset, byte))
+            }
+        }
+        if unmappable.is_empty() {
+            Ok(text)
+        } else {
+            Err(EncodingError::UnmappableBytes(unmappable))
+        }
+    }
+    /// decodes bytes into a [`String`](https://doc.rust-lang.org/std/string/struct.String.html), replacing unmappable bytes with `U+FFFD`
+    pub fn decode_lossy(&self, bytes: &[u8]) -> String {
+        bytes.iter().map(|&byte| self.decode_byte(byte).unwrap_or(char::REPLACEMENT_CHARACTER)).collect()
+    }
+    /// decodes bytes into a [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html) of `GreekAlphabet`
+    ///
+    /// Accents are dropped and every character which is not a Greek letter gives `GreekAlphabet::Empty`.
+    ///
+    /// # Errors
+    /// Returns `EncodingError::UnmappableBytes` with every byte which has no character in the encoding.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{GreekAlphabet, GreekEncoding};
+    /// let letters = GreekEncoding::Windows1253.decode_letters(&[0xA2, 0x20]).unwrap();
+    /// assert_eq!(vec![GreekAlphabet::Alpha, GreekAlphabet::Empty], letters);
+    /// ```
+    pub fn decode_letters(&self, bytes: &[u8]) -> Result<Vec<GreekAlphabet>, EncodingError> {
+        Ok(self.decode(bytes)?.chars().map(from_accented).collect())
+    }
+    /// encodes a text into bytes
+    ///
+    /// The Greek question mark `U+037E` and the ano teleia `U+0387` are written as their canonical equivalents `;` and `·`.
+    ///
+    /// # Errors
+    /// Returns `EncodingError::UnmappableChars` with every character which has no byte in the encoding.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{EncodingError, GreekEncoding};
+    /// assert_eq!(Ok(vec![0xF0, 0x3D, 0x33]), GreekEncoding::Iso8859_7.encode("π=3"));
+    /// assert_eq!(Err(EncodingError::UnmappableChars(vec![(2, '≈')])), GreekEncoding::Iso8859_7.encode("π≈3"));
+    /// ```
+    pub fn encode(&self, text: &str) -> Result<Vec<u8>, EncodingError> {
+        let mut bytes = Vec::with_capacity(text.len());
+        let mut unmappable = Vec::new();
+        for (offset, c) in text.char_indices() {
+            match self.encode_char(c) {
+                Some(byte)  =>  bytes.push(byte),
+                None        =>  unmappable.push((offset, c))
+            }
+        }
+        if unmappable.is_empty() {
+            Ok(bytes)
+        } else {
+            Err(EncodingError::UnmappableChars(unmappable))
+        }
+    }
+    /// encodes the lowercase letters of `GreekAlphabet`s into bytes
+    ///
+    /// # Panics
+    /// Panics when one of the letters is `GreekAlphabet::Empty`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{GreekAlphabet, GreekEncoding};
+    /// let bytes = GreekEncoding::Cp737.encode_letters(&[GreekAlphabet::Alpha, GreekAlphabet::Omega]);
+    /// assert_eq!(vec![0x98, 0xE0], bytes);
+    /// ```
+    pub fn encode_letters(&self, letters: &[GreekAlphabet]) -> Vec<u8> {
+        letters.iter().map(|letter| {
+            self.encode_char(letter.lowercase()).expect("every Greek letter exists in the Greek encodings")
+        }).collect()
+    }
+
+    /// guesses the encoding of bytes which contain Greek text
+    ///
+    /// Every encoding is scored by how much of the decoded text forms Greek words,
+    /// unmappable bytes and control characters count against it.
+    /// On a tie `Windows1253` is preferred over `Iso8859_7` and `Cp737`, so pure ASCII gives `Windows1253`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::GreekEncoding;
+    /// let text = "Άλφα και Ωμέγα";
+    /// for encoding in [GreekEncoding::Iso8859_7, GreekEncoding::Windows1253, GreekEncoding::Cp737] {
+    ///     assert_eq!(encoding, GreekEncoding::detect(&encoding.encode(text).unwrap()));
+    /// }
+    /// ```
+    pub fn detect(bytes: &[u8]) -> GreekEncoding {
+        let mut best = GreekEncoding::Windows1253;
+        let mut best_score = best.score(bytes);
+        for encoding in [GreekEncoding::Iso8859_7, GreekEncoding::Cp737] {
+            let score = encoding.score(bytes);
+            if score > best_score {
+                best = encoding;
+                best_score = score;
+            }
+        }
+        best
+    }
+
+    /// decodes a single byte
+    pub fn decode_byte(&self, byte: u8) -> Option<char> {
+        if byte < 0x80 {
+            return Some(byte as char)
+        }
+        match self.table()[byte as usize - 0x80] {
+            0   =>  None,
+            c   =>  std::char::from_u32(c as u32)
+        }
+    }
+    /// encodes a single character
+    pub fn encode_char(&self, c: char) -> Option<u8> {
+        let c = match c {
+            '\u{037E}'  =>  ';',
+            '\u{0387}'  =>  '·',
+            _           =>  c
+        };
+        if c.is_ascii() {
+            return Some(c as u8)
+        }
+        self.table().iter().position(|&t| t != 0 && t as u32 == c as u32).map(|i| i as u8 + 0x80)
+    }
+
+    fn table(&self) -> &'static [u16; 128] {
+        match self {
+            GreekEncoding::Iso8859_7    =>  &ISO_8859_7,
+            GreekEncoding::Windows1253  =>  &WINDOWS_1253,
+            GreekEncoding::Cp737        =>  &CP737
+        }
+    }
+    fn score(&self, bytes: &[u8]) -> i64 {
+        let mut score = 0;
+        let mut previous_letter = false;
+        for &byte in bytes {
+            let letter = match self.decode_byte(byte) {
+                None                                        =>  { score -= 10; false }
+                Some(c) if c.is_ascii()                     =>  c.is_ascii_alphabetic(),
+                Some(c) if c.is_control()                   =>  { score -= 5; false }
+                Some(c) if from_accented(c) != GreekAlphabet::Empty  =>  {
+                    score += if previous_letter { 3 } else { 2 };
+                    true
+                }
+                Some(_)                                     =>  { score -= 1; false }
+            };
+            previous_letter = letter;
+        }
+        score
+    }
+}
+
+impl std::fmt::Display for GreekEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let name = match self {
+            GreekEncoding::Iso8859_7    =>  "ISO-8859-7",
+            GreekEncoding::Windows1253  =>  "Windows-1253",
+            GreekEncoding::Cp737        =>  "CP737"
+        };
+        write!(f,"{}",name)
+    }
+}
+
+impl std::fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            EncodingError::UnmappableBytes(bytes)   =>  {
+                write!(f,"unmappable bytes:")?;
+                for (offset, byte) in bytes {
+                    write!(f," 0x{:02X} at {}",byte,offset)?;
+                }
+            }
+            EncodingError::UnmappableChars(chars)   =>  {
+                write!(f,"unmappable characters:")?;
+                for (offset, c) in chars {
+                    write!(f," {:?} at {}",c,offset)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+impl std::error::Error for EncodingError {}
+
+// the upper halves of the encodings, `0` marks an unmapped byte
+const ISO_8859_7: [u16; 128] = [
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087,
+    0x0088, 0x0089, 0x008A, 0x008B, 0x008C, 0x008D, 0x008E, 0x008F,
+    0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F,
+    0x00A0, 0x2018, 0x2019, 0x00A3, 0x20AC, 0x20AF, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x037A, 0x00AB, 0x00AC, 0x00AD, 0x0000, 0x2015,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x0384, 0x0385, 0x0386, 0x00B7,
+    0x0388, 0x0389, 0x038A, 0x00BB, 0x038C, 0x00BD, 0x038E, 0x038F,
+    0x0390, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397,
+    0x0398, 0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E, 0x039F,
+    0x03A0, 0x03A1, 0x0000, 0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7,
+    0x03A8, 0x03A9, 0x03AA, 0x03AB, 0x03AC, 0x03AD, 0x03AE, 0x03AF,
+    0x03B0, 0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7,
+    0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF,
+    0x03C0, 0x03C1, 0x03C2, 0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7,
+    0x03C8, 0x03C9, 0x03CA, 0x03CB, 0x03CC, 0x03CD, 0x03CE, 0x0000,
+];
+
+const WINDOWS_1253: [u16; 128] = [
+    0x20AC, 0x0000, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+    0x0000, 0x2030, 0x0000, 0x2039, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x0000, 0x2122, 0x0000, 0x203A, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x00A0, 0x0385, 0x0386, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x0000, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x2015,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x0384, 0x00B5, 0x00B6, 0x00B7,
+    0x0388, 0x0389, 0x038A, 0x00BB, 0x038C, 0x00BD, 0x038E, 0x038F,
+    0x0390, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397,
+    0x0398, 0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E, 0x039F,
+    0x03A0, 0x03A1, 0x0000, 0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7,
+    0x03A8, 0x03A9, 0x03AA, 0x03AB, 0x03AC, 0x03AD, 0x03AE, 0x03AF,
+    0x03B0, 0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7,
+    0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF,
+    0x03C0, 0x03C1, 0x03C2, 0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7,
+    0x03C8, 0x03C9, 0x03CA, 0x03CB, 0x03CC, 0x03CD, 0x03CE, 0x0000,
+];
+
+const CP737: [u16; 128] = [
+    0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398,
+    0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E, 0x039F, 0x03A0,
+    0x03A1, 0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9,
+    0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7, 0x03B8,
+    0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF, 0x03C0,
+    0x03C1, 0x03C3, 0x03C2, 0x03C4, 0x03C5, 0x03C6, 0x03C7, 0x03C8,
+    0x2591, 0x2592, 0x2593, 0x2502, 0x2524, 0x2561, 0x2562, 0x2556,
+    0x2555, 0x2563, 0x2551, 0x2557, 0x255D, 0x255C, 0x255B, 0x2510,
+    0x2514, 0x2534, 0x252C, 0x251C, 0x2500, 0x253C, 0x255E, 0x255F,
+    0x255A, 0x2554, 0x2569, 0x2566, 0x2560, 0x2550, 0x256C, 0x2567,
+    0x2568, 0x2564, 0x2565, 0x2559, 0x2558, 0x2552, 0x2553, 0x256B,
+    0x256A, 0x2518, 0x250C, 0x2588, 0x2584, 0x258C, 0x2590, 0x2580,
+    0x03C9, 0x03AC, 0x03AD, 0x03AE, 0x03CA, 0x03AF, 0x03CC, 0x03CD,
+    0x03CB, 0x03CE, 0x0386, 0x0388, 0x0389, 0x038A, 0x038C, 0x038E,
+    0x038F, 0x00B1, 0x2265, 0x2264, 0x03AA, 0x03AB, 0x00F7, 0x2248,
+    0x00B0, 0x2219, 0x00B7, 0x221A, 0x207F, 0x00B2, 0x25A0, 0x00A0,
+];
@@ -0,0 +1,264 @@
+use super::greek_letter::{from_accented, letter_name};
+use super::GreekAlphabet;
+
+/// the key layouts understood by [`GreekInput`]
+# [derive(Clone,Copy,PartialEq,Eq,Debug,Default)]
+pub enum InputLayout {
+    /// the standard Greek keyboard layout (`a`→`α`, `q`→`;`, `w`→`ς`, `;a`→`ά`, `:i`→`ϊ`)
+    Keyboard,
+    /// LaTeX-like commands (`\alpha`→`α`, `\Omega`→`Ω`), all other keys are passed through
+    Latex,
+    /// the Greek keyboard layout, with LaTeX-like commands starting at `\`
+    #[default]
+    Combined,
+}
+impl InputLayout {
+    /// converts a whole string of keystrokes into Greek text
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::InputLayout;
+    /// assert_eq!("καλημέρα", InputLayout::Keyboard.convert("kalhm;era"));
+    /// assert_eq!("Δϕ", InputLayout::Latex.convert("\\Delta\\varphi"));
+    /// assert_eq!("α+β", InputLayout::Latex.convert("\\alpha +\\beta"));
+    /// ```
+    pub fn convert(&self, keys: &str) -> String {
+        let mut input = GreekInput::new(*self);
+        let mut text = input.push_str(keys);
+        text.push_str(&input.finish());
+        text
+    }
+    /// converts a whole string of keystrokes into a [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html) of `GreekAlphabet`
+    ///
+    /// Accents are dropped and every character which is not a Greek letter gives `GreekAlphabet::Empty`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{GreekAlphabet, InputLayout};
+    /// let letters = InputLayout::Keyboard.letters("pi;");
+    /// assert_eq!(vec![GreekAlphabet::Pi, GreekAlphabet::Iota, GreekAlphabet::Empty], letters);
+    /// ```
+    pub fn letters(&self, keys: &str) -> Vec<GreekAlphabet> {
+        self.convert(keys).chars().map(from_accented).collect()
+    }
+}
+
+/// an input method for typing Greek on a Latin keyboard
+///
+/// Keys are fed one by one, the committed text is returned as soon as it is known.
+/// Dead keys (`;` for tonos, `:` for dialytika) and unfinished LaTeX commands are kept as preedit
+/// until the following key decides what they become.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{GreekInput, InputLayout};
+/// let mut input = GreekInput::new(InputLayout::Combined);
+/// assert_eq!("", input.push(';'));
+/// assert_eq!(";", input.preedit());
+/// assert_eq!("ά", input.push('a'));
+/// assert_eq!("", input.push_str("\\the"));
+/// assert!(input.backspace());
+/// assert_eq!("θ", input.push_str("eta "));
+/// ```
+# [derive(Clone,PartialEq,Eq,Debug,Default)]
+pub struct GreekInput {
+    layout: InputLayout,
+    pending: String,
+}
+impl GreekInput {
+    /// returns a new `GreekInput` for the given layout
+    pub fn new(layout: InputLayout) -> Self {
+        GreekInput { layout, pending: String::new() }
+    }
+    /// returns the layout of `GreekInput`
+    pub fn layout(&self) -> InputLayout {
+        self.layout
+    }
+    /// returns the keys which are typed, but not committed yet
+    pub fn preedit(&self) -> &str {
+        &self.pending
+    }
+    /// returns `true` while a dead key or a LaTeX command is waiting for more keys
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+    /// feeds one key and returns the text committed by it
+    pub fn push(&mut self, key: char) -> String {
+        let mut out = String::new();
+        self.feed(key, &mut out);
+        out
+    }
+    /// feeds all keys of a string and returns the text committed by them
+    pub fn push_str(&mut self, keys: &str) -> String {
+        let mut out = String::new();
+        for key in keys.chars() {
+            self.feed(key, &mut out);
+        }
+        out
+    }
+    /// removes the last key of the preedit
+    ///
+    /// Returns `false` if there was nothing pending, so the caller has to delete committed text itself.
+    pub fn backspace(&mut self) -> bool {
+        self.pending.pop().is_some()
+    }
+    /// commits everything which is still pending
+    ///
+    /// An unfinished dead key becomes its spacing accent (`΄`, `¨`, `΅`),
+    /// an unknown LaTeX command is committed as typed.
+    pub fn finish(&mut self) -> String {
+        let mut out = String::new();
+        if self.pending.starts_with('\\') {
+            self.commit_command(&mut out);
+        } else if !self.pending.is_empty() {
+            out.push(self.spacing_accent());
+            self.pending.clear();
+        }
+        out
+    }
+    /// drops everything which is still pending
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    fn feed(&mut self, key: char, out: &mut String) {
+        if self.pending.starts_with('\\') {
+            if key.is_ascii_alphabetic() {
+                self.pending.push(key);
+                return
+            }
+            if key == '\\' && self.pending.len() == 1 {
+                self.pending.clear();
+                out.push('\\');
+                return
+            }
+            if self.commit_command(out) && key == ' ' {
+                return
+            }
+        } else if !self.pending.is_empty() {
+            let tonos = self.pending.contains(';');
+            let dialytika = self.pending.contains(':');
+            if (key == ';' && !tonos) || (key == ':' && !dialytika) {
+                self.pending.push(key);
+                return
+            }
+            if let Some(c) = keyboard_key(key).and_then(|base| accented(base, tonos, dialytika)) {
+                self.pending.clear();
+                out.push(c);
+                return
+            }
+            out.push(self.spacing_accent());
+            self.pending.clear();
+            if key == ' ' || key == ';' || key == ':' {
+                return
+            }
+        }
+        self.start(key, out);
+    }
+    fn start(&mut self, key: char, out: &mut String) {
+        if key == '\\' && self.layout != InputLayout::Keyboard {
+            self.pending.push(key);
+            return
+        }
+        if self.layout != InputLayout::Latex {
+            if key == ';' || key == ':' {
+                self.pending.push(key);
+                return
+            }
+            if let Some(c) = keyboard_key(key) {
+                out.push(c);
+                return
+            }
+        }
+        out.push(key);
+    }
+    // commits the pending command, returns `true` if it was a known one
+    fn commit_command(&mut self, out: &mut String) -> bool {
+        let known = latex_command(&self.pending[1..]);
+        match known {
+            Some(c) =>  out.push(c),
+            None    =>  out.push_str(&self.pending)
+        }
+        self.pending.clear();
+        known.is_some()
+    }
+    fn spacing_accent(&self) -> char {
+        match (self.pending.contains(';'), self.pending.contains(':')) {
+            (true, true)    =>  '΅',
+            (false, true)   =>  '¨',
+            _               =>  '΄'
+        }
+    }
+}
+
+// the standard Greek keyboard layout, `;` and `:` are dead keys and handled by `GreekInput`
+fn keyboard_key(key: char) -> Option<char> {
+    let c = match key {
+        'a' => 'α', 'b' => 'β', 'c' => 'ψ', 'd' => 'δ', 'e' => 'ε', 'f' => 'φ', 'g' => 'γ',
+        'h' => 'η', 'i' => 'ι', 'j' => 'ξ', 'k' => 'κ', 'l' => 'λ', 'm' => 'μ', 'n' => 'ν',
+        'o' => 'ο', 'p' => 'π', 'q' => ';', 'r' => 'ρ', 's' => 'σ', 't' => 'τ', 'u' => 'θ',
+        'v' => 'ω', 'w' => 'ς', 'x' => 'χ', 'y' => 'υ', 'z' => 'ζ',
+        'A' => 'Α', 'B' => 'Β', 'C' => 'Ψ', 'D' => 'Δ', 'E' => 'Ε', 'F' => 'Φ', 'G' => 'Γ',
+        'H' => 'Η', 'I' => 'Ι', 'J' => 'Ξ', 'K' => 'Κ', 'L' => 'Λ', 'M' => 'Μ', 'N' => 'Ν',
+        'O' => 'Ο', 'P' => 'Π', 'Q' => ':', 'R' => 'Ρ', 'S' => 'Σ', 'T' => 'Τ', 'U' => 'Θ',
+        'V' => 'Ω', 'W' => 'Σ', 'X' => 'Χ', 'Y' => 'Υ', 'Z' => 'Ζ',
+        _   => return None
+    };
+    Some(c)
+}
+
+fn accented(base: char, tonos: bool, dialytika: bool) -> Option<char> {
+    let c = match (tonos, dialytika, base) {
+        (true, false, 'α') => 'ά', (true, false, 'Α') => 'Ά',
+        (true, false, 'ε') => 'έ', (true, false, 'Ε') => 'Έ',
+        (true, false, 'η') => 'ή', (true, false, 'Η') => 'Ή',
+        (true, false, 'ι') => 'ί', (true, false, 'Ι') => 'Ί',
+        (true, false, 'ο') => 'ό', (true, false, 'Ο') => 'Ό',
+        (true, false, 'υ') => 'ύ', (true, false, 'Υ') => 'Ύ',
+        (true, false, 'ω') => 'ώ', (true, false, 'Ω') => 'Ώ',
+        (false, true, 'ι') => 'ϊ', (false, true, 'Ι') => 'Ϊ',
+        (false, true, 'υ') => 'ϋ', (false, true, 'Υ') => 'Ϋ',
+        (true, true, 'ι')  => 'ΐ',
+        (true, true, 'υ')  => 'ΰ',
+        _                  => return None
+    };
+    Some(c)
+}
+
+// the glyph variants of LaTeX, the plain commands give the glyphs of `GreekAlphabet::lowercase()`
+pub(super) const VAR_GLYPHS : [(&str, char);7] = [
+    ("varepsilon", 'ϵ'), ("vartheta", 'ϑ'), ("varkappa", 'ϰ'), ("varpi", 'ϖ'),
+    ("varrho", 'ϱ'), ("varsigma", 'ς'), ("varphi", 'ϕ'),
+];
+
+// `\alpha` and `\Alpha` come from `greek_name()`, the `var` glyphs from `VAR_GLYPHS`
+pub(super) fn latex_command(name: &str) -> Option<char> {
+    if let Some(&(_, c)) = VAR_GLYPHS.iter().find(|(var, _)| *var == name) {
+        return Some(c)
+    }
+    let (letter, uppercase) = greek_name(name)?;
+    Some(if uppercase { letter.uppercase() } else { letter.lowercase() })
+}
+
+// the letter of a name like `alpha` or `Alpha`, with the LaTeX spellings `mu`, `nu` and `upsilon`,
+// and whether it is the uppercase letter
+pub(super) fn greek_name(name: &str) -> Option<(GreekAlphabet, bool)> {
+    match name {
+        "mu"        =>  return Some((GreekAlphabet::My, false)),
+        "Mu"        =>  return Some((GreekAlphabet::My, true)),
+        "nu"        =>  return Some((GreekAlphabet::Ny, false)),
+        "Nu"        =>  return Some((GreekAlphabet::Ny, true)),
+        "upsilon"   =>  return Some((GreekAlphabet::Ypsilon, false)),
+        "Upsilon"   =>  return Some((GreekAlphabet::Ypsilon, true)),
+        _           =>  {}
+    }
+    (1..=24u8).map(GreekAlphabet::from).find_map(|letter| {
+        if name == letter_name(letter) {
+            Some((letter, true))
+        } else if name == letter_name(letter).to_lowercase() {
+            Some((letter, false))
+        } else {
+            None
+        }
+    })
+}
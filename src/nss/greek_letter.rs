@@ -0,0 +1,37 @@
+use super::GreekAlphabet;
+
+// the names of the letters in the order of `GreekAlphabet`, as `Display` writes them
+const NAMES : [&str;24] = [
+    "Alpha", "Beta", "Gamma", "Delta", "Epsilon", "Zeta",
+    "Eta", "Theta", "Iota", "Kappa", "Lambda", "My",
+    "Ny", "Xi", "Omicron", "Pi", "Rho", "Sigma",
+    "Tau", "Ypsilon", "Phi", "Chi", "Psi", "Omega",
+];
+
+// the name of a letter like `Lambda`, which panics for `GreekAlphabet::Empty` like `Display`
+pub(super) fn letter_name(letter: GreekAlphabet) -> &'static str {
+    NAMES.get(letter as usize).copied().expect("GreekAlphabet::Empty has no name")
+}
+
+// the letter of a character like `GreekAlphabet::from(char)`, which also accepts the letters with tonos or dialytika,
+// the final sigma `ς`, the symbol variants like `ϑ` or `ϕ` and the micro sign `µ`
+pub(super) fn from_accented(c: char) -> GreekAlphabet {
+    match c {
+        'ά' | 'Ά'                       =>  GreekAlphabet::Alpha,
+        'ϐ'                             =>  GreekAlphabet::Beta,
+        'έ' | 'Έ' | 'ϵ'                 =>  GreekAlphabet::Epsilon,
+        'ή' | 'Ή'                       =>  GreekAlphabet::Eta,
+        'ϑ' | 'ϴ'                       =>  GreekAlphabet::Theta,
+        'ί' | 'Ί' | 'ϊ' | 'Ϊ' | 'ΐ'     =>  GreekAlphabet::Iota,
+        'ϰ'                             =>  GreekAlphabet::Kappa,
+        'µ'                             =>  GreekAlphabet::My,
+        'ό' | 'Ό'                       =>  GreekAlphabet::Omicron,
+        'ϖ'                             =>  GreekAlphabet::Pi,
+        'ϱ'                             =>  GreekAlphabet::Rho,
+        'ς' | 'ϲ' | 'Ϲ'                 =>  GreekAlphabet::Sigma,
+        'ύ' | 'Ύ' | 'ϋ' | 'Ϋ' | 'ΰ' | 'ϒ' =>  GreekAlphabet::Ypsilon,
+        'ϕ'                             =>  GreekAlphabet::Phi,
+        'ώ' | 'Ώ'                       =>  GreekAlphabet::Omega,
+        _                               =>  GreekAlphabet::from(c)
+    }
+}
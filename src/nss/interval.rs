@@ -0,0 +1,659 @@
+use super::Real;
+
+// the steps of `Interval::newton`, after which the intervals left are returned as they are
+const NEWTON_STEPS : usize = 10_000;
+
+/// a closed interval `[lo, hi]` of real numbers with outward rounded arithmetic
+///
+/// The result of each operation encloses all results of the operation for numbers of the operands,
+/// so a computation with intervals returns an enclosure of the exact result.
+/// The basic operations and the square root are correctly rounded by IEEE 754 and round outward only if they are not exact,
+/// so their enclosures are verified.
+/// The elementary functions widen the results of the platform by two units in the last place,
+/// their enclosures assume that the platform's math library is accurate to two units in the last place,
+/// which common libraries are for `f64` and `f32` but no standard guarantees.
+/// The bounds may be infinite, and the empty interval has bounds which are not a number.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Interval;
+/// let tenth = Interval::point(1.0) / 10.0;
+/// assert_eq!((0.1f64.next_down(), 0.1), (tenth.lo(), tenth.hi()));
+/// let one: Interval<f64> = (0..10).map(|_| tenth).sum();
+/// assert!(one.contains(1.0) && one.width() < 1e-15);
+/// let x = Interval::new(1.0, 2.0);
+/// assert_eq!(Interval::new(-1.0, 3.0), x * x - x);
+/// assert_eq!(Interval::new(0.0, 2.0), x * (x - 1.0));
+/// assert_eq!("[1, 2]", x.to_string());
+/// ```
+# [derive(Clone,Copy,Debug)]
+pub struct Interval<T: Real> {
+    lo: T,
+    hi: T,
+}
+
+/// an enclosure of a root of [`Interval::newton`]
+# [derive(Clone,Copy,PartialEq,Debug)]
+pub struct IntervalRoot<T: Real> {
+    interval: Interval<T>,
+    unique: bool,
+}
+
+impl<T: Real> IntervalRoot<T> {
+    /// returns the interval which contains the root if there is one
+    pub fn interval(&self) -> Interval<T> {
+        self.interval
+    }
+    /// returns `true` if the interval is proven to contain exactly one root
+    pub fn is_unique(&self) -> bool {
+        self.unique
+    }
+}
+
+impl<T: Real> Interval<T> {
+    /// returns `[lo, hi]`
+    ///
+    /// # Panics
+    /// if `lo > hi`, a bound is not a number, `lo` is positive infinity or `hi` is negative infinity
+    pub fn new(lo: T, hi: T) -> Self {
+        assert!(lo <= hi, "the lower bound is greater than the upper bound or not a number");
+        assert!(lo < T::infinity() && hi > -T::infinity(), "an infinite point");
+        Interval { lo, hi }
+    }
+    /// returns `[x, x]`
+    ///
+    /// # Panics
+    /// if the number is not finite
+    pub fn point(x: T) -> Self {
+        assert!(x.is_finite(), "an infinite point");
+        Interval { lo: x, hi: x }
+    }
+    /// returns the empty interval
+    pub fn empty() -> Self {
+        Interval { lo: T::nan(), hi: T::nan() }
+    }
+    /// returns the interval of all real numbers
+    pub fn entire() -> Self {
+        Interval { lo: -T::infinity(), hi: T::infinity() }
+    }
+    /// returns the lower bound, not a number for the empty interval
+    pub fn lo(&self) -> T {
+        self.lo
+    }
+    /// returns the upper bound, not a number for the empty interval
+    pub fn hi(&self) -> T {
+        self.hi
+    }
+    /// returns `true` for the empty interval
+    pub fn is_empty(&self) -> bool {
+        self.lo.is_nan()
+    }
+    /// returns `true` for the interval of all real numbers
+    pub fn is_entire(&self) -> bool {
+        self.lo == -T::infinity() && self.hi == T::infinity()
+    }
+    /// returns `true` if the number is in the interval
+    pub fn contains(&self, x: T) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+    /// returns `true` if all numbers of the interval are in the other
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.is_empty() || (other.lo <= self.lo && self.hi <= other.hi)
+    }
+    /// returns `true` if the interval is in the interior of the other,
+    /// where infinite bounds of the other contain infinite bounds of the interval
+    pub fn is_interior(&self, other: &Self) -> bool {
+        self.is_empty() || (
+            (other.lo < self.lo || other.lo == -T::infinity()) && (self.hi < other.hi || other.hi == T::infinity())
+        )
+    }
+    /// returns a number in the interval near its midpoint, `0` for the entire interval
+    /// and the largest finite number for an infinite bound
+    pub fn mid(&self) -> T {
+        if self.is_empty() || self.is_entire() {
+            return if self.is_empty() { T::nan() } else { T::zero() }
+        }
+        let max = T::infinity().next_down();
+        let (lo, hi) = (self.lo.max(-max), self.hi.min(max));
+        let half = T::from_f64(0.5);
+        let sum = lo + hi;
+        let mid = if sum.is_finite() { sum * half } else { lo * half + hi * half };
+        mid.max(self.lo).min(self.hi)
+    }
+    /// returns the width `hi - lo` rounded up
+    pub fn width(&self) -> T {
+        difference(self.hi, self.lo)[1]
+    }
+    /// returns the largest distance of the bounds from the midpoint rounded up
+    pub fn radius(&self) -> T {
+        let mid = self.mid();
+        difference(mid, self.lo)[1].max(difference(self.hi, mid)[1])
+    }
+    /// returns the largest absolute value of the numbers of the interval
+    pub fn mag(&self) -> T {
+        self.lo.abs().max(self.hi.abs())
+    }
+    /// returns the smallest absolute value of the numbers of the interval
+    pub fn mig(&self) -> T {
+        if self.contains(T::zero()) {
+            T::zero()
+        } else {
+            self.lo.abs().min(self.hi.abs())
+        }
+    }
+    /// returns the smallest interval which contains both intervals
+    pub fn hull(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return *other
+        }
+        if other.is_empty() {
+            return *self
+        }
+        Interval { lo: self.lo.min(other.lo), hi: self.hi.max(other.hi) }
+    }
+    /// returns the numbers which are in both intervals
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (lo, hi) = (self.lo.max(other.lo), self.hi.min(other.hi));
+        if self.is_empty() || other.is_empty() || lo > hi {
+            return Interval::empty()
+        }
+        Interval { lo, hi }
+    }
+    /// returns the halves at the midpoint
+    pub fn bisect(&self) -> (Self, Self) {
+        let mid = self.mid();
+        (Interval { lo: self.lo, hi: mid }, Interval { lo: mid, hi: self.hi })
+    }
+
+    /// returns the quotient as the union of two intervals, of which the second may be empty,
+    /// so that a divisor which contains zero in its interior keeps both unbounded parts
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Interval;
+    /// let (x, y) = (Interval::new(1.0, 2.0), Interval::new(-1.0, 4.0));
+    /// let (negative, positive) = x.extended_div(&y);
+    /// assert_eq!(Interval::new(f64::NEG_INFINITY, -1.0), negative);
+    /// assert_eq!(Interval::new(0.25, f64::INFINITY), positive);
+    /// assert!((x / y).is_entire());
+    /// assert_eq!((Interval::new(0.5, 2.0), Interval::empty()), x.extended_div(&Interval::new(1.0, 2.0)));
+    /// ```
+    pub fn extended_div(&self, other: &Self) -> (Self, Self) {
+        let empty = Interval::empty();
+        if self.is_empty() || other.is_empty() || (other.lo == T::zero() && other.hi == T::zero()) {
+            return (empty, empty)
+        }
+        if !other.contains(T::zero()) {
+            return (self.div_interval(*other), empty)
+        }
+        if self.contains(T::zero()) {
+            return (Interval::entire(), empty)
+        }
+        let infinity = T::infinity();
+        let below = |bound: T| Interval { lo: -infinity, hi: bound };
+        let above = |bound: T| Interval { lo: bound, hi: infinity };
+        // the bound of the dividend nearest to zero divided by the bounds of the divisor
+        let near = if self.hi < T::zero() { self.hi } else { self.lo };
+        let (to_negative, to_positive) = if self.hi < T::zero() { (other.hi, other.lo) } else { (other.lo, other.hi) };
+        if to_negative == T::zero() {
+            (above(quotient(near, to_positive)[0]), empty)
+        } else if to_positive == T::zero() {
+            (below(quotient(near, to_negative)[1]), empty)
+        } else {
+            (below(quotient(near, to_negative)[1]), above(quotient(near, to_positive)[0]))
+        }
+    }
+    /// returns `1/x`, the hull of both parts for intervals which contain zero
+    pub fn recip(&self) -> Self {
+        Interval { lo: T::one(), hi: T::one() }.div_interval(*self)
+    }
+    /// returns the absolute values
+    pub fn abs(&self) -> Self {
+        if self.is_empty() {
+            return *self
+        }
+        Interval { lo: self.mig(), hi: self.mag() }
+    }
+    /// returns the interval to an integer power
+    pub fn powi(&self, n: i32) -> Self {
+        if self.is_empty() {
+            return *self
+        }
+        let exponent = n.unsigned_abs();
+        let power = if exponent.is_multiple_of(2) {
+            power(Interval { lo: self.mig(), hi: self.mag() }, exponent)
+        } else {
+            let lo = power(Interval { lo: self.lo, hi: self.lo }, exponent).lo;
+            let hi = power(Interval { lo: self.hi, hi: self.hi }, exponent).hi;
+            Interval { lo, hi }
+        };
+        if n < 0 { power.recip() } else { power }
+    }
+    /// returns the square roots of the non-negative numbers
+    pub fn sqrt(&self) -> Self {
+        let domain = self.intersection(&Interval { lo: T::zero(), hi: T::infinity() });
+        if domain.is_empty() {
+            return domain
+        }
+        Interval { lo: square_root(domain.lo)[0].max(T::zero()), hi: square_root(domain.hi)[1] }
+    }
+    /// returns `eˣ`
+    pub fn exp(&self) -> Self {
+        self.increasing(|x| x.exp(), T::zero(), T::infinity())
+    }
+    /// returns the natural logarithms of the positive numbers
+    pub fn ln(&self) -> Self {
+        let domain = self.intersection(&Interval { lo: T::zero(), hi: T::infinity() });
+        if domain.hi == T::zero() {
+            return Interval::empty()
+        }
+        domain.increasing(|x| x.ln(), -T::infinity(), T::infinity())
+    }
+    /// returns the sines
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Interval;
+    /// let x = Interval::new(1.0, 5.0).sin();
+    /// assert_eq!((-1.0, 1.0), (x.lo(), x.hi()));
+    /// let y = Interval::new(2.0, 4.0).sin();
+    /// assert!(y.lo() < 4f64.sin() && 2f64.sin() < y.hi() && y.width() < 2.0);
+    /// ```
+    pub fn sin(&self) -> Self {
+        let quarter = pi::<T>().scale(T::from_f64(0.5));
+        self.periodic(|x| x.sin(), quarter, -quarter)
+    }
+    /// returns the cosines
+    pub fn cos(&self) -> Self {
+        self.periodic(|x| x.cos(), Interval { lo: T::zero(), hi: T::zero() }, pi())
+    }
+    /// returns the tangents, the entire interval if it may contain a pole
+    pub fn tan(&self) -> Self {
+        let pole = pi::<T>().scale(T::from_f64(0.5));
+        if self.may_contain(pole, pi()) {
+            return Interval::entire()
+        }
+        self.increasing(|x| x.tan(), -T::infinity(), T::infinity())
+    }
+    /// returns the arcsines of the numbers in `-1..=1`
+    pub fn asin(&self) -> Self {
+        let half = pi::<T>().hi * T::from_f64(0.5);
+        self.intersection(&Interval { lo: -T::one(), hi: T::one() }).increasing(|x| x.asin(), -half, half)
+    }
+    /// returns the arccosines of the numbers in `-1..=1`
+    pub fn acos(&self) -> Self {
+        let domain = self.intersection(&Interval { lo: -T::one(), hi: T::one() });
+        // the arccosine decreases, so the bounds change places
+        Interval { lo: domain.hi, hi: domain.lo }.increasing(|x| x.acos(), T::zero(), pi::<T>().hi)
+    }
+    /// returns the arctangents
+    pub fn atan(&self) -> Self {
+        let half = pi::<T>().hi * T::from_f64(0.5);
+        self.increasing(|x| x.atan(), -half, half)
+    }
+    /// returns the hyperbolic sines
+    pub fn sinh(&self) -> Self {
+        self.increasing(|x| x.sinh(), -T::infinity(), T::infinity())
+    }
+    /// returns the hyperbolic cosines
+    pub fn cosh(&self) -> Self {
+        self.abs().increasing(|x| x.cosh(), T::one(), T::infinity())
+    }
+    /// returns the hyperbolic tangents
+    pub fn tanh(&self) -> Self {
+        self.increasing(|x| x.tanh(), -T::one(), T::one())
+    }
+
+    /// returns enclosures of all roots of a function in the interval by the interval Newton method in increasing order,
+    /// which are narrower than the tolerance or cannot be narrowed further
+    ///
+    /// The functions must return enclosures of the function and its derivative for all numbers of an interval.
+    /// An enclosure is [`unique`](IntervalRoot::is_unique) if a Newton step proved that it contains exactly one root,
+    /// while other enclosures may also contain no root. No enclosure is returned if there is no root.
+    ///
+    /// The method stops after 10000 steps, for example for a function which is zero on a whole interval.
+    /// Then the intervals which are not narrowed yet are returned as they are, wider than the tolerance,
+    /// so the enclosures still contain all roots.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Interval;
+    /// let f = |x: Interval<f64>| x.powi(2) - 2.0;
+    /// let derivative = |x: Interval<f64>| x * 2.0;
+    /// let roots = Interval::new(-3.0, 3.0).newton(f, derivative, 1e-12);
+    /// assert_eq!(2, roots.len());
+    /// assert!(roots.iter().all(|root| root.is_unique() && root.interval().width() <= 1e-12));
+    /// assert!(roots.iter().any(|root| root.interval().contains(2f64.sqrt())));
+    /// assert!(Interval::new(3.0, 4.0).newton(f, derivative, 1e-12).is_empty());
+    /// let zero = Interval::new(0.0, 1.0).newton(|x| x * 0.0, |x| x - x, 1e-12);
+    /// assert!(zero.len() < 20_000 && zero.iter().any(|root| root.interval().width() > 1e-12));
+    /// ```
+    pub fn newton<F, D>(&self, function: F, derivative: D, tolerance: T) -> Vec<IntervalRoot<T>>
+    where F: Fn(Self) -> Self, D: Fn(Self) -> Self {
+        let mut roots = Vec::new();
+        let mut stack = vec![(*self, false)];
+        let mut steps = 0;
+        while let Some((x, unique)) = stack.pop() {
+            if x.is_empty() || !function(x).contains(T::zero()) {
+                continue;
+            }
+            if steps == NEWTON_STEPS {
+                roots.push(IntervalRoot { interval: x, unique });
+                continue;
+            }
+            steps += 1;
+            let mid = x.mid();
+            if x.width() <= tolerance || (mid.next_down() <= x.lo && x.hi <= mid.next_up()) {
+                roots.push(IntervalRoot { interval: x, unique });
+                continue;
+            }
+            let point = Interval { lo: mid, hi: mid };
+            let (first, second) = function(point).extended_div(&derivative(x));
+            let (first, second) = (point.sub_interval(first), point.sub_interval(second));
+            let unique = unique || (second.is_empty() && first.is_interior(&x));
+            let (first, second) = (x.intersection(&first), x.intersection(&second));
+            if first.is_empty() && second.is_empty() {
+                continue;
+            }
+            // a step which does not halve the interval is replaced by a bisection
+            let halved = first.hull(&second).width() <= x.width() * T::from_f64(0.5);
+            if halved || (unique && first != x) {
+                stack.push((second, unique));
+                stack.push((first, unique));
+            } else if unique {
+                roots.push(IntervalRoot { interval: x, unique });
+            } else {
+                // off the midpoint, which is often a root itself and could not be proven in either half
+                let split = if x.width().is_finite() { x.lo + (x.hi - x.lo) * T::from_f64(0.4375) } else { mid };
+                stack.push((Interval { lo: split, hi: x.hi }, false));
+                stack.push((Interval { lo: x.lo, hi: split }, false));
+            }
+        }
+        roots.sort_by(|a, b| a.interval.lo.partial_cmp(&b.interval.lo).unwrap_or(std::cmp::Ordering::Equal));
+        roots
+    }
+
+    // applies a function which increases, within its range
+    fn increasing(&self, function: impl Fn(T) -> T, min: T, max: T) -> Self {
+        if self.is_empty() {
+            return *self
+        }
+        let lo = widened(function(self.lo))[0].max(min).min(max);
+        let hi = widened(function(self.hi))[1].max(min).min(max);
+        Interval { lo, hi }
+    }
+    // applies sine or cosine with the maximum at `maximum` and the minimum at `minimum`
+    fn periodic(&self, function: impl Fn(T) -> T, maximum: Self, minimum: Self) -> Self {
+        if self.is_empty() {
+            return *self
+        }
+        let (a, b) = (widened(function(self.lo)), widened(function(self.hi)));
+        let period = pi::<T>().scale(T::from_f64(2.0));
+        let lo = if self.may_contain(minimum, period) { -T::one() } else { a[0].min(b[0]).max(-T::one()) };
+        let hi = if self.may_contain(maximum, period) { T::one() } else { a[1].max(b[1]).min(T::one()) };
+        Interval { lo, hi }
+    }
+    // returns `true` unless no number of the interval is `point + k·period` for an integer `k`
+    fn may_contain(&self, point: Self, period: Self) -> bool {
+        let turns = self.sub_interval(point).div_interval(period);
+        !(turns.lo.is_finite() && turns.hi.is_finite()) || turns.hi.floor() >= turns.lo.ceil()
+    }
+    // multiplies by a power of two, which is exact
+    fn scale(&self, factor: T) -> Self {
+        Interval { lo: self.lo * factor, hi: self.hi * factor }
+    }
+
+    fn add_interval(self, other: Self) -> Self {
+        Interval { lo: sum(self.lo, other.lo)[0], hi: sum(self.hi, other.hi)[1] }
+    }
+    fn sub_interval(self, other: Self) -> Self {
+        Interval { lo: difference(self.lo, other.hi)[0], hi: difference(self.hi, other.lo)[1] }
+    }
+    fn mul_interval(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return Interval::empty()
+        }
+        let products = [
+            product(self.lo, other.lo), product(self.lo, other.hi),
+            product(self.hi, other.lo), product(self.hi, other.hi)
+        ];
+        Interval {
+            lo: products.iter().fold(T::infinity(), |lo, bounds| lo.min(bounds[0])),
+            hi: products.iter().fold(-T::infinity(), |hi, bounds| hi.max(bounds[1]))
+        }
+    }
+    fn div_interval(self, other: Self) -> Self {
+        if self.is_empty() || other.is_empty() {
+            return Interval::empty()
+        }
+        if other.contains(T::zero()) {
+            let (first, second) = self.extended_div(&other);
+            return first.hull(&second)
+        }
+        let quotients = [
+            quotient(self.lo, other.lo), quotient(self.lo, other.hi),
+            quotient(self.hi, other.lo), quotient(self.hi, other.hi)
+        ];
+        Interval {
+            lo: quotients.iter().fold(T::infinity(), |lo, bounds| lo.min(bounds[0])),
+            hi: quotients.iter().fold(-T::infinity(), |hi, bounds| hi.max(bounds[1]))
+        }
+    }
+}
+
+// the interval to a positive power by squaring
+fn power<T: Real>(base: Interval<T>, mut exponent: u32) -> Interval<T> {
+    let mut result = Interval { lo: T::one(), hi: T::one() };
+    let mut base = base;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = result.mul_interval(base);
+        }
+        base = base.mul_interval(base);
+        exponent /= 2;
+    }
+    result
+}
+
+// an enclosure of π
+fn pi<T: Real>() -> Interval<T> {
+    Interval { lo: T::pi().next_down(), hi: T::pi().next_up() }
+}
+
+// the smallest magnitude of results of which the errors of products and quotients do not underflow
+fn tiny<T: Real>() -> T {
+    T::min_positive() / (T::epsilon() * T::epsilon())
+}
+
+// the bounds of a rounded value from the sign of its error, both neighbours if the error is unknown
+fn bounds<T: Real>(value: T, error: Option<T>) -> [T; 2] {
+    match error {
+        Some(error) if error > T::zero()    =>  [value, value.next_up()],
+        Some(error) if error < T::zero()    =>  [value.next_down(), value],
+        Some(error) if error == T::zero()   =>  [value, value],
+        _                                   =>  [value.next_down(), value.next_up()]
+    }
+}
+
+// the rounded down and rounded up sum
+fn sum<T: Real>(a: T, b: T) -> [T; 2] {
+    let sum = a + b;
+    if !sum.is_finite() {
+        let exact = !(a.is_finite() && b.is_finite());
+        return bounds(sum, if exact { Some(T::zero()) } else { None })
+    }
+    // the error free transformation of Knuth
+    let b_part = sum - a;
+    let error = (a - (sum - b_part)) + (b - b_part);
+    bounds(sum, Some(error))
+}
+
+// the rounded down and rounded up difference
+fn difference<T: Real>(a: T, b: T) -> [T; 2] {
+    sum(a, -b)
+}
+
+// the rounded down and rounded up product, where zero times infinity is zero
+fn product<T: Real>(a: T, b: T) -> [T; 2] {
+    if a == T::zero() || b == T::zero() {
+        return [T::zero(), T::zero()]
+    }
+    let product = a * b;
+    if !product.is_finite() {
+        let exact = !(a.is_finite() && b.is_finite());
+        return bounds(product, if exact { Some(T::zero()) } else { None })
+    }
+    if product.abs() < tiny() {
+        return bounds(product, None)
+    }
+    bounds(product, Some(a.mul_add(b, -product)))
+}
+
+// the rounded down and rounded up quotient for a divisor which is not zero
+fn quotient<T: Real>(a: T, b: T) -> [T; 2] {
+    let quotient = a / b;
+    if a == T::zero() || b.is_infinite() && a.is_finite() {
+        return [quotient, quotient]
+    }
+    if !quotient.is_finite() {
+        let exact = !(a.is_finite() && b.is_finite());
+        return bounds(quotient, if exact { Some(T::zero()) } else { None })
+    }
+    if quotient.abs() < tiny() || a.abs() < tiny() {
+        return bounds(quotient, None)
+    }
+    // the exact remainder a - q·b has the sign of the error times the sign of b
+    let remainder = -quotient.mul_add(b, -a);
+    bounds(quotient, Some(if b > T::zero() { remainder } else { -remainder }))
+}
+
+// the rounded down and rounded up square root of a non-negative number
+fn square_root<T: Real>(x: T) -> [T; 2] {
+    let root = x.sqrt();
+    if x == T::zero() || x.is_infinite() {
+        return [root, root]
+    }
+    if x < tiny() {
+        return bounds(root, None)
+    }
+    bounds(root, Some((-root).mul_add(root, x)))
+}
+
+// the bounds of a result of an elementary function, assuming the math library errs by at most two units in the last place
+fn widened<T: Real>(value: T) -> [T; 2] {
+    [value.next_down().next_down(), value.next_up().next_up()]
+}
+
+impl<T: Real> std::cmp::PartialEq for Interval<T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.is_empty() && other.is_empty()) || (self.lo == other.lo && self.hi == other.hi)
+    }
+}
+
+impl<T: Real> std::fmt::Display for Interval<T> {
+    /// writes `[lo, hi]` or `[empty]`, a precision applies to each bound
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        if self.is_empty() {
+            return write!(f,"[empty]")
+        }
+        match f.precision() {
+            Some(precision) =>  write!(f,"[{:.*}, {:.*}]",precision,self.lo,precision,self.hi),
+            None            =>  write!(f,"[{}, {}]",self.lo,self.hi)
+        }
+    }
+}
+
+// the operators for intervals
+macro_rules! impl_interval_ops {
+    ($($trait:ident, $method:ident, $assign:ident, $assign_method:ident, $core:ident;)*) => {
+        $(
+            impl<T: Real> std::ops::$trait for Interval<T> {
+                type Output = Interval<T>;
+                fn $method(self, other: Interval<T>) -> Interval<T> {
+                    self.$core(other)
+                }
+            }
+            impl<T: Real> std::ops::$trait<T> for Interval<T> {
+                type Output = Interval<T>;
+                fn $method(self, other: T) -> Interval<T> {
+                    self.$core(Interval::point(other))
+                }
+            }
+            impl<T: Real> std::ops::$assign for Interval<T> {
+                fn $assign_method(&mut self, other: Interval<T>) {
+                    *self = self.$core(other);
+                }
+            }
+            impl<T: Real> std::ops::$assign<T> for Interval<T> {
+                fn $assign_method(&mut self, other: T) {
+                    *self = self.$core(Interval::point(other));
+                }
+            }
+        )*
+    };
+}
+impl_interval_ops! {
+    Add, add, AddAssign, add_assign, add_interval;
+    Sub, sub, SubAssign, sub_assign, sub_interval;
+    Mul, mul, MulAssign, mul_assign, mul_interval;
+    Div, div, DivAssign, div_assign, div_interval;
+}
+macro_rules! impl_real_interval_ops {
+    ($($float:ty),*) => {
+        $(
+            impl std::ops::Add<Interval<$float>> for $float {
+                type Output = Interval<$float>;
+                fn add(self, other: Interval<$float>) -> Interval<$float> {
+                    Interval::point(self).add_interval(other)
+                }
+            }
+            impl std::ops::Sub<Interval<$float>> for $float {
+                type Output = Interval<$float>;
+                fn sub(self, other: Interval<$float>) -> Interval<$float> {
+                    Interval::point(self).sub_interval(other)
+                }
+            }
+            impl std::ops::Mul<Interval<$float>> for $float {
+                type Output = Interval<$float>;
+                fn mul(self, other: Interval<$float>) -> Interval<$float> {
+                    Interval::point(self).mul_interval(other)
+                }
+            }
+            impl std::ops::Div<Interval<$float>> for $float {
+                type Output = Interval<$float>;
+                fn div(self, other: Interval<$float>) -> Interval<$float> {
+                    Interval::point(self).div_interval(other)
+                }
+            }
+        )*
+    };
+}
+impl_real_interval_ops!(f32, f64);
+
+impl<T: Real> std::ops::Neg for Interval<T> {
+    type Output = Interval<T>;
+    fn neg(self) -> Interval<T> {
+        Interval { lo: -self.hi, hi: -self.lo }
+    }
+}
+
+impl<T: Real> std::iter::Sum for Interval<T> {
+    fn sum<I: Iterator<Item = Interval<T>>>(iter: I) -> Self {
+        iter.fold(Interval::point(T::zero()), |sum, value| sum + value)
+    }
+}
+impl<T: Real> std::iter::Product for Interval<T> {
+    fn product<I: Iterator<Item = Interval<T>>>(iter: I) -> Self {
+        iter.fold(Interval::point(T::one()), |product, value| product * value)
+    }
+}
+
+impl<T: Real> std::convert::From<T> for Interval<T> {
+    /// returns `[x, x]`
+    ///
+    /// # Panics
+    /// if the number is not finite
+    fn from(x: T) -> Self {
+        Interval::point(x)
+    }
+}
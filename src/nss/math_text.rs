@@ -0,0 +1,362 @@
+use super::greek_letter::{from_accented, letter_name};
+use super::GreekAlphabet;
+use super::greek_input::{latex_command, VAR_GLYPHS};
+use super::script::{from_subscript, from_superscript, is_subscript, is_superscript};
+
+// (symbol, LaTeX command, ASCII operator, word)
+const OPERATORS : [(char, &str, &str, &str);56] = [
+    ('≤', "leq", "<=", ""),         ('≤', "le", "", ""),
+    ('≥', "geq", ">=", ""),         ('≥', "ge", "", ""),
+    ('≠', "neq", "!=", ""),         ('≠', "ne", "", ""),
+    ('≈', "approx", "~=", ""),      ('≡', "equiv", "", ""),
+    ('≪', "ll", "<<", ""),          ('≫', "gg", ">>", ""),
+    ('±', "pm", "+-", ""),          ('∓', "mp", "-+", ""),
+    ('×', "times", "", ""),         ('·', "cdot", "", ""),
+    ('÷', "div", "", ""),           ('∞', "infty", "", "inf"),
+    ('∞', "", "", "infinity"),      ('∑', "sum", "", "sum"),
+    ('∏', "prod", "", "prod"),      ('∫', "int", "", "int"),
+    ('∮', "oint", "", ""),          ('√', "sqrt", "", "sqrt"),
+    ('∂', "partial", "", ""),       ('∇', "nabla", "", "nabla"),
+    ('∀', "forall", "", "forall"),  ('∃', "exists", "", "exists"),
+    ('∈', "in", "", ""),            ('∉', "notin", "", ""),
+    ('⊂', "subset", "", ""),        ('⊆', "subseteq", "", ""),
+    ('⊃', "supset", "", ""),        ('⊇', "supseteq", "", ""),
+    ('∪', "cup", "", ""),           ('∩', "cap", "", ""),
+    ('∅', "emptyset", "", "emptyset"),
+    ('→', "to", "->", ""),          ('→', "rightarrow", "", ""),
+    ('←', "leftarrow", "<-", ""),   ('⇒', "Rightarrow", "=>", ""),
+    ('⇐', "Leftarrow", "", ""),     ('⇔', "Leftrightarrow", "<=>", ""),
+    ('↔', "leftrightarrow", "<->", ""),
+    ('↦', "mapsto", "|->", ""),     ('∧', "land", "", ""),
+    ('∨', "lor", "", ""),           ('¬', "neg", "", ""),
+    ('∝', "propto", "", ""),        ('∼', "sim", "", ""),
+    ('∘', "circ", "", ""),          ('°', "degree", "", ""),
+    ('…', "ldots", "...", ""),      ('⋯', "cdots", "", ""),
+    ('⊥', "perp", "", ""),          ('∥', "parallel", "", ""),
+    ('ℏ', "hbar", "", ""),          ('ℓ', "ell", "", ""),
+];
+
+/// where a word has to start and end to be replaced by [`MathText`]
+# [derive(Clone,Copy,PartialEq,Eq,Debug,Default)]
+pub enum WordBoundary {
+    /// the word is a whole run of letters, digits and `_`, so `theta` is replaced but not `theta1` or `theta_0`
+    #[default]
+    Word,
+    /// the word is a whole run of letters, so `theta` is also replaced in `theta1` and `theta_0`, but not in `thetas`
+    Letters,
+    /// the word is replaced wherever it appears, the longest word first
+    Anywhere,
+}
+
+/// a converter between math prose and Unicode symbols
+///
+/// [`MathText::to_unicode`] replaces the names of `GreekAlphabet` (`theta`, `omega`),
+/// operator words (`inf`, `sum`), LaTeX commands (`\alpha`, `\Omega`) and ASCII operators (`<=`, `->`) by their symbols.
+/// [`MathText::to_ascii`] is the reverse, for systems which cannot take Unicode.
+/// Every kind of replacement can be switched off.
+///
+/// Capitalized names like `Omega` are only replaced with [`MathText::with_capitalized_names`],
+/// as `Pi`, `Tau` or `Chi` at the start of a sentence or as a name in prose are no letters.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::MathText;
+/// let text = MathText::new();
+/// assert_eq!("θ ≤ 2π, ∑ x_i → ∞", text.to_unicode("theta <= 2\\pi, sum x_i -> inf"));
+/// assert_eq!("theta <= 2 pi, sum x_i -> inf", text.to_ascii("θ ≤ 2π, ∑ x_i → ∞"));
+/// assert_eq!("My name is Pi. Tau and χ", text.to_unicode("My name is Pi. Tau and chi"));
+/// ```
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub struct MathText {
+    greek_names: bool,
+    operator_words: bool,
+    latex: bool,
+    ascii_operators: bool,
+    capitalized_names: bool,
+    boundary: WordBoundary,
+    ignored: Vec<String>,
+}
+impl MathText {
+    /// returns a new `MathText` with all replacements but of capitalized names, `WordBoundary::Word`, and the word `my` ignored
+    pub fn new() -> Self {
+        MathText {
+            greek_names: true,
+            operator_words: true,
+            latex: true,
+            ascii_operators: true,
+            capitalized_names: false,
+            boundary: WordBoundary::Word,
+            ignored: vec![String::from("my")],
+        }
+    }
+    /// returns the `MathText` with or without replacing the names of `GreekAlphabet`
+    pub fn with_greek_names(mut self, greek_names: bool) -> Self {
+        self.greek_names = greek_names;
+        self
+    }
+    /// returns the `MathText` with or without replacing operator words like `inf` and `sum`
+    pub fn with_operator_words(mut self, operator_words: bool) -> Self {
+        self.operator_words = operator_words;
+        self
+    }
+    /// returns the `MathText` with or without replacing LaTeX commands
+    pub fn with_latex(mut self, latex: bool) -> Self {
+        self.latex = latex;
+        self
+    }
+    /// returns the `MathText` with or without replacing ASCII operators like `<=` and `->`
+    pub fn with_ascii_operators(mut self, ascii_operators: bool) -> Self {
+        self.ascii_operators = ascii_operators;
+        self
+    }
+    /// returns the `MathText` with or without replacing capitalized names like `Omega` by uppercase letters
+    ///
+    /// Without them, [`MathText::to_ascii`] writes uppercase letters as LaTeX commands like `\Omega`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::MathText;
+    /// let text = MathText::new().with_capitalized_names(true);
+    /// assert_eq!("Δ Ω = Ω", text.to_unicode("Delta Omega = Omega"));
+    /// assert_eq!("Delta Omega", text.to_ascii("ΔΩ"));
+    /// ```
+    pub fn with_capitalized_names(mut self, capitalized_names: bool) -> Self {
+        self.capitalized_names = capitalized_names;
+        self
+    }
+    /// returns the `MathText` with the given `WordBoundary`
+    pub fn with_boundary(mut self, boundary: WordBoundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+    /// returns the `MathText` which never replaces the given words, in any case
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::MathText;
+    /// let text = MathText::new().ignoring(&["eta", "pi"]).with_capitalized_names(true);
+    /// assert_eq!("eta pi μ Pi Μ", text.to_unicode("eta pi mu Pi Mu"));
+    /// assert_eq!("My Ν", text.to_unicode("My Nu"));
+    /// ```
+    pub fn ignoring(mut self, words: &[&str]) -> Self {
+        self.ignored.extend(words.iter().map(|word| word.to_string()));
+        self
+    }
+
+    /// replaces names, operator words, LaTeX commands and ASCII operators by their symbols
+    ///
+    /// A LaTeX command may be closed by `{}`, unknown commands are kept as they are.
+    /// `<-` followed by a number reads as less than a negative number, so `x<-1` is kept and `x <- y` becomes `x ← y`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{MathText, WordBoundary};
+    /// let text = MathText::new();
+    /// assert_eq!("Δt, theta1, \\foo", text.to_unicode("\\Delta{}t, theta1, \\foo"));
+    /// let letters = MathText::new().with_boundary(WordBoundary::Letters);
+    /// assert_eq!("θ1, θ_0, thetas", letters.to_unicode("theta1, theta_0, thetas"));
+    /// let anywhere = MathText::new().with_boundary(WordBoundary::Anywhere);
+    /// assert_eq!("αβ", anywhere.to_unicode("alphabeta"));
+    /// assert_eq!("x<-1, x ← y", text.to_unicode("x<-1, x <- y"));
+    /// ```
+    pub fn to_unicode(&self, text: &str) -> String {
+        let mut unicode = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            if self.latex && c == '\\' {
+                let name = word(&rest[1..], |c| c.is_ascii_alphabetic());
+                if let Some(symbol) = latex_symbol(name) {
+                    unicode.push(symbol);
+                    rest = &rest[1 + name.len()..];
+                    if rest.starts_with("{}") {
+                        rest = &rest[2..];
+                    }
+                    continue
+                }
+                unicode.push_str(&rest[..1 + name.len()]);
+                rest = &rest[1 + name.len()..];
+                continue
+            }
+            if self.ascii_operators {
+                let operator = OPERATORS.iter().filter(|(_, _, ascii, _)| !ascii.is_empty() && rest.starts_with(ascii))
+                    .filter(|(_, _, ascii, _)| *ascii != "<-" || !rest[2..].starts_with(|c: char| c.is_ascii_digit() || c == '.'))
+                    .max_by_key(|(_, _, ascii, _)| ascii.len());
+                if let Some(&(symbol, _, ascii, _)) = operator {
+                    unicode.push(symbol);
+                    rest = &rest[ascii.len()..];
+                    continue
+                }
+            }
+            if !c.is_alphanumeric() {
+                unicode.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue
+            }
+            let run = match self.boundary {
+                WordBoundary::Word      =>  word(rest, |c| c.is_alphanumeric() || c == '_'),
+                WordBoundary::Letters   =>  word(rest, char::is_alphabetic),
+                WordBoundary::Anywhere  =>  {
+                    let longest = self.words().filter(|(name, _)| rest.starts_with(name.as_str()))
+                        .max_by_key(|(name, _)| name.len());
+                    if let Some((name, symbol)) = longest {
+                        unicode.push(symbol);
+                        rest = &rest[name.len()..];
+                    } else {
+                        unicode.push(c);
+                        rest = &rest[c.len_utf8()..];
+                    }
+                    continue
+                }
+            };
+            // a digit does not start a run of letters
+            let run = if run.is_empty() { &rest[..c.len_utf8()] } else { run };
+            match self.words().find(|(name, _)| name == run) {
+                Some((_, symbol))   =>  unicode.push(symbol),
+                None                =>  unicode.push_str(run)
+            }
+            rest = &rest[run.len()..];
+        }
+        unicode
+    }
+
+    /// replaces symbols by ASCII, the reverse of [`MathText::to_unicode`]
+    ///
+    /// Greek letters become their names, operators become ASCII operators or words where they are switched on,
+    /// otherwise LaTeX commands. Superscripts and subscripts become `^2` and `_{ij}`.
+    /// With `WordBoundary::Word` a word would swallow the `_` of a subscript, so a name before a subscript
+    /// is written as its LaTeX command instead, which `to_unicode` reads back as long as LaTeX is switched on.
+    /// Accents of Greek letters are dropped, other characters without an ASCII form are kept.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::MathText;
+    /// let text = MathText::new();
+    /// assert_eq!("x^2 + \\alpha_{12} != \\Omega", text.to_ascii("x² + α₁₂ ≠ Ω"));
+    /// assert_eq!("α_1, β^2", text.to_unicode(&text.to_ascii("α₁, β²")));
+    /// let latex = MathText::new().with_greek_names(false).with_ascii_operators(false);
+    /// assert_eq!("\\alpha{}x \\leq \\pi", latex.to_ascii("αx ≤ π"));
+    /// ```
+    pub fn to_ascii(&self, text: &str) -> String {
+        let chars : Vec<char> = text.chars().collect();
+        let mut ascii = String::with_capacity(text.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            i += 1;
+            if is_superscript(c) || is_subscript(c) {
+                let superscript = is_superscript(c);
+                let start = i - 1;
+                while i < chars.len() && (if superscript { is_superscript(chars[i]) } else { is_subscript(chars[i]) }) {
+                    i += 1;
+                }
+                let script : String = chars[start..i].iter().collect();
+                let script = if superscript { from_superscript(&script) } else { from_subscript(&script) }.unwrap_or_default();
+                ascii.push(if superscript { '^' } else { '_' });
+                if script.chars().count() == 1 {
+                    ascii.push_str(&script);
+                } else {
+                    ascii.push_str(&format!("{{{}}}", script));
+                }
+                continue
+            }
+            if let Some(name) = greek_word(c) {
+                if self.greek_names && !self.is_ignored(&name) && (self.capitalized_names || !c.is_uppercase()) {
+                    self.push_word(&mut ascii, &name, &name, next);
+                } else {
+                    push_command(&mut ascii, &name, next);
+                }
+                continue
+            }
+            let operators = || OPERATORS.iter().filter(|(symbol, _, _, _)| *symbol == c);
+            if let Some(&(_, _, operator, _)) = operators().find(|(_, _, ascii, _)| self.ascii_operators && !ascii.is_empty()) {
+                ascii.push_str(operator);
+            } else if let Some(&(_, command, _, word)) = operators().find(|(_, _, _, word)| self.operator_words && !word.is_empty()) {
+                self.push_word(&mut ascii, word, command, next);
+            } else if let Some(&(_, command, _, _)) = operators().find(|(_, command, _, _)| !command.is_empty()) {
+                push_command(&mut ascii, command, next);
+            } else {
+                ascii.push(c);
+            }
+        }
+        ascii
+    }
+
+    // every word which is replaced, as `(word, symbol)`
+    fn words(&self) -> impl Iterator<Item = (String, char)> + '_ {
+        let greek = (1..=24u8).map(GreekAlphabet::from).flat_map(|letter| {
+            vec![(letter_name(letter).to_string(), letter.uppercase()), (letter_name(letter).to_lowercase(), letter.lowercase())]
+        }).chain(["mu", "Mu", "nu", "Nu", "upsilon", "Upsilon"].iter().map(|name| (name.to_string(), latex_command(name).unwrap())))
+            .chain(VAR_GLYPHS.iter().map(|&(name, c)| (name.to_string(), c)))
+            .filter(move |(name, _)| self.greek_names && (self.capitalized_names || !name.starts_with(char::is_uppercase)));
+        let operators = OPERATORS.iter().filter(|(_, _, _, word)| !word.is_empty())
+            .map(|&(symbol, _, _, word)| (word.to_string(), symbol))
+            .filter(move |_| self.operator_words);
+        greek.chain(operators).filter(move |(name, _)| !self.is_ignored(name))
+    }
+    fn is_ignored(&self, word: &str) -> bool {
+        self.ignored.iter().any(|ignored| ignored.to_lowercase() == word.to_lowercase())
+    }
+    // a word is kept apart from the letters around it, so it is found again by `to_unicode`,
+    // before a subscript, which becomes `_`, the LaTeX command is written where the word would join it
+    fn push_word(&self, ascii: &mut String, word: &str, command: &str, next: Option<char>) {
+        if self.boundary == WordBoundary::Word && next.is_some_and(is_subscript) {
+            push_command(ascii, command, next);
+            return
+        }
+        let joins = |c: char| !is_superscript(c) && !is_subscript(c) && match self.boundary {
+            WordBoundary::Word  =>  c.is_alphanumeric() || c == '_',
+            _                   =>  c.is_alphabetic()
+        };
+        if ascii.chars().next_back().is_some_and(joins) {
+            ascii.push(' ');
+        }
+        ascii.push_str(word);
+        if next.is_some_and(joins) {
+            ascii.push(' ');
+        }
+    }
+}
+impl std::default::Default for MathText {
+    fn default() -> Self {
+        MathText::new()
+    }
+}
+
+fn word(text: &str, f: impl Fn(char) -> bool) -> &str {
+    let end = text.char_indices().find(|&(_, c)| !f(c)).map_or(text.len(), |(i, _)| i);
+    &text[..end]
+}
+
+fn latex_symbol(name: &str) -> Option<char> {
+    latex_command(name).or_else(|| OPERATORS.iter().find(|(_, command, _, _)| *command == name).map(|&(symbol, _, _, _)| symbol))
+}
+
+fn push_command(ascii: &mut String, command: &str, next: Option<char>) {
+    ascii.push('\\');
+    ascii.push_str(command);
+    if next.is_some_and(|c| c.is_ascii_alphabetic()) {
+        ascii.push_str("{}");
+    }
+}
+
+// the LaTeX spelling of a Greek letter, accents are dropped
+fn greek_word(c: char) -> Option<String> {
+    if let Some(&(name, _)) = VAR_GLYPHS.iter().find(|&&(_, var)| var == c) {
+        return Some(name.to_string())
+    }
+    let letter = from_accented(c);
+    let name = match letter {
+        GreekAlphabet::Empty    =>  return None,
+        GreekAlphabet::My       =>  String::from("mu"),
+        GreekAlphabet::Ny       =>  String::from("nu"),
+        GreekAlphabet::Ypsilon  =>  String::from("upsilon"),
+        _                       =>  letter_name(letter).to_lowercase()
+    };
+    if c.is_uppercase() {
+        let mut chars = name.chars();
+        let first = chars.next().unwrap().to_ascii_uppercase();
+        return Some(format!("{}{}", first, chars.as_str()))
+    }
+    Some(name)
+}
@@ -0,0 +1,448 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// the source of the next correlated measurement
+static NEXT_SOURCE : AtomicU64 = AtomicU64::new(0);
+
+/// an error of parsing a [`Measurement`]
+///
+/// The offsets are byte offsets into the text.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum MeasurementError {
+    /// the text is empty or only whitespace
+    Empty,
+    /// a character which does not fit the notation, as `(offset, char)`
+    Unexpected(usize, char),
+}
+
+impl std::fmt::Display for MeasurementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            MeasurementError::Empty                 =>  write!(f,"empty text"),
+            MeasurementError::Unexpected(offset, c) =>  write!(f,"unexpected {:?} at {}",c,offset)
+        }
+    }
+}
+impl std::error::Error for MeasurementError {}
+
+/// a measured value with its standard uncertainty
+///
+/// Arithmetic and the elementary functions propagate the uncertainty to first order.
+/// The operands of a [`Measurement::new`] are taken as independent, so `x - x` has the uncertainty `σ√2`.
+/// Correlations are tracked only for measurements created by [`Measurement::correlated`]:
+/// each is a source of uncertainty and results remember how much each source contributes,
+/// so `x - x` is exactly `0 ± 0` and `x * x` has twice the relative uncertainty of `x`.
+/// [`Measurement::independent`] forgets the correlations of a result.
+///
+/// `Display` writes `value ± σ`, the alternate flag `#` the concise notation `value(σ)`.
+/// The uncertainty is rounded to two significant digits when its first digit is 1 and to one otherwise,
+/// the value to the same decimal place.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Measurement;
+/// let g : Measurement = "9.81 ± 0.02".parse().unwrap();
+/// assert_eq!("9.81 ± 0.02", g.to_string());
+/// assert_eq!("9.81(2)", format!("{:#}", g));
+/// let t = Measurement::correlated(2.0, 0.01);
+/// let height = 0.5 * &g * &t * &t;
+/// assert_eq!("19.6 ± 0.2", height.to_string());
+/// assert_eq!(0.0, (&t - &t).uncertainty());
+/// assert_eq!("0.00 ± 0.03", (&g - &g).to_string());
+/// ```
+# [derive(Clone,Debug)]
+pub struct Measurement {
+    value: f64,
+    // the uncertainty from the operands without a source
+    untracked: f64,
+    // the contribution ∂value/∂source · σ of every source, sorted by source
+    contributions: Vec<(u64, f64)>,
+}
+
+impl Measurement {
+    /// returns a new `Measurement` with the given standard uncertainty, which is independent of every other operand
+    pub fn new(value: f64, uncertainty: f64) -> Self {
+        Measurement { value, untracked: uncertainty.abs(), contributions: Vec::new() }
+    }
+    /// returns a new `Measurement` with the given standard uncertainty as a source whose correlations are tracked
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Measurement;
+    /// let x = Measurement::correlated(3.0, 0.1);
+    /// assert_eq!("9.0 ± 0.6", (&x * &x).to_string());
+    /// let y = Measurement::new(3.0, 0.1);
+    /// assert_eq!("9.0 ± 0.4", (&y * &y).to_string());
+    /// ```
+    pub fn correlated(value: f64, uncertainty: f64) -> Self {
+        let contributions = if uncertainty == 0.0 {
+            Vec::new()
+        } else {
+            vec![(NEXT_SOURCE.fetch_add(1, Ordering::Relaxed), uncertainty.abs())]
+        };
+        Measurement { value, untracked: 0.0, contributions }
+    }
+    /// returns a `Measurement` without uncertainty
+    pub fn exact(value: f64) -> Self {
+        Measurement { value, untracked: 0.0, contributions: Vec::new() }
+    }
+    /// returns the value
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+    /// returns the standard uncertainty
+    pub fn uncertainty(&self) -> f64 {
+        self.contributions.iter().fold(self.untracked * self.untracked, |sum, (_, c)| sum + c * c).sqrt()
+    }
+    /// returns the standard uncertainty relative to the value
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty() / self.value.abs()
+    }
+    /// returns a new independent `Measurement` with the same value and uncertainty
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Measurement;
+    /// let x = Measurement::correlated(1.0, 0.3);
+    /// let y = x.independent();
+    /// assert_eq!(0.0, (&x - &x).uncertainty());
+    /// assert!(((&x - &y).uncertainty() - 0.3 * 2f64.sqrt()).abs() < 1e-15);
+    /// ```
+    pub fn independent(&self) -> Measurement {
+        Measurement::new(self.value, self.uncertainty())
+    }
+    /// returns the covariance of two measurements, from the correlated sources they share
+    pub fn covariance(&self, other: &Measurement) -> f64 {
+        self.contributions.iter()
+            .filter_map(|(source, c)| other.contributions.binary_search_by_key(source, |&(s, _)| s).ok().map(|j| c * other.contributions[j].1))
+            .fold(0.0, |sum, c| sum + c)
+    }
+    /// returns the correlation coefficient of two measurements, from -1 to 1
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Measurement;
+    /// let x = Measurement::correlated(2.0, 0.1);
+    /// let y = Measurement::correlated(3.0, 0.1);
+    /// let sum = &x + &y;
+    /// assert!((x.correlation(&sum) - 0.5f64.sqrt()).abs() < 1e-12);
+    /// assert_eq!(0.0, x.correlation(&y));
+    /// ```
+    pub fn correlation(&self, other: &Measurement) -> f64 {
+        self.covariance(other) / (self.uncertainty() * other.uncertainty())
+    }
+
+    /// returns the square root
+    pub fn sqrt(&self) -> Measurement {
+        let root = self.value.sqrt();
+        self.map(root, 0.5 / root)
+    }
+    /// returns the measurement raised to an integer power
+    pub fn powi(&self, n: i32) -> Measurement {
+        let a = self.value;
+        let derivative = if n == 0 { 0.0 } else { n as f64 * n.checked_sub(1).map_or_else(|| a.powi(n) / a, |m| a.powi(m)) };
+        self.map(a.powi(n), derivative)
+    }
+    /// returns the measurement raised to a power
+    pub fn powf(&self, n: f64) -> Measurement {
+        self.map(self.value.powf(n), n * self.value.powf(n - 1.0))
+    }
+    /// returns `e` raised to the measurement
+    pub fn exp(&self) -> Measurement {
+        let exp = self.value.exp();
+        self.map(exp, exp)
+    }
+    /// returns the natural logarithm
+    pub fn ln(&self) -> Measurement {
+        self.map(self.value.ln(), self.value.recip())
+    }
+    /// returns the logarithm to base 10
+    pub fn log10(&self) -> Measurement {
+        self.map(self.value.log10(), (self.value * std::f64::consts::LN_10).recip())
+    }
+    /// returns the sine of the measurement in radians
+    pub fn sin(&self) -> Measurement {
+        self.map(self.value.sin(), self.value.cos())
+    }
+    /// returns the cosine of the measurement in radians
+    pub fn cos(&self) -> Measurement {
+        self.map(self.value.cos(), -self.value.sin())
+    }
+    /// returns the tangent of the measurement in radians
+    pub fn tan(&self) -> Measurement {
+        self.map(self.value.tan(), self.value.cos().powi(-2))
+    }
+    /// returns the arctangent in radians
+    pub fn atan(&self) -> Measurement {
+        self.map(self.value.atan(), (1.0 + self.value * self.value).recip())
+    }
+    /// returns the absolute value
+    pub fn abs(&self) -> Measurement {
+        self.map(self.value.abs(), self.value.signum())
+    }
+
+    // the result of a function of this measurement with the given derivative
+    fn map(&self, value: f64, derivative: f64) -> Measurement {
+        let contributions = self.contributions.iter().map(|&(source, c)| (source, derivative * c)).collect();
+        Measurement { value, untracked: scaled(self.untracked, derivative), contributions }
+    }
+    // the result of a function of two measurements with the given partial derivatives
+    fn combine(&self, other: &Measurement, value: f64, derivative: f64, other_derivative: f64) -> Measurement {
+        let untracked = scaled(self.untracked, derivative).hypot(scaled(other.untracked, other_derivative));
+        Measurement { value, untracked, contributions: merge(&self.contributions, &other.contributions, derivative, other_derivative) }
+    }
+    // the decimal places to round to, negative for tens, hundreds, ...
+    fn decimals(&self) -> Option<i32> {
+        let uncertainty = self.uncertainty();
+        if uncertainty == 0.0 || !uncertainty.is_finite() {
+            return None
+        }
+        let exponent = uncertainty.log10().floor() as i32;
+        let leading = uncertainty / 10f64.powi(exponent);
+        let decimals = -exponent + if leading < 2.0 { 1 } else { 0 };
+        // rounding may give a leading 2, like 0.0196 to 0.020
+        let rounded = (uncertainty * 10f64.powi(decimals)).round();
+        Some(if leading < 2.0 && rounded >= 20.0 { decimals - 1 } else { decimals })
+    }
+}
+
+// an untracked uncertainty times a derivative, which stays exact for an infinite derivative
+fn scaled(uncertainty: f64, derivative: f64) -> f64 {
+    if uncertainty == 0.0 { 0.0 } else { (derivative * uncertainty).abs() }
+}
+
+// the sum of two lists of contributions with factors
+fn merge(left: &[(u64, f64)], right: &[(u64, f64)], left_factor: f64, right_factor: f64) -> Vec<(u64, f64)> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() || j < right.len() {
+        let (source, c) = match (left.get(i), right.get(j)) {
+            (Some(&(a, ca)), Some(&(b, cb))) if a == b  =>  {
+                i += 1;
+                j += 1;
+                (a, left_factor * ca + right_factor * cb)
+            }
+            (Some(&(a, ca)), Some(&(b, _))) if a < b    =>  {
+                i += 1;
+                (a, left_factor * ca)
+            }
+            (Some(&(a, ca)), None)                      =>  {
+                i += 1;
+                (a, left_factor * ca)
+            }
+            (_, Some(&(b, cb)))                         =>  {
+                j += 1;
+                (b, right_factor * cb)
+            }
+            (None, None)                                =>  unreachable!()
+        };
+        if c != 0.0 {
+            merged.push((source, c));
+        }
+    }
+    merged
+}
+
+// a number rounded to the decimal places, which are negative for tens, hundreds, ...
+fn round_to(value: f64, decimals: i32) -> String {
+    if decimals >= 0 {
+        format!("{:.*}", decimals as usize, value)
+    } else {
+        let scale = 10f64.powi(-decimals);
+        format!("{:.0}", (value / scale).round() * scale)
+    }
+}
+
+impl std::fmt::Display for Measurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let decimals = match self.decimals() {
+            Some(decimals)  =>  decimals,
+            None            =>  return if f.alternate() { write!(f,"{}",self.value) } else { write!(f,"{} ± 0",self.value) }
+        };
+        let value = round_to(self.value, decimals);
+        if f.alternate() {
+            let digits = (self.uncertainty() * 10f64.powi(decimals)).round();
+            let digits = if decimals >= 0 { digits } else { digits * 10f64.powi(-decimals) };
+            write!(f,"{}({})",value,digits)
+        } else {
+            write!(f,"{} ± {}",value,round_to(self.uncertainty(), decimals))
+        }
+    }
+}
+
+impl std::str::FromStr for Measurement {
+    type Err = MeasurementError;
+    /// parses `value ± σ` (also with `+-` or `+/-`), the concise `value(σ)` or a plain value without uncertainty
+    ///
+    /// In the concise notation, σ counts units of the last digit of the value, unless it has a decimal point.
+    /// An exponent after the parentheses applies to both, like `6.674(15)e-11`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Measurement, MeasurementError};
+    /// let g : Measurement = "6.674(15)e-11".parse().unwrap();
+    /// assert_eq!(6.674e-11, g.value());
+    /// assert!((g.uncertainty() - 0.015e-11).abs() < 1e-25);
+    /// let x : Measurement = "-1.25 +/- 0.5".parse().unwrap();
+    /// assert_eq!((-1.25, 0.5), (x.value(), x.uncertainty()));
+    /// assert_eq!(Err(MeasurementError::Unexpected(5, 'x')), "9.81(x)".parse::<Measurement>().map(|m| m.value()));
+    /// ```
+    fn from_str(text: &str) -> Result<Self, MeasurementError> {
+        let start = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(MeasurementError::Empty)
+        }
+        let unexpected = |rest: &str| {
+            let offset = start + trimmed.len() - rest.len();
+            MeasurementError::Unexpected(offset, rest.chars().next().unwrap_or(' '))
+        };
+        let (value_text, rest) = split_number(trimmed, false);
+        let value : f64 = value_text.replace('−', "-").parse().map_err(|_| unexpected(trimmed))?;
+        let rest_trimmed = rest.trim_start();
+        if rest_trimmed.is_empty() {
+            return Ok(Measurement::exact(value))
+        }
+        if let Some(concise) = rest.strip_prefix('(') {
+            let (digits, after) = split_number(concise, false);
+            let after = after.strip_prefix(')').ok_or_else(|| unexpected(after))?;
+            let (exponent_text, after) = split_number(after, true);
+            if !after.is_empty() {
+                return Err(unexpected(after))
+            }
+            let mut uncertainty : f64 = digits.parse().map_err(|_| unexpected(concise))?;
+            if !digits.contains('.') {
+                let decimals = value_text.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+                uncertainty /= 10f64.powi(decimals as i32);
+            }
+            let scale = if exponent_text.is_empty() { 1.0 } else { format!("1{}", exponent_text).parse().unwrap_or(1.0) };
+            let value : f64 = format!("{}{}", value_text.replace('−', "-"), exponent_text).parse().map_err(|_| unexpected(trimmed))?;
+            return Ok(Measurement::new(value, uncertainty * scale))
+        }
+        let uncertainty_text = ["±", "+/-", "+-"].iter().find_map(|sign| rest_trimmed.strip_prefix(sign))
+            .ok_or_else(|| unexpected(rest_trimmed))?.trim_start();
+        let uncertainty : f64 = uncertainty_text.parse().map_err(|_| unexpected(uncertainty_text))?;
+        Ok(Measurement::new(value, uncertainty))
+    }
+}
+
+// splits off a decimal number with an exponent, or only an exponent like `e-11`
+fn split_number(text: &str, exponent_only: bool) -> (&str, &str) {
+    let mut length = 0;
+    let mut chars = text.char_indices().peekable();
+    if !exponent_only {
+        while let Some(&(i, c)) = chars.peek() {
+            let sign = (c == '-' || c == '+' || c == '−') && i == 0;
+            if !(c.is_ascii_digit() || c == '.' || sign) {
+                break
+            }
+            length = i + c.len_utf8();
+            chars.next();
+        }
+    }
+    let rest = &text[length..];
+    if let Some(exponent) = rest.strip_prefix(['e', 'E']) {
+        let unsigned = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+        let digits = unsigned.find(|c: char| !c.is_ascii_digit()).unwrap_or(unsigned.len());
+        if digits > 0 {
+            length += 1 + exponent.len() - unsigned.len() + digits;
+        }
+    }
+    text.split_at(length)
+}
+
+// the operators for owned and borrowed measurements and for `f64`
+macro_rules! impl_measurement_ops {
+    ($($lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl std::ops::Add<$rhs> for $lhs {
+                type Output = Measurement;
+                fn add(self, other: $rhs) -> Measurement {
+                    let (a, b) = (self.to_measurement(), other.to_measurement());
+                    a.combine(&b, a.value + b.value, 1.0, 1.0)
+                }
+            }
+            impl std::ops::Sub<$rhs> for $lhs {
+                type Output = Measurement;
+                fn sub(self, other: $rhs) -> Measurement {
+                    let (a, b) = (self.to_measurement(), other.to_measurement());
+                    a.combine(&b, a.value - b.value, 1.0, -1.0)
+                }
+            }
+            impl std::ops::Mul<$rhs> for $lhs {
+                type Output = Measurement;
+                fn mul(self, other: $rhs) -> Measurement {
+                    let (a, b) = (self.to_measurement(), other.to_measurement());
+                    a.combine(&b, a.value * b.value, b.value, a.value)
+                }
+            }
+            impl std::ops::Div<$rhs> for $lhs {
+                type Output = Measurement;
+                fn div(self, other: $rhs) -> Measurement {
+                    let (a, b) = (self.to_measurement(), other.to_measurement());
+                    a.combine(&b, a.value / b.value, b.value.recip(), -a.value / (b.value * b.value))
+                }
+            }
+        )*
+    };
+}
+impl_measurement_ops! {
+    Measurement, Measurement;
+    Measurement, &Measurement;
+    &Measurement, Measurement;
+    &Measurement, &Measurement;
+    Measurement, f64;
+    &Measurement, f64;
+    f64, Measurement;
+    f64, &Measurement;
+}
+
+// the operands of the operators as measurements
+trait ToMeasurement {
+    fn to_measurement(&self) -> std::borrow::Cow<'_, Measurement>;
+}
+impl ToMeasurement for Measurement {
+    fn to_measurement(&self) -> std::borrow::Cow<'_, Measurement> {
+        std::borrow::Cow::Borrowed(self)
+    }
+}
+impl ToMeasurement for &Measurement {
+    fn to_measurement(&self) -> std::borrow::Cow<'_, Measurement> {
+        std::borrow::Cow::Borrowed(*self)
+    }
+}
+impl ToMeasurement for f64 {
+    fn to_measurement(&self) -> std::borrow::Cow<'_, Measurement> {
+        std::borrow::Cow::Owned(Measurement::exact(*self))
+    }
+}
+
+impl std::ops::Neg for Measurement {
+    type Output = Measurement;
+    fn neg(self) -> Measurement {
+        self.map(-self.value, -1.0)
+    }
+}
+impl std::ops::Neg for &Measurement {
+    type Output = Measurement;
+    fn neg(self) -> Measurement {
+        self.map(-self.value, -1.0)
+    }
+}
+impl std::cmp::PartialEq for Measurement {
+    /// compares the values and the uncertainties, but not the sources of the uncertainties
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Measurement;
+    /// assert_eq!(Measurement::new(1.0, 0.1), Measurement::correlated(1.0, 0.1));
+    /// assert_eq!(Measurement::correlated(1.0, 0.1), Measurement::correlated(1.0, 0.1));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.uncertainty() == other.uncertainty()
+    }
+}
+impl std::convert::From<f64> for Measurement {
+    fn from(value: f64) -> Self {
+        Measurement::exact(value)
+    }
+}
@@ -0,0 +1,457 @@
+/// how many digits a [`NumberFormat`] keeps
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum Precision {
+    /// the number of significant figures, at least 1
+    Significant(u32),
+    /// the number of decimal places, of the mantissa in scientific and engineering notation
+    Decimals(u32),
+}
+
+/// how a [`NumberFormat`] rounds the digits it drops
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub enum RoundingMode {
+    /// to the nearest, ties to the even digit, like `2.5` to `2` and `3.5` to `4`
+    #[default]
+    HalfEven,
+    /// to the nearest, ties away from zero, like `2.5` to `3` and `-2.5` to `-3`
+    HalfUp,
+    /// toward zero, cutting the digits off
+    TowardZero,
+    /// toward positive infinity
+    Ceiling,
+    /// toward negative infinity
+    Floor,
+}
+
+/// where a [`NumberFormat`] puts the decimal point
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub enum Notation {
+    /// without exponent, like `12345.6`
+    #[default]
+    Fixed,
+    /// with one digit before the decimal point, like `1.23456e4`
+    Scientific,
+    /// with an exponent which is a multiple of 3, like `12.3456e3`
+    Engineering,
+}
+
+/// the separators of a language
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub enum Locale {
+    /// `1,234.5`
+    #[default]
+    English,
+    /// `1.234,5`
+    German,
+    /// `1 234,5` with a narrow no-break space
+    French,
+    /// `1'234.5`
+    Swiss,
+}
+impl Locale {
+    /// returns the decimal separator
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Locale::English |
+            Locale::Swiss       =>  '.',
+            Locale::German |
+            Locale::French      =>  ','
+        }
+    }
+    /// returns the separator of digit groups
+    pub fn group_separator(&self) -> char {
+        match self {
+            Locale::English     =>  ',',
+            Locale::German      =>  '.',
+            Locale::French      =>  '\u{202F}',
+            Locale::Swiss       =>  '\''
+        }
+    }
+}
+
+/// an error of [`NumberFormat::parse`]
+///
+/// The offsets are byte offsets into the text.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum NumberFormatError {
+    /// the text is empty or only whitespace
+    Empty,
+    /// a character which does not fit the format, as `(offset, char)`
+    Unexpected(usize, char),
+    /// the text ends before a digit, as `offset`
+    UnexpectedEnd(usize),
+}
+
+impl std::fmt::Display for NumberFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            NumberFormatError::Empty                    =>  write!(f,"empty text"),
+            NumberFormatError::Unexpected(offset, c)    =>  write!(f,"unexpected {:?} at {}",c,offset),
+            NumberFormatError::UnexpectedEnd(offset)    =>  write!(f,"expected a digit at {}",offset)
+        }
+    }
+}
+impl std::error::Error for NumberFormatError {}
+
+/// a formatter and parser of numbers with a fixed number of digits
+///
+/// The digits are rounded from the shortest decimal which reads back as the same `f64`,
+/// so `2.675`, which is stored as `2.67499999…`, is `2.68` with two decimals and `RoundingMode::HalfEven`.
+/// [`NumberFormat::parse`] reads what [`NumberFormat::format`] writes,
+/// formatting the parsed value again gives the same text in every `RoundingMode`.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{Locale, Notation, NumberFormat, Precision, RoundingMode};
+/// let german = NumberFormat::new().with_precision(Precision::Significant(5)).with_locale(Locale::German).with_grouping(true);
+/// assert_eq!("1.234,5", german.format(1234.46));
+/// assert_eq!(Ok(1234.5), german.parse("1.234,5"));
+/// let engineering = NumberFormat::new().with_precision(Precision::Significant(3)).with_notation(Notation::Engineering);
+/// assert_eq!("47.0e-9", engineering.format(4.7e-8));
+/// let money = NumberFormat::new().with_precision(Precision::Decimals(2)).with_rounding(RoundingMode::Ceiling);
+/// assert_eq!("0.02", money.format(0.011));
+/// ```
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct NumberFormat {
+    precision: Precision,
+    rounding: RoundingMode,
+    notation: Notation,
+    decimal_separator: char,
+    group_separator: char,
+    grouping: bool,
+}
+
+impl NumberFormat {
+    /// returns a new `NumberFormat` with 6 significant figures, `RoundingMode::HalfEven`, `Notation::Fixed`,
+    /// the separators of `Locale::English` and without grouping
+    pub fn new() -> Self {
+        NumberFormat {
+            precision: Precision::Significant(6),
+            rounding: RoundingMode::HalfEven,
+            notation: Notation::Fixed,
+            decimal_separator: '.',
+            group_separator: ',',
+            grouping: false,
+        }
+    }
+    /// returns the `NumberFormat` with the given `Precision`
+    ///
+    /// # Panics
+    /// Panics with `Precision::Significant(0)`.
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        assert!(precision != Precision::Significant(0), "at least one significant figure");
+        self.precision = precision;
+        self
+    }
+    /// returns the `NumberFormat` with the given `RoundingMode`
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+    /// returns the `NumberFormat` with the given `Notation`
+    pub fn with_notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+    /// returns the `NumberFormat` with the separators of the `Locale`
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.decimal_separator = locale.decimal_separator();
+        self.group_separator = locale.group_separator();
+        self
+    }
+    /// returns the `NumberFormat` with the given decimal and group separators
+    ///
+    /// # Panics
+    /// Panics if the separators are equal, or one of them is a digit, a sign or `e`.
+    pub fn with_separators(mut self, decimal_separator: char, group_separator: char) -> Self {
+        for separator in [decimal_separator, group_separator] {
+            assert!(!separator.is_ascii_digit() && !"+-−eE".contains(separator), "invalid separator {:?}", separator);
+        }
+        assert!(decimal_separator != group_separator, "equal separators {:?}", decimal_separator);
+        self.decimal_separator = decimal_separator;
+        self.group_separator = group_separator;
+        self
+    }
+    /// returns the `NumberFormat` with or without grouping the integer digits by three
+    pub fn with_grouping(mut self, grouping: bool) -> Self {
+        self.grouping = grouping;
+        self
+    }
+    /// returns the `Precision`
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+    /// returns the `RoundingMode`
+    pub fn rounding(&self) -> RoundingMode {
+        self.rounding
+    }
+    /// returns the `Notation`
+    pub fn notation(&self) -> Notation {
+        self.notation
+    }
+
+    /// returns the value as text
+    ///
+    /// `NaN` and the infinities are written as `NaN`, `inf` and `-inf`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Notation, NumberFormat, Precision, RoundingMode};
+    /// let format = NumberFormat::new().with_precision(Precision::Significant(2));
+    /// assert_eq!("0.0012", format.format(0.001_249));
+    /// assert_eq!("120000", format.format(123_456.0));
+    /// assert_eq!("1.2e5", format.with_notation(Notation::Scientific).format(123_456.0));
+    /// assert_eq!("-2.0", format.format(-2.0));
+    /// assert_eq!("10", format.format(9.96));
+    /// let halves = NumberFormat::new().with_precision(Precision::Decimals(0));
+    /// assert_eq!("2 4 -2", format!("{} {} {}", halves.format(2.5), halves.format(3.5), halves.format(-2.5)));
+    /// let halves = halves.with_rounding(RoundingMode::HalfUp);
+    /// assert_eq!("3 4 -3", format!("{} {} {}", halves.format(2.5), halves.format(3.5), halves.format(-2.5)));
+    /// ```
+    pub fn format(&self, value: f64) -> String {
+        if value.is_nan() {
+            return String::from("NaN")
+        }
+        if value.is_infinite() {
+            return String::from(if value < 0.0 { "-inf" } else { "inf" })
+        }
+        let (digits, exponent, decimals, scale) = self.rounded(value);
+        let mut text = String::new();
+        if value.is_sign_negative() {
+            text.push('-');
+        }
+        let integer : String = (0..=(exponent - scale).max(0)).rev().map(|power| digit(&digits, exponent, power + scale)).collect();
+        if self.grouping {
+            for (i, c) in integer.chars().enumerate() {
+                if i > 0 && (integer.len() - i).is_multiple_of(3) {
+                    text.push(self.group_separator);
+                }
+                text.push(c);
+            }
+        } else {
+            text.push_str(&integer);
+        }
+        if decimals > 0 {
+            text.push(self.decimal_separator);
+            text.extend((1..=decimals).map(|k| digit(&digits, exponent, scale - k)));
+        }
+        if self.notation != Notation::Fixed {
+            text.push_str(&format!("e{}", scale));
+        }
+        text
+    }
+    /// returns the value rounded like [`NumberFormat::format`] does
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{NumberFormat, Precision};
+    /// let format = NumberFormat::new().with_precision(Precision::Significant(3));
+    /// assert_eq!(300_000_000.0, format.round(299_792_458.0));
+    /// assert_eq!(1.23e-300, format.round(1.234_5e-300));
+    /// assert_eq!(-0.000_123, format.round(-0.000_123_45));
+    /// ```
+    pub fn round(&self, value: f64) -> f64 {
+        if !value.is_finite() {
+            return value
+        }
+        let (digits, exponent, _, _) = self.rounded(value);
+        let digits : String = if digits.is_empty() { String::from("0") } else { digits.iter().map(|&d| char::from(b'0' + d)).collect() };
+        let rounded : f64 = format!("0.{}e{}", digits, exponent + 1).parse().unwrap_or(0.0);
+        if value.is_sign_negative() { -rounded } else { rounded }
+    }
+    /// returns the value of a text written in this format
+    ///
+    /// The group separators are optional, but where they are used, every group after the first has three digits.
+    /// The notation and the number of digits may differ from the format.
+    ///
+    /// # Errors
+    /// Returns a `NumberFormatError` if the text is not a number with the separators of the format.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Locale, NumberFormat, NumberFormatError};
+    /// let french = NumberFormat::new().with_locale(Locale::French);
+    /// assert_eq!(Ok(-1234567.5), french.parse("−1\u{202F}234\u{202F}567,5"));
+    /// assert_eq!(Ok(2.5e-3), french.parse("2,5e-3"));
+    /// assert_eq!(Err(NumberFormatError::Unexpected(1, '.')), french.parse("1.5"));
+    /// let english = NumberFormat::new().with_grouping(true);
+    /// assert_eq!(Err(NumberFormatError::Unexpected(1, ',')), english.parse("1,5"));
+    /// for value in [0.1, 123.456, -9.999_95e-12, 6.022_140_76e23] {
+    ///     let text = english.format(value);
+    ///     assert_eq!(text, english.format(english.parse(&text).unwrap()));
+    /// }
+    /// ```
+    pub fn parse(&self, text: &str) -> Result<f64, NumberFormatError> {
+        let start = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(NumberFormatError::Empty)
+        }
+        let unsigned = trimmed.strip_prefix(['+', '-', '−']).unwrap_or(trimmed);
+        let negative = unsigned.len() != trimmed.len() && !trimmed.starts_with('+');
+        match unsigned {
+            "NaN"   =>  return Ok(f64::NAN),
+            "inf"   =>  return Ok(if negative { f64::NEG_INFINITY } else { f64::INFINITY }),
+            _       =>  ()
+        }
+        let mut number = String::from(if negative { "-" } else { "" });
+        // the digits of the number and of the current group, and the offset of the last group separator
+        let (mut digits, mut group, mut separator) = (0, 0, None);
+        let mut chars = unsigned.char_indices().peekable();
+        let offset = |i: usize| start + trimmed.len() - unsigned.len() + i;
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                digits += 1;
+                group += 1;
+            } else if c == self.group_separator && group > 0 && (separator.is_none() && group <= 3 || group == 3) {
+                separator = Some(i);
+                group = 0;
+            } else {
+                break
+            }
+            chars.next();
+        }
+        if let Some(i) = separator.filter(|_| group != 3) {
+            return Err(NumberFormatError::Unexpected(offset(i), self.group_separator))
+        }
+        if chars.peek().is_some_and(|&(_, c)| c == self.decimal_separator) {
+            chars.next();
+            number.push('.');
+            while let Some(&(_, c)) = chars.peek().filter(|(_, c)| c.is_ascii_digit()) {
+                number.push(c);
+                digits += 1;
+                chars.next();
+            }
+        }
+        if digits == 0 {
+            return Err(match unsigned.chars().next() {
+                Some(c) =>  NumberFormatError::Unexpected(offset(0), c),
+                None    =>  NumberFormatError::UnexpectedEnd(offset(0))
+            })
+        }
+        if chars.peek().is_some_and(|&(_, c)| c == 'e' || c == 'E') {
+            chars.next();
+            number.push('e');
+            if let Some(&(_, c)) = chars.peek().filter(|(_, c)| "+-−".contains(*c)) {
+                number.push(if c == '+' { '+' } else { '-' });
+                chars.next();
+            }
+            let mut exponent_digits = 0;
+            while let Some(&(_, c)) = chars.peek().filter(|(_, c)| c.is_ascii_digit()) {
+                number.push(c);
+                exponent_digits += 1;
+                chars.next();
+            }
+            if exponent_digits == 0 {
+                return Err(match chars.peek() {
+                    Some(&(i, c))   =>  NumberFormatError::Unexpected(offset(i), c),
+                    None            =>  NumberFormatError::UnexpectedEnd(offset(unsigned.len()))
+                })
+            }
+        }
+        if let Some(&(i, c)) = chars.peek() {
+            return Err(NumberFormatError::Unexpected(offset(i), c))
+        }
+        Ok(number.parse().unwrap_or(0.0))
+    }
+
+    // the rounded digits without trailing zeros, the exponent of the first digit,
+    // the decimals to show and the exponent written after the mantissa
+    fn rounded(&self, value: f64) -> (Vec<u8>, i32, i32, i32) {
+        let (shortest, shortest_exponent) = shortest_digits(value.abs());
+        let negative = value.is_sign_negative();
+        let keep = |exponent: i32| match (self.precision, self.notation) {
+            (Precision::Significant(n), _)                      =>  n as i32,
+            (Precision::Decimals(n), Notation::Fixed)           =>  exponent + 1 + n as i32,
+            (Precision::Decimals(n), Notation::Scientific)      =>  1 + n as i32,
+            (Precision::Decimals(n), Notation::Engineering)     =>  exponent.rem_euclid(3) + 1 + n as i32
+        };
+        let (mut digits, mut exponent) = round_digits(&shortest, shortest_exponent, keep(shortest_exponent), self.rounding, negative);
+        if self.notation == Notation::Engineering && exponent != shortest_exponent {
+            // rounding up to the next power of ten may leave fewer decimals
+            let again = round_digits(&digits, exponent, keep(exponent), self.rounding, negative);
+            digits = again.0;
+            exponent = again.1;
+        }
+        let scale = match self.notation {
+            Notation::Fixed         =>  0,
+            Notation::Scientific    =>  exponent,
+            Notation::Engineering   =>  exponent.div_euclid(3) * 3
+        };
+        let decimals = match self.precision {
+            Precision::Significant(n)   =>  (n as i32 - 1 - exponent + scale).max(0),
+            Precision::Decimals(n)      =>  n as i32
+        };
+        (digits, exponent, decimals, scale)
+    }
+}
+
+impl std::default::Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat::new()
+    }
+}
+
+// the digit of `d₀.d₁d₂… × 10^exponent` at the given power of ten
+fn digit(digits: &[u8], exponent: i32, power: i32) -> char {
+    let index = exponent - power;
+    if index < 0 || index >= digits.len() as i32 {
+        return '0'
+    }
+    char::from(b'0' + digits[index as usize])
+}
+
+// the shortest decimal digits of a finite non-negative value, and the exponent of the first digit
+fn shortest_digits(value: f64) -> (Vec<u8>, i32) {
+    if value == 0.0 {
+        return (Vec::new(), 0)
+    }
+    let text = format!("{:e}", value);
+    let (mantissa, exponent) = text.split_once('e').unwrap_or((&text, "0"));
+    let mut digits : Vec<u8> = mantissa.bytes().filter(u8::is_ascii_digit).map(|b| b - b'0').collect();
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    (digits, exponent.parse().unwrap_or(0))
+}
+
+// the digits rounded to `keep` significant digits, which may be zero or negative, and the new exponent
+fn round_digits(digits: &[u8], exponent: i32, keep: i32, mode: RoundingMode, negative: bool) -> (Vec<u8>, i32) {
+    if digits.is_empty() || keep >= digits.len() as i32 {
+        return (digits.to_vec(), exponent)
+    }
+    let kept = keep.max(0) as usize;
+    let first = if keep >= 0 { digits[kept] } else { 0 };
+    let tail = digits[(keep + 1).max(0) as usize..].iter().any(|&d| d != 0);
+    let odd = digits[..kept].last().is_some_and(|d| d % 2 == 1);
+    let up = match mode {
+        RoundingMode::HalfEven      =>  first > 5 || first == 5 && (tail || odd),
+        RoundingMode::HalfUp        =>  first >= 5,
+        RoundingMode::TowardZero    =>  false,
+        RoundingMode::Ceiling       =>  !negative,
+        RoundingMode::Floor         =>  negative
+    };
+    let mut rounded = digits[..kept].to_vec();
+    let mut exponent = exponent;
+    if up {
+        match rounded.iter().rposition(|&d| d != 9) {
+            Some(i) =>  {
+                rounded[i] += 1;
+                rounded.truncate(i + 1);
+            }
+            None    =>  {
+                // all nines, or nothing kept, become a one in front
+                exponent += 1 - keep.min(0);
+                rounded = vec![1];
+            }
+        }
+    }
+    while rounded.last() == Some(&0) {
+        rounded.pop();
+    }
+    if rounded.is_empty() {
+        exponent = 0;
+    }
+    (rounded, exponent)
+}
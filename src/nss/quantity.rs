@@ -0,0 +1,460 @@
+use super::script::to_superscript;
+
+/// the symbols of the seven SI base units, in the order of the exponents of a [`Dimension`]
+const BASE_SYMBOLS : [&str;7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+/// the dimension of a quantity, as the exponents of the seven SI base units
+///
+/// The exponents are in the order metre, kilogram, second, ampere, kelvin, mole, candela.
+/// `*`, `/`, `powi` and `recip` panic if an exponent leaves the range of `i8`,
+/// the `checked_*` methods return `None` instead.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Dimension;
+/// let speed = Dimension::LENGTH / Dimension::TIME;
+/// assert_eq!([1, 0, -1, 0, 0, 0, 0], speed.exponents());
+/// assert_eq!("m·s⁻¹", speed.to_string());
+/// assert_eq!(Dimension::LENGTH, speed * Dimension::TIME);
+/// ```
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub struct Dimension([i8;7]);
+
+impl Dimension {
+    /// the dimension of a pure number
+    pub const DIMENSIONLESS : Dimension = Dimension([0, 0, 0, 0, 0, 0, 0]);
+    /// the dimension of the metre
+    pub const LENGTH : Dimension = Dimension([1, 0, 0, 0, 0, 0, 0]);
+    /// the dimension of the kilogram
+    pub const MASS : Dimension = Dimension([0, 1, 0, 0, 0, 0, 0]);
+    /// the dimension of the second
+    pub const TIME : Dimension = Dimension([0, 0, 1, 0, 0, 0, 0]);
+    /// the dimension of the ampere
+    pub const CURRENT : Dimension = Dimension([0, 0, 0, 1, 0, 0, 0]);
+    /// the dimension of the kelvin
+    pub const TEMPERATURE : Dimension = Dimension([0, 0, 0, 0, 1, 0, 0]);
+    /// the dimension of the mole
+    pub const AMOUNT : Dimension = Dimension([0, 0, 0, 0, 0, 1, 0]);
+    /// the dimension of the candela
+    pub const LUMINOUS_INTENSITY : Dimension = Dimension([0, 0, 0, 0, 0, 0, 1]);
+
+    /// returns the `Dimension` with the given exponents of metre, kilogram, second, ampere, kelvin, mole and candela
+    pub const fn new(exponents: [i8;7]) -> Self {
+        Dimension(exponents)
+    }
+    /// returns the exponents of metre, kilogram, second, ampere, kelvin, mole and candela
+    pub const fn exponents(&self) -> [i8;7] {
+        self.0
+    }
+    /// returns `true` if all exponents are zero
+    pub fn is_dimensionless(&self) -> bool {
+        *self == Dimension::DIMENSIONLESS
+    }
+    /// returns the dimension raised to the power `n`
+    ///
+    /// # Panics
+    /// if an exponent overflows, see [`Dimension::checked_powi`]
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Dimension;
+    /// assert_eq!(Dimension::new([3, 0, 0, 0, 0, 0, 0]), Dimension::LENGTH.powi(3));
+    /// ```
+    pub fn powi(&self, n: i8) -> Self {
+        self.checked_powi(n).expect("dimension exponent overflow")
+    }
+    /// returns the dimension raised to the power `n`, or `None` if an exponent overflows
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Dimension;
+    /// assert_eq!(Some(Dimension::new([100, 0, 0, 0, 0, 0, 0])), Dimension::LENGTH.checked_powi(100));
+    /// assert_eq!(None, Dimension::LENGTH.powi(100).checked_powi(2));
+    /// ```
+    pub fn checked_powi(&self, n: i8) -> Option<Self> {
+        let mut exponents = self.0;
+        for exponent in exponents.iter_mut() {
+            *exponent = exponent.checked_mul(n)?;
+        }
+        Some(Dimension(exponents))
+    }
+    /// returns the reciprocal dimension
+    ///
+    /// # Panics
+    /// if an exponent is `-128`
+    pub fn recip(&self) -> Self {
+        self.powi(-1)
+    }
+    /// returns the reciprocal dimension, or `None` if an exponent is `-128`
+    pub fn checked_recip(&self) -> Option<Self> {
+        self.checked_powi(-1)
+    }
+    /// returns the product of the dimensions, or `None` if an exponent overflows
+    pub fn checked_mul(&self, other: Dimension) -> Option<Self> {
+        self.combine(other, i8::checked_add)
+    }
+    /// returns the quotient of the dimensions, or `None` if an exponent overflows
+    pub fn checked_div(&self, other: Dimension) -> Option<Self> {
+        self.combine(other, i8::checked_sub)
+    }
+    // combines the exponents of both dimensions one by one
+    fn combine(&self, other: Dimension, operation: fn(i8, i8) -> Option<i8>) -> Option<Self> {
+        let mut exponents = self.0;
+        for (exponent, other) in exponents.iter_mut().zip(other.0.iter()) {
+            *exponent = operation(*exponent, *other)?;
+        }
+        Some(Dimension(exponents))
+    }
+}
+
+impl std::fmt::Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        if self.is_dimensionless() {
+            return write!(f,"1")
+        }
+        let mut first = true;
+        for (symbol, &exponent) in BASE_SYMBOLS.iter().zip(self.0.iter()) {
+            if exponent == 0 {
+                continue
+            }
+            if !first {
+                write!(f,"·")?;
+            }
+            first = false;
+            write!(f,"{}",symbol)?;
+            if exponent != 1 {
+                write!(f,"{}",to_superscript(&exponent.to_string()).unwrap_or_default())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// # Panics
+/// Panics when an exponent overflows, see [`Dimension::checked_mul`].
+impl std::ops::Mul for Dimension {
+    type Output = Dimension;
+    fn mul(self, other: Dimension) -> Dimension {
+        self.checked_mul(other).expect("dimension exponent overflow")
+    }
+}
+/// # Panics
+/// Panics when an exponent overflows, see [`Dimension::checked_div`].
+impl std::ops::Div for Dimension {
+    type Output = Dimension;
+    fn div(self, other: Dimension) -> Dimension {
+        self.checked_div(other).expect("dimension exponent overflow")
+    }
+}
+
+/// an error of adding, subtracting or comparing quantities of different dimensions
+# [derive(Clone,Copy,PartialEq,Eq,Debug)]
+pub struct DimensionError {
+    pub left: Dimension,
+    pub right: Dimension,
+}
+
+impl std::fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(f,"incompatible dimensions {} and {}",self.left,self.right)
+    }
+}
+impl std::error::Error for DimensionError {}
+
+/// the SI base units and the SI derived units with a special name
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{Dimension, SiUnit};
+/// assert_eq!("Ω", SiUnit::Ohm.symbol());
+/// assert_eq!(Dimension::new([2, 1, -3, -2, 0, 0, 0]), SiUnit::Ohm.dimension());
+/// assert_eq!(Some(SiUnit::Joule), SiUnit::from_dimension(SiUnit::Newton.dimension() * Dimension::LENGTH));
+/// ```
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub enum SiUnit {
+    Metre,  Kilogram,   Second, Ampere, Kelvin, Mole,   Candela,
+    Newton, Pascal,     Joule,  Watt,   Coulomb,    Volt,   Farad,
+    Ohm,    Siemens,    Weber,  Tesla,  Henry,  Hertz,
+}
+
+impl SiUnit {
+    /// every `SiUnit`, the base units first
+    pub const ALL : [SiUnit;20] = [
+        SiUnit::Metre, SiUnit::Kilogram, SiUnit::Second, SiUnit::Ampere, SiUnit::Kelvin, SiUnit::Mole, SiUnit::Candela,
+        SiUnit::Newton, SiUnit::Pascal, SiUnit::Joule, SiUnit::Watt, SiUnit::Coulomb, SiUnit::Volt, SiUnit::Farad,
+        SiUnit::Ohm, SiUnit::Siemens, SiUnit::Weber, SiUnit::Tesla, SiUnit::Henry, SiUnit::Hertz,
+    ];
+
+    /// returns the symbol of the unit
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SiUnit::Metre       =>  "m",
+            SiUnit::Kilogram    =>  "kg",
+            SiUnit::Second      =>  "s",
+            SiUnit::Ampere      =>  "A",
+            SiUnit::Kelvin      =>  "K",
+            SiUnit::Mole        =>  "mol",
+            SiUnit::Candela     =>  "cd",
+            SiUnit::Newton      =>  "N",
+            SiUnit::Pascal      =>  "Pa",
+            SiUnit::Joule       =>  "J",
+            SiUnit::Watt        =>  "W",
+            SiUnit::Coulomb     =>  "C",
+            SiUnit::Volt        =>  "V",
+            SiUnit::Farad       =>  "F",
+            SiUnit::Ohm         =>  "Ω",
+            SiUnit::Siemens     =>  "S",
+            SiUnit::Weber       =>  "Wb",
+            SiUnit::Tesla       =>  "T",
+            SiUnit::Henry       =>  "H",
+            SiUnit::Hertz       =>  "Hz"
+        }
+    }
+    /// returns the dimension of the unit
+    pub fn dimension(&self) -> Dimension {
+        Dimension(match self {
+            SiUnit::Metre       =>  [1, 0, 0, 0, 0, 0, 0],
+            SiUnit::Kilogram    =>  [0, 1, 0, 0, 0, 0, 0],
+            SiUnit::Second      =>  [0, 0, 1, 0, 0, 0, 0],
+            SiUnit::Ampere      =>  [0, 0, 0, 1, 0, 0, 0],
+            SiUnit::Kelvin      =>  [0, 0, 0, 0, 1, 0, 0],
+            SiUnit::Mole        =>  [0, 0, 0, 0, 0, 1, 0],
+            SiUnit::Candela     =>  [0, 0, 0, 0, 0, 0, 1],
+            SiUnit::Newton      =>  [1, 1, -2, 0, 0, 0, 0],
+            SiUnit::Pascal      =>  [-1, 1, -2, 0, 0, 0, 0],
+            SiUnit::Joule       =>  [2, 1, -2, 0, 0, 0, 0],
+            SiUnit::Watt        =>  [2, 1, -3, 0, 0, 0, 0],
+            SiUnit::Coulomb     =>  [0, 0, 1, 1, 0, 0, 0],
+            SiUnit::Volt        =>  [2, 1, -3, -1, 0, 0, 0],
+            SiUnit::Farad       =>  [-2, -1, 4, 2, 0, 0, 0],
+            SiUnit::Ohm         =>  [2, 1, -3, -2, 0, 0, 0],
+            SiUnit::Siemens     =>  [-2, -1, 3, 2, 0, 0, 0],
+            SiUnit::Weber       =>  [2, 1, -2, -1, 0, 0, 0],
+            SiUnit::Tesla       =>  [0, 1, -2, -1, 0, 0, 0],
+            SiUnit::Henry       =>  [2, 1, -2, -2, 0, 0, 0],
+            SiUnit::Hertz       =>  [0, 0, -1, 0, 0, 0, 0]
+        })
+    }
+    /// returns the unit with the given dimension
+    pub fn from_dimension(dimension: Dimension) -> Option<SiUnit> {
+        SiUnit::ALL.iter().copied().find(|unit| unit.dimension() == dimension)
+    }
+    /// returns the unit raised to the power `n` as a `Quantity`
+    pub fn powi(self, n: i8) -> Quantity {
+        Quantity::from(self).powi(n)
+    }
+}
+
+impl std::fmt::Display for SiUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(f,"{}",self.symbol())
+    }
+}
+impl std::convert::From<SiUnit> for Dimension {
+    fn from(unit: SiUnit) -> Self {
+        unit.dimension()
+    }
+}
+
+/// a value with a [`Dimension`], in SI base units
+///
+/// Multiplying and dividing combine the dimensions, adding and subtracting need equal dimensions.
+/// Quantities of different dimensions are not comparable, so `partial_cmp` returns `None` for them.
+/// Like the operators of [`Dimension`], `*`, `/`, `powi` and `recip` panic if an exponent of the dimension overflows,
+/// the `checked_*` methods return `None` instead.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{Quantity, SiUnit};
+/// let speed = 20.0 * SiUnit::Metre / SiUnit::Second;
+/// let distance = speed * (3.0 * SiUnit::Second);
+/// assert_eq!(60.0 * SiUnit::Metre, distance);
+/// let force = 2.0 * SiUnit::Kilogram * (9.5 * SiUnit::Metre / SiUnit::Second.powi(2));
+/// assert_eq!("19 N", force.to_string());
+/// assert_eq!("60 m", distance.to_string());
+/// assert!(distance.try_add(speed).is_err());
+/// ```
+# [derive(Clone,Copy,PartialEq,Debug)]
+pub struct Quantity {
+    value: f64,
+    dimension: Dimension,
+}
+
+impl Quantity {
+    /// returns the `Quantity` of `value` SI base units of the given dimension
+    pub fn new(value: f64, dimension: Dimension) -> Self {
+        Quantity { value, dimension }
+    }
+    /// returns the value in SI base units
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+    /// returns the dimension
+    pub fn dimension(&self) -> Dimension {
+        self.dimension
+    }
+    /// returns the sum of two quantities
+    ///
+    /// # Errors
+    /// Returns a `DimensionError` when the dimensions are not equal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Dimension, DimensionError, Quantity, SiUnit};
+    /// let sum = (2.0 * SiUnit::Joule).try_add(3.0 * SiUnit::Newton * SiUnit::Metre);
+    /// assert_eq!(Ok(5.0 * SiUnit::Joule), sum);
+    /// let error = (2.0 * SiUnit::Metre).try_add(3.0 * SiUnit::Second).unwrap_err();
+    /// assert_eq!(DimensionError { left: Dimension::LENGTH, right: Dimension::TIME }, error);
+    /// ```
+    pub fn try_add(self, other: Quantity) -> Result<Quantity, DimensionError> {
+        self.check(&other)?;
+        Ok(Quantity::new(self.value + other.value, self.dimension))
+    }
+    /// returns the difference of two quantities
+    ///
+    /// # Errors
+    /// Returns a `DimensionError` when the dimensions are not equal.
+    pub fn try_sub(self, other: Quantity) -> Result<Quantity, DimensionError> {
+        self.check(&other)?;
+        Ok(Quantity::new(self.value - other.value, self.dimension))
+    }
+    /// returns the quantity raised to the power `n`
+    ///
+    /// # Panics
+    /// if an exponent of the dimension overflows, see [`Quantity::checked_powi`]
+    pub fn powi(self, n: i8) -> Quantity {
+        Quantity::new(self.value.powi(n as i32), self.dimension.powi(n))
+    }
+    /// returns the quantity raised to the power `n`, or `None` if an exponent of the dimension overflows
+    pub fn checked_powi(self, n: i8) -> Option<Quantity> {
+        Some(Quantity::new(self.value.powi(n as i32), self.dimension.checked_powi(n)?))
+    }
+    /// returns the reciprocal quantity
+    ///
+    /// # Panics
+    /// if an exponent of the dimension is `-128`
+    pub fn recip(self) -> Quantity {
+        Quantity::new(self.value.recip(), self.dimension.recip())
+    }
+    /// returns the reciprocal quantity, or `None` if an exponent of the dimension is `-128`
+    pub fn checked_recip(self) -> Option<Quantity> {
+        Some(Quantity::new(self.value.recip(), self.dimension.checked_recip()?))
+    }
+    /// returns the product, or `None` if an exponent of the dimension overflows
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Dimension, Quantity};
+    /// let large = Quantity::new(2.0, Dimension::LENGTH.powi(100));
+    /// assert_eq!(Some(Quantity::new(6.0, Dimension::LENGTH.powi(101))), large.checked_mul(Quantity::new(3.0, Dimension::LENGTH)));
+    /// assert_eq!(None, large.checked_mul(large));
+    /// ```
+    pub fn checked_mul(self, other: Quantity) -> Option<Quantity> {
+        Some(Quantity::new(self.value * other.value, self.dimension.checked_mul(other.dimension)?))
+    }
+    /// returns the quotient, or `None` if an exponent of the dimension overflows
+    pub fn checked_div(self, other: Quantity) -> Option<Quantity> {
+        Some(Quantity::new(self.value / other.value, self.dimension.checked_div(other.dimension)?))
+    }
+    /// returns the value if the quantity is dimensionless
+    ///
+    /// # Errors
+    /// Returns a `DimensionError` when the quantity has a dimension.
+    pub fn dimensionless(self) -> Result<f64, DimensionError> {
+        self.check(&Quantity::new(0.0, Dimension::DIMENSIONLESS))?;
+        Ok(self.value)
+    }
+    fn check(&self, other: &Quantity) -> Result<(), DimensionError> {
+        if self.dimension == other.dimension {
+            Ok(())
+        } else {
+            Err(DimensionError { left: self.dimension, right: other.dimension })
+        }
+    }
+}
+
+impl std::fmt::Display for Quantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        std::fmt::Display::fmt(&self.value, f)?;
+        if self.dimension.is_dimensionless() {
+            return Ok(())
+        }
+        match SiUnit::from_dimension(self.dimension) {
+            Some(unit)  =>  write!(f," {}",unit),
+            None        =>  write!(f," {}",self.dimension)
+        }
+    }
+}
+
+impl std::cmp::PartialOrd for Quantity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.dimension == other.dimension {
+            self.value.partial_cmp(&other.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::convert::From<SiUnit> for Quantity {
+    fn from(unit: SiUnit) -> Self {
+        Quantity::new(1.0, unit.dimension())
+    }
+}
+impl std::convert::From<f64> for Quantity {
+    fn from(value: f64) -> Self {
+        Quantity::new(value, Dimension::DIMENSIONLESS)
+    }
+}
+
+/// # Panics
+/// Panics when the dimensions are not equal, see [`Quantity::try_add`].
+impl std::ops::Add for Quantity {
+    type Output = Quantity;
+    fn add(self, other: Quantity) -> Quantity {
+        self.try_add(other).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+/// # Panics
+/// Panics when the dimensions are not equal, see [`Quantity::try_sub`].
+impl std::ops::Sub for Quantity {
+    type Output = Quantity;
+    fn sub(self, other: Quantity) -> Quantity {
+        self.try_sub(other).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+impl std::ops::Neg for Quantity {
+    type Output = Quantity;
+    fn neg(self) -> Quantity {
+        Quantity::new(-self.value, self.dimension)
+    }
+}
+
+// multiplying and dividing by a `Quantity`, an `SiUnit` or an `f64`, which panic when an exponent overflows
+macro_rules! impl_quantity_mul_div {
+    ($($lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl std::ops::Mul<$rhs> for $lhs {
+                type Output = Quantity;
+                fn mul(self, other: $rhs) -> Quantity {
+                    Quantity::from(self).checked_mul(Quantity::from(other)).expect("dimension exponent overflow")
+                }
+            }
+            impl std::ops::Div<$rhs> for $lhs {
+                type Output = Quantity;
+                fn div(self, other: $rhs) -> Quantity {
+                    Quantity::from(self).checked_div(Quantity::from(other)).expect("dimension exponent overflow")
+                }
+            }
+        )*
+    };
+}
+impl_quantity_mul_div! {
+    Quantity, Quantity;
+    Quantity, SiUnit;
+    Quantity, f64;
+    SiUnit, Quantity;
+    SiUnit, SiUnit;
+    f64, Quantity;
+    f64, SiUnit;
+}
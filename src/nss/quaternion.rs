@@ -0,0 +1,434 @@
+use super::Real;
+
+/// the order of the axes of Euler angles
+///
+/// The rotations are intrinsic: `Zyx` turns about z, then about the turned y and then about the twice turned x,
+/// which is the same as turning about the fixed x, y and z axes in this order.
+/// The first angle of the angles array belongs to the first axis of the order.
+/// The orders of three axes are the Tait-Bryan angles, those which turn about the first axis again, like `Zxz`,
+/// the proper Euler angles.
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub enum EulerOrder {
+    /// x, y, z
+    Xyz,
+    /// x, z, y
+    Xzy,
+    /// y, x, z
+    Yxz,
+    /// y, z, x
+    Yzx,
+    /// z, x, y
+    Zxy,
+    /// z, y, x, the yaw, pitch and roll of vehicles
+    #[default]
+    Zyx,
+    /// x, y, x
+    Xyx,
+    /// x, z, x
+    Xzx,
+    /// y, x, y
+    Yxy,
+    /// y, z, y
+    Yzy,
+    /// z, x, z, the precession, nutation and spin of tops and orbits
+    Zxz,
+    /// z, y, z
+    Zyz,
+}
+
+impl EulerOrder {
+    /// returns the indices of the axes, `0` for x, `1` for y and `2` for z
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::Xyz =>  [0, 1, 2],
+            EulerOrder::Xzy =>  [0, 2, 1],
+            EulerOrder::Yxz =>  [1, 0, 2],
+            EulerOrder::Yzx =>  [1, 2, 0],
+            EulerOrder::Zxy =>  [2, 0, 1],
+            EulerOrder::Zyx =>  [2, 1, 0],
+            EulerOrder::Xyx =>  [0, 1, 0],
+            EulerOrder::Xzx =>  [0, 2, 0],
+            EulerOrder::Yxy =>  [1, 0, 1],
+            EulerOrder::Yzy =>  [1, 2, 1],
+            EulerOrder::Zxz =>  [2, 0, 2],
+            EulerOrder::Zyz =>  [2, 1, 2]
+        }
+    }
+}
+
+/// a quaternion `w + xi + yj + zk`
+///
+/// Unit quaternions are rotations in three dimensions. Vectors are arrays `[x, y, z]`,
+/// and rotation matrices are arrays of rows which turn column vectors.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Quaternion;
+/// let close = |a: [f64; 3], b: [f64; 3]| a.iter().zip(&b).all(|(a, b)| (a - b).abs() < 1e-12);
+/// let quarter = Quaternion::from_axis_angle([0.0, 0.0, 2.0], std::f64::consts::FRAC_PI_2);
+/// assert!(close([0.0, 1.0, 0.0], quarter.rotate([1.0, 0.0, 0.0])));
+/// let half = quarter * quarter;
+/// assert!(close([-1.0, 0.0, 0.0], half.rotate([1.0, 0.0, 0.0])));
+/// assert!(close([1.0, 0.0, 0.0], (half * half.inverse()).rotate([1.0, 0.0, 0.0])));
+/// let (i, j, k) = (Quaternion::new(0.0, 1.0, 0.0, 0.0), Quaternion::new(0.0, 0.0, 1.0, 0.0), Quaternion::new(0.0, 0.0, 0.0, 1.0));
+/// assert_eq!(k, i * j);
+/// assert_eq!(-k, j * i);
+/// assert_eq!("0 + 1i - 2j + 0.5k", Quaternion::new(0.0, 1.0, -2.0, 0.5).to_string());
+/// ```
+# [derive(Clone,Copy,PartialEq,Debug)]
+pub struct Quaternion<T: Real> {
+    w: T,
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T: Real> Quaternion<T> {
+    /// returns `w + xi + yj + zk`
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, x, y, z }
+    }
+    /// returns the quaternion of a scalar and a vector part
+    pub fn from_parts(scalar: T, vector: [T; 3]) -> Self {
+        Quaternion::new(scalar, vector[0], vector[1], vector[2])
+    }
+    /// returns `0`
+    pub fn zero() -> Self {
+        Quaternion::new(T::zero(), T::zero(), T::zero(), T::zero())
+    }
+    /// returns `1`, the rotation which turns nothing
+    pub fn identity() -> Self {
+        Quaternion::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+    /// returns the scalar part `w`
+    pub fn w(&self) -> T {
+        self.w
+    }
+    /// returns the part `x` of `i`
+    pub fn x(&self) -> T {
+        self.x
+    }
+    /// returns the part `y` of `j`
+    pub fn y(&self) -> T {
+        self.y
+    }
+    /// returns the part `z` of `k`
+    pub fn z(&self) -> T {
+        self.z
+    }
+    /// returns the vector part `[x, y, z]`
+    pub fn vector(&self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+    /// returns the conjugate `w - xi - yj - zk`
+    pub fn conj(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+    /// returns the sum of the products of the parts
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+    /// returns the squared norm
+    pub fn norm_sqr(&self) -> T {
+        self.dot(self)
+    }
+    /// returns the norm
+    pub fn norm(&self) -> T {
+        self.w.hypot(self.x).hypot(self.y.hypot(self.z))
+    }
+    /// returns the quaternion divided by its norm, with parts which are not a number for zero
+    pub fn normalize(&self) -> Self {
+        *self / self.norm()
+    }
+    /// returns the multiplicative inverse, which is the conjugate for unit quaternions
+    pub fn inverse(&self) -> Self {
+        self.conj() / self.norm_sqr()
+    }
+
+    /// returns the rotation by an angle in radians about an axis, which need not have length 1,
+    /// the identity for the angle `0` or the zero axis
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Quaternion;
+    /// assert_eq!(Quaternion::identity(), Quaternion::from_axis_angle([0.0, 0.0, 0.0], 1.0));
+    /// assert_eq!(Quaternion::identity(), Quaternion::from_axis_angle([0.0, 0.0, 0.0], 0.0));
+    /// ```
+    pub fn from_axis_angle(axis: [T; 3], angle: T) -> Self {
+        let length = axis[0].hypot(axis[1]).hypot(axis[2]);
+        if length == T::zero() || angle == T::zero() {
+            return Quaternion::identity()
+        }
+        let half = angle * T::from_f64(0.5);
+        let scale = half.sin() / length;
+        Quaternion::new(half.cos(), axis[0] * scale, axis[1] * scale, axis[2] * scale)
+    }
+    /// returns the unit axis and the angle in `0..=π` radians of a rotation,
+    /// the axis of the identity is `[1, 0, 0]`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Quaternion;
+    /// let rotation = Quaternion::from_axis_angle([0.0, 3.0, 4.0], -1.0);
+    /// let (axis, angle) = rotation.to_axis_angle();
+    /// assert!((angle - 1.0f64).abs() < 1e-15);
+    /// assert!(axis.iter().zip(&[0.0, -0.6, -0.8]).all(|(a, b)| (a - b).abs() < 1e-15));
+    /// ```
+    pub fn to_axis_angle(&self) -> ([T; 3], T) {
+        let rotation = if self.w < T::zero() { -*self } else { *self };
+        let length = rotation.x.hypot(rotation.y).hypot(rotation.z);
+        if length == T::zero() {
+            return ([T::one(), T::zero(), T::zero()], T::zero())
+        }
+        let angle = (length.atan2(rotation.w)) * (T::one() + T::one());
+        ([rotation.x / length, rotation.y / length, rotation.z / length], angle)
+    }
+    /// returns the rotation of intrinsic Euler angles in radians in the order of the axes
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{EulerOrder, Quaternion};
+    /// let angles = [0.3f64, -1.2, 2.5];
+    /// for &order in &[EulerOrder::Xyz, EulerOrder::Yzx, EulerOrder::Zyx] {
+    ///     let rotation = Quaternion::from_euler(angles, order);
+    ///     let back = rotation.to_euler(order);
+    ///     assert!(angles.iter().zip(&back).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// }
+    /// let proper = [0.3f64, 1.2, -2.5];
+    /// let back = Quaternion::from_euler(proper, EulerOrder::Zxz).to_euler(EulerOrder::Zxz);
+    /// assert!(proper.iter().zip(&back).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// let yaw = Quaternion::from_euler([0.5, 0.0, 0.0], EulerOrder::Zyx);
+    /// assert_eq!(Quaternion::from_axis_angle([0.0, 0.0, 1.0], 0.5), yaw);
+    /// ```
+    pub fn from_euler(angles: [T; 3], order: EulerOrder) -> Self {
+        let half = T::from_f64(0.5);
+        order.axes().iter().zip(&angles).fold(Quaternion::identity(), |rotation, (&axis, &angle)| {
+            let mut vector = [T::zero(); 3];
+            vector[axis] = (angle * half).sin();
+            rotation * Quaternion::from_parts((angle * half).cos(), vector)
+        })
+    }
+    /// returns the intrinsic Euler angles in radians in the order of the axes,
+    /// the middle angle is in `-π/2..=π/2` for Tait-Bryan angles, in `0..=π` for proper Euler angles,
+    /// and the others in `-π..=π`
+    ///
+    /// In gimbal lock, when the middle angle is `±π/2` or `0` and `π` respectively, the last angle is `0`.
+    pub fn to_euler(&self, order: EulerOrder) -> [T; 3] {
+        // the half angle method of Bernardes and Viollet on the reversed, extrinsic order
+        let [last, middle, first] = order.axes();
+        let proper = first == last;
+        let vector = self.vector();
+        let sign = if (middle + 3 - first) % 3 == 1 { T::one() } else { -T::one() };
+        let (a, b, c, d) = if proper {
+            (self.w, vector[first], vector[middle], vector[3 - first - middle] * sign)
+        } else {
+            (self.w - vector[middle], vector[first] + vector[last] * sign, vector[middle] + self.w, vector[last] * sign - vector[first])
+        };
+        let (outer, inner) = (a.hypot(b), c.hypot(d));
+        let two = T::one() + T::one();
+        let pitch = inner.atan2(outer) * two - if proper { T::zero() } else { T::pi() / two };
+        let (half_sum, half_difference) = (b.atan2(a), d.atan2(c));
+        let (start, end) = if inner <= T::epsilon() * outer {
+            (T::zero(), half_sum * two)
+        } else if outer <= T::epsilon() * inner {
+            (T::zero(), half_difference * two)
+        } else {
+            (half_sum - half_difference, half_sum + half_difference)
+        };
+        [wrap(if proper { end } else { end * sign }), pitch, wrap(start)]
+    }
+    /// returns the rotation of a rotation matrix, with a scalar part which is not negative
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Quaternion;
+    /// let rotation = Quaternion::new(0.5, -0.5, 0.5, 0.5);
+    /// let matrix = rotation.to_rotation_matrix();
+    /// assert_eq!([[0.0, -1.0, 0.0], [0.0, 0.0, 1.0], [-1.0, 0.0, 0.0]], matrix);
+    /// assert_eq!(rotation, Quaternion::from_rotation_matrix(matrix));
+    /// ```
+    pub fn from_rotation_matrix(matrix: [[T; 3]; 3]) -> Self {
+        let [[m00, m01, m02], [m10, m11, m12], [m20, m21, m22]] = matrix;
+        let one = T::one();
+        let quarter = T::from_f64(0.25);
+        let trace = m00 + m11 + m22;
+        // Shepperd's method divides by the largest of the four parts
+        let rotation = if trace > T::zero() {
+            let s = (trace + one).sqrt() * (one + one);
+            Quaternion::new(s * quarter, (m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s)
+        } else if m00 >= m11 && m00 >= m22 {
+            let s = (one + m00 - m11 - m22).sqrt() * (one + one);
+            Quaternion::new((m21 - m12) / s, s * quarter, (m01 + m10) / s, (m02 + m20) / s)
+        } else if m11 >= m22 {
+            let s = (one + m11 - m00 - m22).sqrt() * (one + one);
+            Quaternion::new((m02 - m20) / s, (m01 + m10) / s, s * quarter, (m12 + m21) / s)
+        } else {
+            let s = (one + m22 - m00 - m11).sqrt() * (one + one);
+            Quaternion::new((m10 - m01) / s, (m02 + m20) / s, (m12 + m21) / s, s * quarter)
+        };
+        if rotation.w < T::zero() { -rotation } else { rotation }
+    }
+    /// returns the rotation matrix, which also turns vectors for quaternions which are not normalized
+    pub fn to_rotation_matrix(&self) -> [[T; 3]; 3] {
+        let one = T::one();
+        let s = (one + one) / self.norm_sqr();
+        let Quaternion { w, x, y, z } = *self;
+        [
+            [one - s * (y * y + z * z), s * (x * y - z * w), s * (x * z + y * w)],
+            [s * (x * y + z * w), one - s * (x * x + z * z), s * (y * z - x * w)],
+            [s * (x * z - y * w), s * (y * z + x * w), one - s * (x * x + y * y)]
+        ]
+    }
+    /// returns the vector turned by the rotation, `q·v·q⁻¹`
+    pub fn rotate(&self, vector: [T; 3]) -> [T; 3] {
+        // v + w·t + u × t with t = 2 (u × v) / |q|²
+        let u = self.vector();
+        let scale = (T::one() + T::one()) / self.norm_sqr();
+        let t = cross(u, vector).map(|part| part * scale);
+        let turn = cross(u, t);
+        [0, 1, 2].map(|axis| vector[axis] + self.w * t[axis] + turn[axis])
+    }
+
+    /// returns the spherical linear interpolation of unit quaternions on the shorter arc,
+    /// with a constant angular velocity for `t` from `0` to `1`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Quaternion;
+    /// let start = Quaternion::identity();
+    /// let end = Quaternion::from_axis_angle([0.0, 0.0, 1.0], 2.0);
+    /// let (_, angle) = start.slerp(&end, 0.25).to_axis_angle();
+    /// assert!((angle - 0.5f64).abs() < 1e-15);
+    /// assert_eq!(start, start.slerp(&start, 0.5));
+    /// ```
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let end = if self.dot(other) < T::zero() { -*other } else { *other };
+        // half the angle between them without the cancellation of acos near 1
+        let half = (*self - end).norm().atan2((*self + end).norm());
+        let angle = half + half;
+        let sine = angle.sin();
+        if sine == T::zero() {
+            return *self
+        }
+        let start_weight = ((T::one() - t) * angle).sin() / sine;
+        let end_weight = (t * angle).sin() / sine;
+        *self * start_weight + end * end_weight
+    }
+    /// returns the normalized linear interpolation of unit quaternions on the shorter arc,
+    /// which is faster than [`slerp`](Quaternion::slerp) but does not turn with a constant velocity
+    pub fn nlerp(&self, other: &Self, t: T) -> Self {
+        let end = if self.dot(other) < T::zero() { -*other } else { *other };
+        (*self * (T::one() - t) + end * t).normalize()
+    }
+
+    fn add_quaternion(self, other: Self) -> Self {
+        Quaternion::new(self.w + other.w, self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+    fn sub_quaternion(self, other: Self) -> Self {
+        Quaternion::new(self.w - other.w, self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+    // the Hamilton product, which is not commutative
+    fn mul_quaternion(self, other: Self) -> Self {
+        Quaternion::new(
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w
+        )
+    }
+}
+
+// the angle in -π..=π
+fn wrap<T: Real>(angle: T) -> T {
+    let turn = T::pi() + T::pi();
+    if angle > T::pi() {
+        angle - turn
+    } else if angle < -T::pi() {
+        angle + turn
+    } else {
+        angle
+    }
+}
+
+// the cross product
+fn cross<T: Real>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+impl<T: Real> std::fmt::Display for Quaternion<T> {
+    /// writes `w + xi + yj + zk`, a precision applies to each number
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match f.precision() {
+            Some(precision) =>  write!(f,"{:.*}",precision,self.w)?,
+            None            =>  write!(f,"{}",self.w)?
+        }
+        for (part, unit) in [(self.x, 'i'), (self.y, 'j'), (self.z, 'k')] {
+            let sign = if part.is_sign_negative() { '-' } else { '+' };
+            match f.precision() {
+                Some(precision) =>  write!(f," {} {:.*}{}",sign,precision,part.abs(),unit)?,
+                None            =>  write!(f," {} {}{}",sign,part.abs(),unit)?
+            }
+        }
+        Ok(())
+    }
+}
+
+// the operators for quaternions
+macro_rules! impl_quaternion_ops {
+    ($($trait:ident, $method:ident, $assign:ident, $assign_method:ident, $core:ident;)*) => {
+        $(
+            impl<T: Real> std::ops::$trait for Quaternion<T> {
+                type Output = Quaternion<T>;
+                fn $method(self, other: Quaternion<T>) -> Quaternion<T> {
+                    self.$core(other)
+                }
+            }
+            impl<T: Real> std::ops::$assign for Quaternion<T> {
+                fn $assign_method(&mut self, other: Quaternion<T>) {
+                    *self = self.$core(other);
+                }
+            }
+        )*
+    };
+}
+impl_quaternion_ops! {
+    Add, add, AddAssign, add_assign, add_quaternion;
+    Sub, sub, SubAssign, sub_assign, sub_quaternion;
+    Mul, mul, MulAssign, mul_assign, mul_quaternion;
+}
+
+impl<T: Real> std::ops::Mul<T> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: T) -> Quaternion<T> {
+        Quaternion::new(self.w * other, self.x * other, self.y * other, self.z * other)
+    }
+}
+impl<T: Real> std::ops::Div<T> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn div(self, other: T) -> Quaternion<T> {
+        Quaternion::new(self.w / other, self.x / other, self.y / other, self.z / other)
+    }
+}
+impl<T: Real> std::ops::Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn neg(self) -> Quaternion<T> {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Real> std::iter::Sum for Quaternion<T> {
+    fn sum<I: Iterator<Item = Quaternion<T>>>(iter: I) -> Self {
+        iter.fold(Quaternion::zero(), |sum, value| sum + value)
+    }
+}
+impl<T: Real> std::iter::Product for Quaternion<T> {
+    fn product<I: Iterator<Item = Quaternion<T>>>(iter: I) -> Self {
+        iter.fold(Quaternion::identity(), |product, value| product * value)
+    }
+}
+
+impl<T: Real> std::convert::From<T> for Quaternion<T> {
+    fn from(w: T) -> Self {
+        Quaternion::new(w, T::zero(), T::zero(), T::zero())
+    }
+}
@@ -0,0 +1,695 @@
+use super::wide::wide_mul;
+
+pub(super) mod sealed {
+    pub trait Sealed {}
+}
+
+/// an integer type which can be the numerator and denominator of a [`Rational`]
+///
+/// The trait is implemented for the primitive integers, [`BigUint`](super::BigUint) and [`BigInt`](super::BigInt),
+/// and cannot be implemented outside of this crate.
+/// Its methods take references, so they are only called on a generic `T: Integer`,
+/// the inherent methods of the primitive integers with the same names are unchanged.
+pub trait Integer: Clone + Ord + std::fmt::Debug + std::fmt::Display + sealed::Sealed {
+    /// returns `0`
+    fn zero() -> Self;
+    /// returns `1`
+    fn one() -> Self;
+    /// returns `self + other`, or `None` on overflow
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    /// returns `self - other`, or `None` on overflow
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    /// returns `self * other`, or `None` on overflow
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    /// returns `self / other` rounded toward zero, or `None` on overflow or division by zero
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+    /// returns the remainder of `self / other` with the sign of `self`, or `None` on overflow or division by zero
+    fn checked_rem(&self, other: &Self) -> Option<Self>;
+    /// returns `-self`, or `None` on overflow
+    fn checked_neg(&self) -> Option<Self>;
+    /// returns the integer of the value, or `None` if it does not fit
+    fn from_u128(value: u128) -> Option<Self>;
+    /// returns the value as `u128`, or `None` if it is negative or does not fit
+    fn to_u128(&self) -> Option<u128>;
+    /// returns the nearest `f64`
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl sealed::Sealed for $integer {}
+            impl Integer for $integer {
+                fn zero() -> Self {
+                    0
+                }
+                fn one() -> Self {
+                    1
+                }
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_add(*self, *other)
+                }
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_sub(*self, *other)
+                }
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_mul(*self, *other)
+                }
+                fn checked_div(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_div(*self, *other)
+                }
+                fn checked_rem(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_rem(*self, *other)
+                }
+                fn checked_neg(&self) -> Option<Self> {
+                    <$integer>::checked_neg(*self)
+                }
+                fn from_u128(value: u128) -> Option<Self> {
+                    <$integer as std::convert::TryFrom<u128>>::try_from(value).ok()
+                }
+                fn to_u128(&self) -> Option<u128> {
+                    <u128 as std::convert::TryFrom<$integer>>::try_from(*self).ok()
+                }
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// an error of parsing a [`Rational`]
+///
+/// The offsets are byte offsets into the text.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum RationalError {
+    /// the text is empty or only whitespace
+    Empty,
+    /// a character which does not fit the notation, as `(offset, char)`
+    Unexpected(usize, char),
+    /// the text ends before a digit, as `offset`
+    UnexpectedEnd(usize),
+    /// the denominator starting at `offset` is zero
+    ZeroDenominator(usize),
+    /// the number does not fit the integer type
+    Overflow,
+}
+
+impl std::fmt::Display for RationalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            RationalError::Empty                    =>  write!(f,"empty text"),
+            RationalError::Unexpected(offset, c)    =>  write!(f,"unexpected {:?} at {}",c,offset),
+            RationalError::UnexpectedEnd(offset)    =>  write!(f,"expected a digit at {}",offset),
+            RationalError::ZeroDenominator(offset)  =>  write!(f,"zero denominator at {}",offset),
+            RationalError::Overflow                 =>  write!(f,"number too large for the integer type")
+        }
+    }
+}
+impl std::error::Error for RationalError {}
+
+/// an exact fraction of two integers in lowest terms
+///
+/// The denominator is always positive, so equal fractions have equal fields.
+/// The operators panic on overflow like the operators of the integers in debug builds,
+/// the `checked_*` methods return `None` instead.
+/// Comparisons never overflow.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Rational;
+/// let third = Rational::new(1, 3);
+/// let sum = third + Rational::new(1, 6);
+/// assert_eq!(Rational::new(1, 2), sum);
+/// assert_eq!("1/2", sum.to_string());
+/// assert!(Rational::new(2_000_000_001i32, 2_000_000_000) < Rational::new(1_999_999_999, 1_999_999_998));
+/// assert_eq!(None, Rational::new(i32::MAX, 2).checked_add(&Rational::new(i32::MAX, 3)));
+/// ```
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug)]
+pub struct Rational<T> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Integer> Rational<T> {
+    /// returns the fraction in lowest terms
+    ///
+    /// # Panics
+    /// Panics if the denominator is zero, or the fraction does not fit, like `i32::MIN / -1`.
+    pub fn new(numerator: T, denominator: T) -> Self {
+        assert!(denominator != T::zero(), "zero denominator");
+        Rational::checked_new(numerator, denominator).expect("attempt to reduce with overflow")
+    }
+    /// returns the fraction in lowest terms, or `None` if the denominator is zero or the fraction does not fit
+    pub fn checked_new(numerator: T, denominator: T) -> Option<Self> {
+        let zero = T::zero();
+        if denominator == zero {
+            return None
+        }
+        let divisor = gcd(&numerator, &denominator);
+        let numerator = numerator.checked_div(&divisor)?;
+        let denominator = denominator.checked_div(&divisor)?;
+        if denominator < zero {
+            Some(Rational { numerator: numerator.checked_neg()?, denominator: denominator.checked_neg()? })
+        } else {
+            Some(Rational { numerator, denominator })
+        }
+    }
+    /// returns the integer as fraction
+    pub fn from_integer(integer: T) -> Self {
+        Rational { numerator: integer, denominator: T::one() }
+    }
+    /// returns `0`
+    pub fn zero() -> Self {
+        Rational::from_integer(T::zero())
+    }
+    /// returns `1`
+    pub fn one() -> Self {
+        Rational::from_integer(T::one())
+    }
+    /// returns the numerator, which has the sign of the fraction
+    pub fn numerator(&self) -> &T {
+        &self.numerator
+    }
+    /// returns the denominator, which is positive
+    pub fn denominator(&self) -> &T {
+        &self.denominator
+    }
+    /// returns whether the fraction is an integer
+    pub fn is_integer(&self) -> bool {
+        self.denominator == T::one()
+    }
+    /// returns whether the fraction is zero
+    pub fn is_zero(&self) -> bool {
+        self.numerator == T::zero()
+    }
+    /// returns whether the fraction is less than zero
+    pub fn is_negative(&self) -> bool {
+        self.numerator < T::zero()
+    }
+
+    /// returns `self + other`, or `None` on overflow
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let divisor = gcd(&self.denominator, &other.denominator);
+        let left = self.denominator.checked_div(&divisor)?;
+        let right = other.denominator.checked_div(&divisor)?;
+        let numerator = self.numerator.checked_mul(&right)?.checked_add(&other.numerator.checked_mul(&left)?)?;
+        Rational::checked_new(numerator, left.checked_mul(&other.denominator)?)
+    }
+    /// returns `self - other`, or `None` on overflow
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let divisor = gcd(&self.denominator, &other.denominator);
+        let left = self.denominator.checked_div(&divisor)?;
+        let right = other.denominator.checked_div(&divisor)?;
+        let numerator = self.numerator.checked_mul(&right)?.checked_sub(&other.numerator.checked_mul(&left)?)?;
+        Rational::checked_new(numerator, left.checked_mul(&other.denominator)?)
+    }
+    /// returns `self * other`, or `None` on overflow
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let left = gcd(&self.numerator, &other.denominator);
+        let right = gcd(&other.numerator, &self.denominator);
+        let numerator = self.numerator.checked_div(&left)?.checked_mul(&other.numerator.checked_div(&right)?)?;
+        let denominator = self.denominator.checked_div(&right)?.checked_mul(&other.denominator.checked_div(&left)?)?;
+        Rational::checked_new(numerator, denominator)
+    }
+    /// returns `self / other`, or `None` on overflow or division by zero
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_mul(&other.checked_recip()?)
+    }
+    /// returns the remainder of `self / other` with the sign of `self`, or `None` on overflow or division by zero
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Rational;
+    /// assert_eq!(Some(Rational::new(-1, 6)), Rational::new(-7, 6).checked_rem(&Rational::new(1, 2)));
+    /// ```
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        let quotient = Rational::from_integer(self.checked_div(other)?.trunc());
+        self.checked_sub(&quotient.checked_mul(other)?)
+    }
+    /// returns `-self`, or `None` on overflow or for a positive unsigned fraction
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Rational { numerator: self.numerator.checked_neg()?, denominator: self.denominator.clone() })
+    }
+    /// returns `1 / self`, or `None` on overflow or for zero
+    pub fn checked_recip(&self) -> Option<Self> {
+        Rational::checked_new(self.denominator.clone(), self.numerator.clone())
+    }
+    /// returns the fraction raised to an integer power, or `None` on overflow or for a negative power of zero
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Rational;
+    /// assert_eq!(Some(Rational::new(-8, 27)), Rational::new(-3, 2).checked_pow(-3));
+    /// assert_eq!(None, Rational::new(3u8, 2).checked_pow(6));
+    /// ```
+    pub fn checked_pow(&self, exponent: i32) -> Option<Self> {
+        let mut base = if exponent < 0 { self.checked_recip()? } else { self.clone() };
+        let mut exponent = exponent.unsigned_abs();
+        let mut power = Rational::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                power = power.checked_mul(&base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.checked_mul(&base)?;
+            }
+        }
+        Some(power)
+    }
+    /// returns the absolute value, or `None` on overflow
+    pub fn checked_abs(&self) -> Option<Self> {
+        if self.is_negative() { self.checked_neg() } else { Some(self.clone()) }
+    }
+    /// returns `1 / self`
+    ///
+    /// # Panics
+    /// Panics for zero or on overflow.
+    pub fn recip(&self) -> Self {
+        assert!(!self.is_zero(), "reciprocal of zero");
+        self.checked_recip().expect("attempt to invert with overflow")
+    }
+    /// returns the fraction raised to an integer power
+    ///
+    /// # Panics
+    /// Panics for a negative power of zero or on overflow.
+    pub fn pow(&self, exponent: i32) -> Self {
+        self.checked_pow(exponent).expect("attempt to raise to a power with overflow or zero to a negative power")
+    }
+    /// returns the absolute value
+    ///
+    /// # Panics
+    /// Panics on overflow.
+    pub fn abs(&self) -> Self {
+        self.checked_abs().expect("attempt to negate with overflow")
+    }
+    /// returns the greatest integer less than or equal to the fraction
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Rational;
+    /// let x = Rational::new(-7, 2);
+    /// assert_eq!((-4, -3, -3, -4), (x.floor(), x.ceil(), x.trunc(), x.round()));
+    /// ```
+    pub fn floor(&self) -> T {
+        floor_div(&self.numerator, &self.denominator).0
+    }
+    /// returns the least integer greater than or equal to the fraction
+    pub fn ceil(&self) -> T {
+        let (floor, remainder) = floor_div(&self.numerator, &self.denominator);
+        if remainder == T::zero() { floor } else { floor.checked_add(&T::one()).unwrap_or(floor) }
+    }
+    /// returns the integer part, rounded toward zero
+    pub fn trunc(&self) -> T {
+        self.numerator.checked_div(&self.denominator).unwrap_or_else(T::zero)
+    }
+    /// returns the nearest integer, halves away from zero
+    pub fn round(&self) -> T {
+        let (floor, remainder) = floor_div(&self.numerator, &self.denominator);
+        // the remainder is less than the denominator, so the subtraction cannot overflow
+        let rest = self.denominator.checked_sub(&remainder).unwrap_or_else(T::zero);
+        let up = match remainder.cmp(&rest) {
+            std::cmp::Ordering::Less    =>  false,
+            std::cmp::Ordering::Equal   =>  !self.is_negative(),
+            std::cmp::Ordering::Greater =>  true
+        };
+        if up { floor.checked_add(&T::one()).unwrap_or(floor) } else { floor }
+    }
+    /// returns the fractional part `self - trunc(self)`, which has the sign of the fraction
+    pub fn fract(&self) -> Self {
+        let numerator = self.numerator.checked_rem(&self.denominator).unwrap_or_else(T::zero);
+        Rational { numerator, denominator: self.denominator.clone() }
+    }
+
+    /// returns the nearest `f64`
+    pub fn to_f64(&self) -> f64 {
+        self.numerator.to_f64() / self.denominator.to_f64()
+    }
+    /// returns the exact value of a finite `f64`, or `None` if it is not finite or does not fit
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Rational;
+    /// assert_eq!(Some(Rational::new(-3i64, 8)), Rational::from_f64(-0.375));
+    /// assert_eq!(Some(Rational::new(3_602_879_701_896_397i64, 36_028_797_018_963_968)), Rational::from_f64(0.1));
+    /// assert_eq!(None, Rational::<i32>::from_f64(0.1));
+    /// ```
+    pub fn from_f64(value: f64) -> Option<Self> {
+        let (mantissa, exponent) = decompose(value)?;
+        let two = T::from_u128(2)?;
+        let mut power = T::one();
+        for _ in 0..exponent.unsigned_abs() {
+            power = power.checked_mul(&two)?;
+        }
+        let mantissa = T::from_u128(mantissa as u128)?;
+        let mantissa = if value < 0.0 { mantissa.checked_neg()? } else { mantissa };
+        if exponent >= 0 {
+            Some(Rational::from_integer(mantissa.checked_mul(&power)?))
+        } else {
+            Rational::checked_new(mantissa, power)
+        }
+    }
+    /// returns the fraction nearest to a finite `f64` with a denominator of at most `max_denominator`,
+    /// or `None` if the value is not finite or does not fit
+    ///
+    /// Of two fractions which are equally near, the one with the smaller denominator is returned.
+    ///
+    /// # Panics
+    /// Panics if `max_denominator` is less than 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::Rational;
+    /// let pi = std::f64::consts::PI;
+    /// assert_eq!(Some(Rational::new(22, 7)), Rational::approximate(pi, &10));
+    /// assert_eq!(Some(Rational::new(355, 113)), Rational::approximate(pi, &1000));
+    /// assert_eq!(Some(Rational::new(-1, 3)), Rational::approximate(-0.333, &100));
+    /// assert_eq!(Some(Rational::new(5, 1)), Rational::approximate(5.0, &1));
+    /// ```
+    pub fn approximate(value: f64, max_denominator: &T) -> Option<Self> {
+        assert!(*max_denominator >= T::one(), "max_denominator less than 1");
+        let (mantissa, exponent) = decompose(value)?;
+        if exponent >= 0 {
+            return Rational::from_f64(value)
+        }
+        let bound = max_denominator.to_u128().unwrap_or(u128::MAX);
+        // the value is mantissa / 2^shift, values below 2^-74 lose bits, which only matters for huge bounds
+        let shift = exponent.unsigned_abs();
+        let (mantissa, shift) = if shift > 127 { (mantissa >> (shift - 127).min(63), 127) } else { (mantissa, shift) };
+        let (numerator, denominator) = best_fraction(mantissa as u128, 1 << shift, bound);
+        let numerator = T::from_u128(numerator)?;
+        let numerator = if value < 0.0 { numerator.checked_neg()? } else { numerator };
+        Rational::checked_new(numerator, T::from_u128(denominator)?)
+    }
+}
+
+// the greatest common divisor, positive if it fits
+fn gcd<T: Integer>(a: &T, b: &T) -> T {
+    let zero = T::zero();
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while b != zero {
+        // only `MIN % -1` overflows, and its remainder is zero
+        let remainder = a.checked_rem(&b).unwrap_or_else(T::zero);
+        a = b;
+        b = remainder;
+    }
+    if a < zero {
+        a.checked_neg().unwrap_or(a)
+    } else if a == zero {
+        T::one()
+    } else {
+        a
+    }
+}
+
+// the floor of `a / b` for a positive `b`, and the remainder from 0 to `b - 1`
+fn floor_div<T: Integer>(a: &T, b: &T) -> (T, T) {
+    let zero = T::zero();
+    let quotient = a.checked_div(b).unwrap_or_else(T::zero);
+    let remainder = a.checked_rem(b).unwrap_or_else(T::zero);
+    if remainder < zero {
+        // a negative remainder needs `b > 1`, so the quotient is above the minimum
+        (quotient.checked_sub(&T::one()).unwrap_or(quotient), remainder.checked_add(b).unwrap_or(remainder))
+    } else {
+        (quotient, remainder)
+    }
+}
+
+// the odd mantissa and the exponent of a finite non-zero absolute value, `(0, 0)` for zero
+fn decompose(value: f64) -> Option<(u64, i32)> {
+    if !value.is_finite() {
+        return None
+    }
+    let bits = value.abs().to_bits();
+    let biased = (bits >> 52) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = if biased == 0 { (fraction, -1074) } else { (fraction | 1 << 52, biased - 1075) };
+    if mantissa == 0 {
+        return Some((0, 0))
+    }
+    let zeros = mantissa.trailing_zeros();
+    Some((mantissa >> zeros, exponent + zeros as i32))
+}
+
+// the fraction nearest to `p / q` with a denominator of at most `bound`, by continued fractions
+fn best_fraction(p: u128, q: u128, bound: u128) -> (u128, u128) {
+    if q <= bound {
+        return (p, q)
+    }
+    // the convergents p0/q0 and p1/q1, and the rest n/d of the continued fraction
+    let (mut p0, mut q0, mut p1, mut q1) = (0u128, 1u128, 1u128, 0u128);
+    let (mut n, mut d) = (p, q);
+    while d != 0 {
+        let a = n / d;
+        match a.checked_mul(q1).and_then(|aq| aq.checked_add(q0)) {
+            Some(q2) if q2 <= bound =>  {
+                let p2 = p0 + a * p1;
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2;
+                let rest = n - a * d;
+                n = d;
+                d = rest;
+            }
+            _                       =>  break
+        }
+    }
+    // the semiconvergent (p0 + k·p1) / (q0 + k·q1) misses by (n - k·d) / (q·qk), the convergent by d / (q·q1)
+    let k = (bound - q0) / q1;
+    let (pk, qk) = (p0 + k * p1, q0 + k * q1);
+    if wide_mul(d, qk) <= wide_mul(n - k * d, q1) { (p1, q1) } else { (pk, qk) }
+}
+
+impl<T: Integer> std::cmp::PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T: Integer> std::cmp::Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // compares the continued fractions term by term, which never overflows
+        let (mut a, mut b) = ((self.numerator.clone(), self.denominator.clone()), (other.numerator.clone(), other.denominator.clone()));
+        let zero = T::zero();
+        loop {
+            let (floor_a, rest_a) = floor_div(&a.0, &a.1);
+            let (floor_b, rest_b) = floor_div(&b.0, &b.1);
+            if floor_a != floor_b {
+                return floor_a.cmp(&floor_b)
+            }
+            match (rest_a == zero, rest_b == zero) {
+                (true, true)    =>  return std::cmp::Ordering::Equal,
+                (true, false)   =>  return std::cmp::Ordering::Less,
+                (false, true)   =>  return std::cmp::Ordering::Greater,
+                (false, false)  =>  {
+                    // rest_a / a.1 < rest_b / b.1 if a.1 / rest_a > b.1 / rest_b
+                    let next_a = (b.1, rest_b);
+                    b = (a.1, rest_a);
+                    a = next_a;
+                }
+            }
+        }
+    }
+}
+
+impl<T: Integer> std::fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        if self.is_integer() {
+            write!(f,"{}",self.numerator)
+        } else {
+            write!(f,"{}/{}",self.numerator,self.denominator)
+        }
+    }
+}
+
+impl<T: Integer> std::str::FromStr for Rational<T> {
+    type Err = RationalError;
+    /// parses a fraction `3/4`, a decimal `-0.125` or a repeating decimal `1.(3)`,
+    /// a decimal may also be divided by an integer, like `1.5/4`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Rational, RationalError};
+    /// assert_eq!(Ok(Rational::new(3, 4)), "3/4".parse());
+    /// assert_eq!(Ok(Rational::new(-1, 8)), "-0.125".parse());
+    /// assert_eq!(Ok(Rational::new(4, 3)), "1.(3)".parse());
+    /// assert_eq!(Ok(Rational::new(1, 6)), "0.1(6)".parse());
+    /// assert_eq!(Ok(Rational::new(i32::MIN, 1)), "-2147483648".parse());
+    /// assert_eq!(Err(RationalError::ZeroDenominator(2)), "1/0".parse::<Rational<i32>>());
+    /// assert_eq!(Err(RationalError::Unexpected(3, ')')), "1.()".parse::<Rational<i32>>());
+    /// assert_eq!(Err(RationalError::Overflow), "-1/2".parse::<Rational<u8>>());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, RationalError> {
+        let start = text.len() - text.trim_start().len();
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(RationalError::Empty)
+        }
+        let mut parser = RationalParser { text: trimmed, offset: 0, start };
+        let negative = parser.eat('-') || parser.eat('−');
+        if !negative {
+            parser.eat('+');
+        }
+        let whole = parser.digits(&T::zero(), negative)?;
+        let mut value = Rational::from_integer(whole.0.clone());
+        if whole.1 == 0 {
+            return Err(parser.unexpected())
+        }
+        if parser.eat('.') {
+            let fixed = parser.digits(&whole.0, negative)?;
+            let ten = T::from_u128(10).ok_or(RationalError::Overflow)?;
+            let scale = power(&ten, fixed.1)?;
+            value = Rational::checked_new(fixed.0.clone(), scale.clone()).ok_or(RationalError::Overflow)?;
+            if parser.eat('(') {
+                let repeating = parser.digits(&fixed.0, negative)?;
+                if repeating.1 == 0 {
+                    return Err(parser.unexpected())
+                }
+                if !parser.eat(')') {
+                    return Err(parser.unexpected())
+                }
+                // 0.1(6) is (16 - 1) / 90
+                let period = power(&ten, repeating.1)?.checked_sub(&T::one()).ok_or(RationalError::Overflow)?;
+                let numerator = repeating.0.checked_sub(&fixed.0).ok_or(RationalError::Overflow)?;
+                let denominator = scale.checked_mul(&period).ok_or(RationalError::Overflow)?;
+                value = Rational::checked_new(numerator, denominator).ok_or(RationalError::Overflow)?;
+            }
+        }
+        if parser.eat('/') {
+            let offset = start + parser.offset;
+            let denominator = parser.digits(&T::zero(), false)?;
+            if denominator.1 == 0 {
+                return Err(parser.unexpected())
+            }
+            if denominator.0 == T::zero() {
+                return Err(RationalError::ZeroDenominator(offset))
+            }
+            value = value.checked_div(&Rational::from_integer(denominator.0)).ok_or(RationalError::Overflow)?;
+        }
+        if parser.offset < trimmed.len() {
+            return Err(parser.unexpected())
+        }
+        Ok(value)
+    }
+}
+
+// ten to a power
+fn power<T: Integer>(ten: &T, exponent: usize) -> Result<T, RationalError> {
+    (0..exponent).try_fold(T::one(), |power, _| power.checked_mul(ten)).ok_or(RationalError::Overflow)
+}
+
+struct RationalParser<'a> {
+    text: &'a str,
+    offset: usize,
+    // the offset of the trimmed text in the original text
+    start: usize,
+}
+impl<'a> RationalParser<'a> {
+    fn eat(&mut self, expected: char) -> bool {
+        if self.text[self.offset..].starts_with(expected) {
+            self.offset += expected.len_utf8();
+            return true
+        }
+        false
+    }
+    // appends the following digits to an integer, subtracting them for negative numbers, and counts them
+    fn digits<T: Integer>(&mut self, integer: &T, negative: bool) -> Result<(T, usize), RationalError> {
+        let ten = T::from_u128(10).ok_or(RationalError::Overflow)?;
+        let mut integer = integer.clone();
+        let mut count = 0;
+        for c in self.text[self.offset..].chars().take_while(char::is_ascii_digit) {
+            let digit = T::from_u128(c as u128 - '0' as u128).ok_or(RationalError::Overflow)?;
+            let shifted = integer.checked_mul(&ten).ok_or(RationalError::Overflow)?;
+            integer = if negative { shifted.checked_sub(&digit) } else { shifted.checked_add(&digit) }.ok_or(RationalError::Overflow)?;
+            count += 1;
+        }
+        self.offset += count;
+        Ok((integer, count))
+    }
+    fn unexpected(&self) -> RationalError {
+        match self.text[self.offset..].chars().next() {
+            Some(c) =>  RationalError::Unexpected(self.start + self.offset, c),
+            None    =>  RationalError::UnexpectedEnd(self.start + self.offset)
+        }
+    }
+}
+
+impl<T: Integer> std::convert::From<T> for Rational<T> {
+    fn from(integer: T) -> Self {
+        Rational::from_integer(integer)
+    }
+}
+
+// the operators for owned and borrowed fractions, which panic on overflow
+macro_rules! impl_rational_ops {
+    ($($trait:ident, $method:ident, $checked:ident, $assign:ident, $assign_method:ident, $message:expr;)*) => {
+        $(
+            impl<T: Integer> std::ops::$trait for Rational<T> {
+                type Output = Rational<T>;
+                fn $method(self, other: Rational<T>) -> Rational<T> {
+                    self.$checked(&other).expect($message)
+                }
+            }
+            impl<T: Integer> std::ops::$trait<&Rational<T>> for Rational<T> {
+                type Output = Rational<T>;
+                fn $method(self, other: &Rational<T>) -> Rational<T> {
+                    self.$checked(other).expect($message)
+                }
+            }
+            impl<T: Integer> std::ops::$trait<Rational<T>> for &Rational<T> {
+                type Output = Rational<T>;
+                fn $method(self, other: Rational<T>) -> Rational<T> {
+                    self.$checked(&other).expect($message)
+                }
+            }
+            impl<T: Integer> std::ops::$trait for &Rational<T> {
+                type Output = Rational<T>;
+                fn $method(self, other: &Rational<T>) -> Rational<T> {
+                    self.$checked(other).expect($message)
+                }
+            }
+            impl<T: Integer> std::ops::$assign for Rational<T> {
+                fn $assign_method(&mut self, other: Rational<T>) {
+                    *self = self.$checked(&other).expect($message);
+                }
+            }
+            impl<T: Integer> std::ops::$assign<&Rational<T>> for Rational<T> {
+                fn $assign_method(&mut self, other: &Rational<T>) {
+                    *self = self.$checked(other).expect($message);
+                }
+            }
+        )*
+    };
+}
+impl_rational_ops! {
+    Add, add, checked_add, AddAssign, add_assign, "attempt to add with overflow";
+    Sub, sub, checked_sub, SubAssign, sub_assign, "attempt to subtract with overflow";
+    Mul, mul, checked_mul, MulAssign, mul_assign, "attempt to multiply with overflow";
+    Div, div, checked_div, DivAssign, div_assign, "attempt to divide by zero or with overflow";
+    Rem, rem, checked_rem, RemAssign, rem_assign, "attempt to calculate the remainder with a divisor of zero or with overflow";
+}
+
+impl<T: Integer> std::ops::Neg for Rational<T> {
+    type Output = Rational<T>;
+    fn neg(self) -> Rational<T> {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+impl<T: Integer> std::ops::Neg for &Rational<T> {
+    type Output = Rational<T>;
+    fn neg(self) -> Rational<T> {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+impl<T: Integer> std::iter::Sum for Rational<T> {
+    fn sum<I: Iterator<Item = Rational<T>>>(iter: I) -> Self {
+        iter.fold(Rational::zero(), |sum, x| sum + x)
+    }
+}
+impl<T: Integer> std::iter::Product for Rational<T> {
+    fn product<I: Iterator<Item = Rational<T>>>(iter: I) -> Self {
+        iter.fold(Rational::one(), |product, x| product * x)
+    }
+}
@@ -0,0 +1,137 @@
+// (normal, superscript) and (normal, subscript) pairs, the minus sign `−` is handled as `-`
+const SUPERSCRIPTS : [(char, char);68] = [
+    ('0', '⁰'), ('1', '¹'), ('2', '²'), ('3', '³'), ('4', '⁴'), ('5', '⁵'), ('6', '⁶'), ('7', '⁷'), ('8', '⁸'), ('9', '⁹'),
+    ('+', '⁺'), ('-', '⁻'), ('=', '⁼'), ('(', '⁽'), (')', '⁾'),
+    ('a', 'ᵃ'), ('b', 'ᵇ'), ('c', 'ᶜ'), ('d', 'ᵈ'), ('e', 'ᵉ'), ('f', 'ᶠ'), ('g', 'ᵍ'), ('h', 'ʰ'), ('i', 'ⁱ'), ('j', 'ʲ'),
+    ('k', 'ᵏ'), ('l', 'ˡ'), ('m', 'ᵐ'), ('n', 'ⁿ'), ('o', 'ᵒ'), ('p', 'ᵖ'), ('r', 'ʳ'), ('s', 'ˢ'), ('t', 'ᵗ'), ('u', 'ᵘ'),
+    ('v', 'ᵛ'), ('w', 'ʷ'), ('x', 'ˣ'), ('y', 'ʸ'), ('z', 'ᶻ'),
+    ('A', 'ᴬ'), ('B', 'ᴮ'), ('D', 'ᴰ'), ('E', 'ᴱ'), ('G', 'ᴳ'), ('H', 'ᴴ'), ('I', 'ᴵ'), ('J', 'ᴶ'), ('K', 'ᴷ'), ('L', 'ᴸ'),
+    ('M', 'ᴹ'), ('N', 'ᴺ'), ('O', 'ᴼ'), ('P', 'ᴾ'), ('R', 'ᴿ'), ('T', 'ᵀ'), ('U', 'ᵁ'), ('V', 'ⱽ'), ('W', 'ᵂ'),
+    ('α', 'ᵅ'), ('β', 'ᵝ'), ('γ', 'ᵞ'), ('δ', 'ᵟ'), ('ε', 'ᵋ'), ('θ', 'ᶿ'), ('ι', 'ᶥ'), ('φ', 'ᵠ'), ('χ', 'ᵡ'),
+];
+const SUBSCRIPTS : [(char, char);37] = [
+    ('0', '₀'), ('1', '₁'), ('2', '₂'), ('3', '₃'), ('4', '₄'), ('5', '₅'), ('6', '₆'), ('7', '₇'), ('8', '₈'), ('9', '₉'),
+    ('+', '₊'), ('-', '₋'), ('=', '₌'), ('(', '₍'), (')', '₎'),
+    ('a', 'ₐ'), ('e', 'ₑ'), ('h', 'ₕ'), ('i', 'ᵢ'), ('j', 'ⱼ'), ('k', 'ₖ'), ('l', 'ₗ'), ('m', 'ₘ'), ('n', 'ₙ'), ('o', 'ₒ'),
+    ('p', 'ₚ'), ('r', 'ᵣ'), ('s', 'ₛ'), ('t', 'ₜ'), ('u', 'ᵤ'), ('v', 'ᵥ'), ('x', 'ₓ'),
+    ('β', 'ᵦ'), ('γ', 'ᵧ'), ('ρ', 'ᵨ'), ('φ', 'ᵩ'), ('χ', 'ᵪ'),
+];
+
+/// an error of the superscript and subscript conversions
+///
+/// Holds every character which has no Unicode form, as `(offset, char)` where `offset` is the byte offset into the text.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub struct ScriptError {
+    pub unmappable: Vec<(usize, char)>,
+}
+
+impl std::fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(f,"no Unicode form for")?;
+        for (offset, c) in &self.unmappable {
+            write!(f," {:?} at {}",c,offset)?;
+        }
+        Ok(())
+    }
+}
+impl std::error::Error for ScriptError {}
+
+/// converts a text into Unicode superscript
+///
+/// Digits, `+ - = ( )`, most Latin letters and the Greek letters `αβγδεθιφχ` have a superscript form.
+///
+/// # Errors
+/// Returns a `ScriptError` with every character which has no superscript form.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::to_superscript;
+/// assert_eq!(Ok(String::from("⁻¹²")), to_superscript("-12"));
+/// assert_eq!(Ok(String::from("ⁱᵖ")), to_superscript("ip"));
+/// assert_eq!(vec![(0, 'q')], to_superscript("q+1").unwrap_err().unmappable);
+/// ```
+pub fn to_superscript(text: &str) -> Result<String, ScriptError> {
+    convert(text, |c| lookup(&SUPERSCRIPTS, normal(c)))
+}
+
+/// converts a text into Unicode subscript
+///
+/// Digits, `+ - = ( )`, the letters `aehijklmnoprstuvx` and `βγρφχ` have a subscript form.
+///
+/// # Errors
+/// Returns a `ScriptError` with every character which has no subscript form.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::to_subscript;
+/// assert_eq!(Ok(String::from("₁₂")), to_subscript("12"));
+/// assert_eq!(Ok(String::from("ᵢ₊₁")), to_subscript("i+1"));
+/// assert_eq!(vec![(1, 'b'), (2, 'c')], to_subscript("abc").unwrap_err().unmappable);
+/// ```
+pub fn to_subscript(text: &str) -> Result<String, ScriptError> {
+    convert(text, |c| lookup(&SUBSCRIPTS, normal(c)))
+}
+
+/// converts a Unicode superscript back into normal text
+///
+/// # Errors
+/// Returns a `ScriptError` with every character which is no superscript.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::from_superscript;
+/// assert_eq!(Ok(String::from("-12")), from_superscript("⁻¹²"));
+/// assert_eq!(vec![(2, '2')], from_superscript("¹2").unwrap_err().unmappable);
+/// ```
+pub fn from_superscript(text: &str) -> Result<String, ScriptError> {
+    convert(text, |c| reverse(&SUPERSCRIPTS, c))
+}
+
+/// converts a Unicode subscript back into normal text
+///
+/// # Errors
+/// Returns a `ScriptError` with every character which is no subscript.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::from_subscript;
+/// assert_eq!(Ok(String::from("max")), from_subscript("ₘₐₓ"));
+/// ```
+pub fn from_subscript(text: &str) -> Result<String, ScriptError> {
+    convert(text, |c| reverse(&SUBSCRIPTS, c))
+}
+
+pub(super) fn is_superscript(c: char) -> bool {
+    reverse(&SUPERSCRIPTS, c).is_some()
+}
+pub(super) fn is_subscript(c: char) -> bool {
+    reverse(&SUBSCRIPTS, c).is_some()
+}
+
+fn convert<F: Fn(char) -> Option<char>>(text: &str, map: F) -> Result<String, ScriptError> {
+    let mut converted = String::with_capacity(text.len());
+    let mut unmappable = Vec::new();
+    for (offset, c) in text.char_indices() {
+        match map(c) {
+            Some(c) =>  converted.push(c),
+            None    =>  unmappable.push((offset, c))
+        }
+    }
+    if unmappable.is_empty() {
+        Ok(converted)
+    } else {
+        Err(ScriptError { unmappable })
+    }
+}
+fn normal(c: char) -> char {
+    match c {
+        '−' =>  '-',
+        _   =>  c
+    }
+}
+fn lookup(table: &[(char, char)], c: char) -> Option<char> {
+    table.iter().find(|(normal, _)| *normal == c).map(|(_, script)| *script)
+}
+fn reverse(table: &[(char, char)], c: char) -> Option<char> {
+    table.iter().find(|(_, script)| *script == c).map(|(normal, _)| *normal)
+}
@@ -0,0 +1,317 @@
+use super::SiUnit;
+use super::units::is_unit_symbol;
+
+/// a decimal prefix of the SI, including the prefixes of 2022
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{GreekAlphabet, SiPrefix};
+/// assert_eq!("k", SiPrefix::Kilo.symbol());
+/// assert_eq!(Some(GreekAlphabet::My.lowercase()), SiPrefix::Micro.symbol().chars().next());
+/// assert_eq!(1e-6, SiPrefix::Micro.factor());
+/// assert_eq!(Some(SiPrefix::Micro), SiPrefix::from_symbol("u"));
+/// ```
+# [derive(Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Debug)]
+pub enum SiPrefix {
+    Quecto, Ronto,  Yocto,  Zepto,  Atto,   Femto,  Pico,   Nano,   Micro,  Milli,  Centi,  Deci,
+    Deca,   Hecto,  Kilo,   Mega,   Giga,   Tera,   Peta,   Exa,    Zetta,  Yotta,  Ronna,  Quetta,
+}
+
+impl SiPrefix {
+    /// every `SiPrefix`, from the smallest to the largest
+    pub const ALL : [SiPrefix;24] = [
+        SiPrefix::Quecto, SiPrefix::Ronto, SiPrefix::Yocto, SiPrefix::Zepto, SiPrefix::Atto, SiPrefix::Femto,
+        SiPrefix::Pico, SiPrefix::Nano, SiPrefix::Micro, SiPrefix::Milli, SiPrefix::Centi, SiPrefix::Deci,
+        SiPrefix::Deca, SiPrefix::Hecto, SiPrefix::Kilo, SiPrefix::Mega, SiPrefix::Giga, SiPrefix::Tera,
+        SiPrefix::Peta, SiPrefix::Exa, SiPrefix::Zetta, SiPrefix::Yotta, SiPrefix::Ronna, SiPrefix::Quetta,
+    ];
+
+    /// returns the symbol, `μ` (U+03BC, the lowercase `GreekAlphabet::My`) for `Micro`
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SiPrefix::Quecto    =>  "q",
+            SiPrefix::Ronto     =>  "r",
+            SiPrefix::Yocto     =>  "y",
+            SiPrefix::Zepto     =>  "z",
+            SiPrefix::Atto      =>  "a",
+            SiPrefix::Femto     =>  "f",
+            SiPrefix::Pico      =>  "p",
+            SiPrefix::Nano      =>  "n",
+            SiPrefix::Micro     =>  "μ",
+            SiPrefix::Milli     =>  "m",
+            SiPrefix::Centi     =>  "c",
+            SiPrefix::Deci      =>  "d",
+            SiPrefix::Deca      =>  "da",
+            SiPrefix::Hecto     =>  "h",
+            SiPrefix::Kilo      =>  "k",
+            SiPrefix::Mega      =>  "M",
+            SiPrefix::Giga      =>  "G",
+            SiPrefix::Tera      =>  "T",
+            SiPrefix::Peta      =>  "P",
+            SiPrefix::Exa       =>  "E",
+            SiPrefix::Zetta     =>  "Z",
+            SiPrefix::Yotta     =>  "Y",
+            SiPrefix::Ronna     =>  "R",
+            SiPrefix::Quetta    =>  "Q"
+        }
+    }
+    /// returns the English name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SiPrefix::Quecto    =>  "quecto",
+            SiPrefix::Ronto     =>  "ronto",
+            SiPrefix::Yocto     =>  "yocto",
+            SiPrefix::Zepto     =>  "zepto",
+            SiPrefix::Atto      =>  "atto",
+            SiPrefix::Femto     =>  "femto",
+            SiPrefix::Pico      =>  "pico",
+            SiPrefix::Nano      =>  "nano",
+            SiPrefix::Micro     =>  "micro",
+            SiPrefix::Milli     =>  "milli",
+            SiPrefix::Centi     =>  "centi",
+            SiPrefix::Deci      =>  "deci",
+            SiPrefix::Deca      =>  "deca",
+            SiPrefix::Hecto     =>  "hecto",
+            SiPrefix::Kilo      =>  "kilo",
+            SiPrefix::Mega      =>  "mega",
+            SiPrefix::Giga      =>  "giga",
+            SiPrefix::Tera      =>  "tera",
+            SiPrefix::Peta      =>  "peta",
+            SiPrefix::Exa       =>  "exa",
+            SiPrefix::Zetta     =>  "zetta",
+            SiPrefix::Yotta     =>  "yotta",
+            SiPrefix::Ronna     =>  "ronna",
+            SiPrefix::Quetta    =>  "quetta"
+        }
+    }
+    /// returns the power of ten of the prefix
+    pub fn exponent(&self) -> i8 {
+        match self {
+            SiPrefix::Quecto    =>  -30,
+            SiPrefix::Ronto     =>  -27,
+            SiPrefix::Yocto     =>  -24,
+            SiPrefix::Zepto     =>  -21,
+            SiPrefix::Atto      =>  -18,
+            SiPrefix::Femto     =>  -15,
+            SiPrefix::Pico      =>  -12,
+            SiPrefix::Nano      =>  -9,
+            SiPrefix::Micro     =>  -6,
+            SiPrefix::Milli     =>  -3,
+            SiPrefix::Centi     =>  -2,
+            SiPrefix::Deci      =>  -1,
+            SiPrefix::Deca      =>  1,
+            SiPrefix::Hecto     =>  2,
+            SiPrefix::Kilo      =>  3,
+            SiPrefix::Mega      =>  6,
+            SiPrefix::Giga      =>  9,
+            SiPrefix::Tera      =>  12,
+            SiPrefix::Peta      =>  15,
+            SiPrefix::Exa       =>  18,
+            SiPrefix::Zetta     =>  21,
+            SiPrefix::Yotta     =>  24,
+            SiPrefix::Ronna     =>  27,
+            SiPrefix::Quetta    =>  30
+        }
+    }
+    /// returns the factor of the prefix, `10` to the power of [`SiPrefix::exponent`]
+    pub fn factor(&self) -> f64 {
+        // parsing the decimal gives the closest `f64`, unlike `powi`
+        format!("1e{}", self.exponent()).parse().unwrap()
+    }
+    /// returns the prefix with the given symbol
+    ///
+    /// Besides `μ` (U+03BC), the micro sign `µ` (U+00B5) and `u` are accepted for `Micro`.
+    pub fn from_symbol(symbol: &str) -> Option<SiPrefix> {
+        match symbol {
+            "u" | "µ"   =>  Some(SiPrefix::Micro),
+            _           =>  SiPrefix::ALL.iter().copied().find(|prefix| prefix.symbol() == symbol)
+        }
+    }
+    /// returns the prefix with the given power of ten
+    pub fn from_exponent(exponent: i8) -> Option<SiPrefix> {
+        SiPrefix::ALL.iter().copied().find(|prefix| prefix.exponent() == exponent)
+    }
+}
+
+impl std::fmt::Display for SiPrefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(f,"{}",self.symbol())
+    }
+}
+
+/// an error of [`parse_si`]
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum SiPrefixError {
+    /// the text is empty or only whitespace
+    Empty,
+    /// a character where a number was expected, as `(offset, char)` where `offset` is the byte offset into the text
+    Unexpected(usize, char),
+}
+
+impl std::fmt::Display for SiPrefixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            SiPrefixError::Empty                    =>  write!(f,"empty text"),
+            SiPrefixError::Unexpected(offset, c)    =>  write!(f,"unexpected {:?} at {}",c,offset)
+        }
+    }
+}
+impl std::error::Error for SiPrefixError {}
+
+/// formats a value with the engineering `SiPrefix` which gives a number from 1 to below 1000
+///
+/// The number is the shortest one which parses back to the same `f64`, so no rounding noise appears.
+/// Values beyond quecto and quetta keep the outermost prefix.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::format_si;
+/// assert_eq!("4.7 μF", format_si(4.7e-6, "F"));
+/// assert_eq!("47 kΩ", format_si(47000.0, "Ω"));
+/// assert_eq!("-1.5 mA", format_si(-0.0015, "A"));
+/// assert_eq!("250 V", format_si(250.0, "V"));
+/// assert_eq!("0 s", format_si(0.0, "s"));
+/// assert_eq!("2.2 M", format_si(2.2e6, ""));
+/// ```
+pub fn format_si(value: f64, unit: &str) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return format!("{} {}",value,unit).trim_end().to_string()
+    }
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+    let exponent : i32 = exponent.parse().unwrap();
+    let engineering = (exponent.div_euclid(3) * 3).clamp(-30, 30);
+    let digits : String = mantissa.chars().filter(|c| c.is_ascii_digit()).collect();
+    let point = 1 + exponent - engineering;
+    let number = if point <= 0 {
+        format!("0.{}{}", "0".repeat(-point as usize), digits)
+    } else if point as usize >= digits.len() {
+        format!("{}{}", digits, "0".repeat(point as usize - digits.len()))
+    } else {
+        format!("{}.{}", &digits[..point as usize], &digits[point as usize..])
+    };
+    let prefix = SiPrefix::from_exponent(engineering as i8).map(|prefix| prefix.symbol()).unwrap_or("");
+    let sign = if value < 0.0 { "-" } else { "" };
+    format!("{}{} {}{}",sign,number,prefix,unit).trim_end().to_string()
+}
+
+/// parses a value with an optional `SiPrefix` and unit, returning the value and the unit
+///
+/// The number may have a sign, a decimal point and an exponent like `1.5e3`.
+/// In the RKM notation of resistors and capacitors the prefix stands in place of the decimal point
+/// (`4k7` is 4.7 k), where `R` stands for no prefix (`4R7` is 4.7, `R47` is 0.47).
+/// A unit which is a symbol of an [`SiUnit`] or another unit of [`Unit`](super::Unit) is not split into a prefix,
+/// so `5 m` is 5 metres, `2 mol` are 2 moles, `1 h` is an hour and `5 min` are 5 minutes.
+/// A prefix needs a unit after it, so `10k` is 10 with the unit `k`.
+///
+/// # Errors
+/// Returns an `SiPrefixError` when the text is empty or does not start with a number.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{parse_si, SiPrefixError};
+/// assert_eq!(Ok((4700.0, "Ω")), parse_si("4k7Ω"));
+/// assert_eq!(Ok((4.7e-6, "F")), parse_si("4.7 μF"));
+/// assert_eq!(Ok((2.2e-9, "F")), parse_si("2n2F"));
+/// assert_eq!(Ok((0.47, "Ω")), parse_si("R47Ω"));
+/// assert_eq!(Ok((10e-6, "A")), parse_si("10uA"));
+/// assert_eq!(Ok((10.0, "u")), parse_si("10u"));
+/// assert_eq!(Ok((5.0, "m")), parse_si("5 m"));
+/// assert_eq!(Ok((1.0, "h")), parse_si("1 h"));
+/// assert_eq!(Ok((5.0, "min")), parse_si("5 min"));
+/// assert_eq!(Ok((-1500.0, "Hz")), parse_si("-1.5e3Hz"));
+/// assert_eq!(Err(SiPrefixError::Unexpected(0, 'k')), parse_si("kΩ"));
+/// ```
+pub fn parse_si(text: &str) -> Result<(f64, &str), SiPrefixError> {
+    let start = text.len() - text.trim_start().len();
+    let trimmed = text.trim();
+    let first = match trimmed.chars().next() {
+        Some(first) =>  first,
+        None        =>  return Err(SiPrefixError::Empty)
+    };
+    if let Some((value, rest)) = parse_rkm(trimmed) {
+        return Ok((value, rest.trim_start()))
+    }
+    let length = number_length(trimmed);
+    let number = &trimmed[..length];
+    let rest = trimmed[length..].trim_start();
+    let unit = SiUnit::ALL.iter().any(|unit| unit.symbol() == rest) || is_unit_symbol(rest);
+    let prefix = SiPrefix::ALL.iter().map(|&prefix| (prefix.symbol(), prefix))
+        .chain([("u", SiPrefix::Micro), ("µ", SiPrefix::Micro)])
+        .filter(|(symbol, _)| !unit && rest.starts_with(symbol) && rest.len() > symbol.len())
+        .max_by_key(|(symbol, _)| symbol.len());
+    let (exponent, rest) = match prefix {
+        Some((symbol, prefix))  =>  (prefix.exponent(), &rest[symbol.len()..]),
+        None                    =>  (0, rest)
+    };
+    match scale(number, exponent) {
+        Some(value) =>  Ok((value, rest)),
+        None        =>  Err(SiPrefixError::Unexpected(start, first))
+    }
+}
+
+// the value of a decimal number times ten to the power of `exponent`
+fn scale(number: &str, exponent: i8) -> Option<f64> {
+    if !number.bytes().any(|b| b.is_ascii_digit()) {
+        return None
+    }
+    if number.contains(['e', 'E']) {
+        let value : f64 = number.parse().ok()?;
+        return format!("{}e{}", value, exponent).parse().ok()
+    }
+    format!("{}e{}", number, exponent).parse().ok()
+}
+
+// the length of the decimal number at the start of the text
+fn number_length(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        i += 1;
+    }
+    while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+        i += 1;
+    }
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        let mut j = i + 1;
+        if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+            j += 1;
+        }
+        if j < bytes.len() && bytes[j].is_ascii_digit() {
+            i = j;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+    }
+    i
+}
+
+// a number in the RKM notation, like `4k7` or `R47`, and the text after it
+fn parse_rkm(text: &str) -> Option<(f64, &str)> {
+    let (sign, unsigned) = match text.strip_prefix('-') {
+        Some(unsigned)  =>  ("-", unsigned),
+        None            =>  ("", text.strip_prefix('+').unwrap_or(text))
+    };
+    let integer_length = unsigned.find(|c: char| !c.is_ascii_digit()).unwrap_or(unsigned.len());
+    let marker = unsigned[integer_length..].chars().next()?;
+    let exponent = match marker {
+        'R'             =>  0,
+        'p'             =>  -12,
+        'n'             =>  -9,
+        'μ' | 'µ' | 'u' =>  -6,
+        'm'             =>  -3,
+        'k'             =>  3,
+        'M'             =>  6,
+        'G'             =>  9,
+        'T'             =>  12,
+        _               =>  return None
+    };
+    let fraction = &unsigned[integer_length + marker.len_utf8()..];
+    let fraction_length = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+    if fraction_length == 0 || (integer_length == 0 && marker != 'R') {
+        return None
+    }
+    let number = format!("{}{}.{}", sign, &unsigned[..integer_length], &fraction[..fraction_length]);
+    Some((scale(&number, exponent)?, &fraction[fraction_length..]))
+}
@@ -0,0 +1,531 @@
+use super::greek_letter::letter_name;
+use super::GreekAlphabet;
+use super::greek_input::greek_name;
+use super::script::{to_subscript, to_superscript, from_subscript, from_superscript, is_subscript, is_superscript};
+
+use std::collections::HashSet;
+
+/// the letter a [`Symbol`] is built on
+# [derive(Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Debug)]
+pub enum SymbolBase {
+    /// an ASCII letter
+    Latin(char),
+    /// a letter of `GreekAlphabet`, in uppercase or lowercase
+    Greek {
+        letter: GreekAlphabet,
+        uppercase: bool,
+    },
+}
+impl SymbolBase {
+    /// returns the letter as a [`char`](https://doc.rust-lang.org/std/char/index.html)
+    pub fn char(&self) -> char {
+        match self {
+            SymbolBase::Latin(c)                        =>  *c,
+            SymbolBase::Greek { letter, uppercase }     =>  if *uppercase { letter.uppercase() } else { letter.lowercase() }
+        }
+    }
+    /// returns the letter as LaTeX
+    ///
+    /// Capital Greek letters which look like Latin ones have no LaTeX command and are written as the Latin letter,
+    /// so are `ο` and `Ο`.
+    pub fn latex(&self) -> String {
+        let (letter, uppercase) = match self {
+            SymbolBase::Latin(c)                        =>  return c.to_string(),
+            SymbolBase::Greek { letter, uppercase }     =>  (*letter, *uppercase)
+        };
+        let name = match letter {
+            GreekAlphabet::My       =>  "mu",
+            GreekAlphabet::Ny       =>  "nu",
+            GreekAlphabet::Ypsilon  =>  "upsilon",
+            GreekAlphabet::Omicron  =>  return String::from(if uppercase { "O" } else { "o" }),
+            _                       =>  ""
+        };
+        let name = if name.is_empty() { letter_name(letter).to_lowercase() } else { name.to_string() };
+        if !uppercase {
+            return format!("\\{}", name)
+        }
+        match letter {
+            GreekAlphabet::Gamma | GreekAlphabet::Delta | GreekAlphabet::Theta | GreekAlphabet::Lambda
+            | GreekAlphabet::Xi | GreekAlphabet::Pi | GreekAlphabet::Sigma | GreekAlphabet::Ypsilon
+            | GreekAlphabet::Phi | GreekAlphabet::Psi | GreekAlphabet::Omega    =>  {
+                let mut chars = name.chars();
+                let first = chars.next().unwrap().to_ascii_uppercase();
+                format!("\\{}{}", first, chars.as_str())
+            }
+            _   =>  latin_lookalike(letter).to_string()
+        }
+    }
+}
+
+/// the accent placed over a [`Symbol`]
+# [derive(Clone,Copy,PartialEq,Eq,PartialOrd,Ord,Hash,Debug)]
+pub enum SymbolAccent {
+    Hat,    Bar,    Tilde,  Dot,    DoubleDot,  Vec
+}
+impl SymbolAccent {
+    /// returns the combining [`char`](https://doc.rust-lang.org/std/char/index.html) of `SymbolAccent`
+    pub fn combining(&self) -> char {
+        match self {
+            SymbolAccent::Hat       =>  '\u{0302}',
+            SymbolAccent::Bar       =>  '\u{0304}',
+            SymbolAccent::Tilde     =>  '\u{0303}',
+            SymbolAccent::Dot       =>  '\u{0307}',
+            SymbolAccent::DoubleDot =>  '\u{0308}',
+            SymbolAccent::Vec       =>  '\u{20D7}'
+        }
+    }
+    /// returns the LaTeX command of `SymbolAccent`, without the backslash
+    pub fn latex(&self) -> &'static str {
+        match self {
+            SymbolAccent::Hat       =>  "hat",
+            SymbolAccent::Bar       =>  "bar",
+            SymbolAccent::Tilde     =>  "tilde",
+            SymbolAccent::Dot       =>  "dot",
+            SymbolAccent::DoubleDot =>  "ddot",
+            SymbolAccent::Vec       =>  "vec"
+        }
+    }
+    fn from_combining(c: char) -> Option<Self> {
+        ACCENTS.iter().copied().find(|accent| accent.combining() == c)
+    }
+    fn from_latex(name: &str) -> Option<Self> {
+        ACCENTS.iter().copied().find(|accent| accent.latex() == name)
+    }
+}
+
+const ACCENTS : [SymbolAccent;6] = [
+    SymbolAccent::Hat, SymbolAccent::Bar, SymbolAccent::Tilde, SymbolAccent::Dot, SymbolAccent::DoubleDot, SymbolAccent::Vec
+];
+
+/// an error of parsing a [`Symbol`]
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum SymbolError {
+    /// the text was empty
+    Empty,
+    /// a LaTeX command which is no Greek letter or accent, as `(offset, command)`
+    UnknownCommand(usize, String),
+    /// a character which does not belong there, as `(offset, char)`
+    Unexpected(usize, char),
+    /// a `{` at the offset is never closed
+    Unclosed(usize),
+    /// the prime at the offset makes more than 255 primes
+    TooManyPrimes(usize),
+}
+
+impl std::fmt::Display for SymbolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            SymbolError::Empty                          =>  write!(f,"empty symbol"),
+            SymbolError::UnknownCommand(offset, name)   =>  write!(f,"unknown command \\{} at {}",name,offset),
+            SymbolError::Unexpected(offset, c)          =>  write!(f,"unexpected {:?} at {}",c,offset),
+            SymbolError::Unclosed(offset)               =>  write!(f,"unclosed {{ at {}",offset),
+            SymbolError::TooManyPrimes(offset)          =>  write!(f,"more than 255 primes at {}",offset)
+        }
+    }
+}
+impl std::error::Error for SymbolError {}
+
+/// a variable name of symbolic mathematics, like `α₁`, `θ′`, `x̂` or `λ_max`
+///
+/// A `Symbol` is a Latin or Greek letter with an optional accent, a number of primes and optional sub- and superscript.
+/// It is written as Unicode by `Display` and as LaTeX by [`Symbol::latex`], and parsed from both.
+/// Symbols are ordered by their letter first, then by accent, primes, subscript and superscript.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{GreekAlphabet, Symbol, SymbolAccent};
+/// let alpha = Symbol::greek(GreekAlphabet::Alpha).with_subscript("1");
+/// assert_eq!("α₁", alpha.to_string());
+/// assert_eq!("\\alpha_{1}", alpha.latex());
+///
+/// let x : Symbol = "\\hat{x}'".parse().unwrap();
+/// assert_eq!(Symbol::latin('x').with_accent(SymbolAccent::Hat).with_primes(1), x);
+/// assert_eq!(x, "x̂′".parse().unwrap());
+/// ```
+# [derive(Clone,PartialEq,Eq,PartialOrd,Ord,Hash,Debug)]
+pub struct Symbol {
+    base: SymbolBase,
+    accent: Option<SymbolAccent>,
+    primes: u8,
+    subscript: Option<String>,
+    superscript: Option<String>,
+}
+impl Symbol {
+    /// returns a `Symbol` of a lowercase Greek letter
+    ///
+    /// # Panics
+    /// Panics when called with `GreekAlphabet::Empty`.
+    pub fn greek(letter: GreekAlphabet) -> Self {
+        Symbol::from_base(SymbolBase::Greek { letter: checked(letter), uppercase: false })
+    }
+    /// returns a `Symbol` of an uppercase Greek letter
+    ///
+    /// # Panics
+    /// Panics when called with `GreekAlphabet::Empty`.
+    pub fn greek_upper(letter: GreekAlphabet) -> Self {
+        Symbol::from_base(SymbolBase::Greek { letter: checked(letter), uppercase: true })
+    }
+    /// returns a `Symbol` of a Latin letter
+    ///
+    /// # Panics
+    /// Panics when called with a character which is no ASCII letter.
+    pub fn latin(letter: char) -> Self {
+        assert!(letter.is_ascii_alphabetic(), "charackter is no Latin letter");
+        Symbol::from_base(SymbolBase::Latin(letter))
+    }
+    fn from_base(base: SymbolBase) -> Self {
+        Symbol { base, accent: None, primes: 0, subscript: None, superscript: None }
+    }
+
+    /// returns the `Symbol` with the given subscript
+    pub fn with_subscript(mut self, subscript: &str) -> Self {
+        self.subscript = Some(subscript.to_string()).filter(|s| !s.is_empty());
+        self
+    }
+    /// returns the `Symbol` with the given superscript
+    pub fn with_superscript(mut self, superscript: &str) -> Self {
+        self.superscript = Some(superscript.to_string()).filter(|s| !s.is_empty());
+        self
+    }
+    /// returns the `Symbol` with the given number of primes
+    pub fn with_primes(mut self, primes: u8) -> Self {
+        self.primes = primes;
+        self
+    }
+    /// returns the `Symbol` with the given accent
+    pub fn with_accent(mut self, accent: SymbolAccent) -> Self {
+        self.accent = Some(accent);
+        self
+    }
+
+    /// returns the letter of `Symbol`
+    pub fn base(&self) -> SymbolBase {
+        self.base
+    }
+    /// returns the accent of `Symbol`
+    pub fn accent(&self) -> Option<SymbolAccent> {
+        self.accent
+    }
+    /// returns the number of primes of `Symbol`
+    pub fn primes(&self) -> u8 {
+        self.primes
+    }
+    /// returns the subscript of `Symbol`
+    pub fn subscript(&self) -> Option<&str> {
+        self.subscript.as_deref()
+    }
+    /// returns the superscript of `Symbol`
+    pub fn superscript(&self) -> Option<&str> {
+        self.superscript.as_deref()
+    }
+
+    /// returns `Symbol` as LaTeX
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{GreekAlphabet, Symbol, SymbolAccent};
+    /// let lambda = Symbol::greek(GreekAlphabet::Lambda).with_subscript("max").with_primes(2);
+    /// assert_eq!("\\lambda''_{max}", lambda.latex());
+    /// let omega = Symbol::greek_upper(GreekAlphabet::Omega).with_accent(SymbolAccent::Tilde);
+    /// assert_eq!("\\tilde{\\Omega}", omega.latex());
+    /// ```
+    pub fn latex(&self) -> String {
+        let mut latex = match self.accent {
+            Some(accent)    =>  format!("\\{}{{{}}}", accent.latex(), self.base.latex()),
+            None            =>  self.base.latex()
+        };
+        for _ in 0..self.primes {
+            latex.push('\'');
+        }
+        if let Some(subscript) = &self.subscript {
+            latex.push_str(&format!("_{{{}}}", subscript));
+        }
+        if let Some(superscript) = &self.superscript {
+            latex.push_str(&format!("^{{{}}}", superscript));
+        }
+        latex
+    }
+}
+
+impl std::convert::From<GreekAlphabet> for Symbol {
+    fn from(letter: GreekAlphabet) -> Self {
+        Symbol::greek(letter)
+    }
+}
+
+impl std::fmt::Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(f,"{}",self.base.char())?;
+        if let Some(accent) = self.accent {
+            write!(f,"{}",accent.combining())?;
+        }
+        match self.primes {
+            0   =>  {}
+            1   =>  write!(f,"′")?,
+            2   =>  write!(f,"″")?,
+            3   =>  write!(f,"‴")?,
+            4   =>  write!(f,"⁗")?,
+            n   =>  write!(f,"{}","′".repeat(n as usize))?
+        }
+        if let Some(subscript) = &self.subscript {
+            match to_subscript(subscript) {
+                Ok(script)  =>  write!(f,"{}",script)?,
+                Err(_)      =>  write!(f,"_{}",script_text(subscript))?
+            }
+        }
+        if let Some(superscript) = &self.superscript {
+            match to_superscript(superscript) {
+                Ok(script)  =>  write!(f,"{}",script)?,
+                Err(_)      =>  write!(f,"^{}",script_text(superscript))?
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Symbol {
+    type Err = SymbolError;
+    /// parses a `Symbol` from Unicode (`θ′₂`, `λ_max`) or from LaTeX (`\theta'_{2}`, `\hat{x}`)
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Symbol, SymbolError};
+    /// assert_eq!(Ok(Symbol::latin('x')), "x_{}".parse());
+    /// assert_eq!(Ok(Symbol::latin('x').with_primes(255)), format!("x'''{}", "⁗".repeat(63)).parse());
+    /// assert_eq!(Err(SymbolError::TooManyPrimes(190)), format!("x{}", "⁗".repeat(64)).parse::<Symbol>());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { text: s, offset: 0, end: s.len() };
+        if s.is_empty() {
+            return Err(SymbolError::Empty)
+        }
+        let symbol = parser.symbol()?;
+        match parser.peek() {
+            Some(c) =>  Err(SymbolError::Unexpected(parser.offset, c)),
+            None    =>  Ok(symbol)
+        }
+    }
+}
+
+/// an [`Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html) of fresh [`Symbol`]s
+///
+/// Yields `α`, `β`, …, `ω`, then `α₁`, `β₁`, …, `ω₁`, then `α₂` and so on,
+/// skipping every `Symbol` which is reserved or was already yielded.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{GreekAlphabet, Symbol, SymbolGenerator};
+/// let mut generator = SymbolGenerator::new();
+/// generator.reserve(Symbol::greek(GreekAlphabet::Beta));
+/// let names : Vec<String> = generator.take(3).map(|s| s.to_string()).collect();
+/// assert_eq!(vec!["α", "γ", "δ"], names);
+///
+/// let names : Vec<String> = SymbolGenerator::new().skip(24).take(2).map(|s| s.to_string()).collect();
+/// assert_eq!(vec!["α₁", "β₁"], names);
+/// ```
+# [derive(Clone,Debug,Default)]
+pub struct SymbolGenerator {
+    next: usize,
+    used: HashSet<Symbol>,
+}
+impl SymbolGenerator {
+    /// returns a new `SymbolGenerator`
+    pub fn new() -> Self {
+        SymbolGenerator::default()
+    }
+    /// returns a new `SymbolGenerator` which never yields one of the given `Symbol`s
+    pub fn with_used<I: IntoIterator<Item = Symbol>>(used: I) -> Self {
+        SymbolGenerator { next: 0, used: used.into_iter().collect() }
+    }
+    /// reserves a `Symbol`, so it is never yielded
+    pub fn reserve(&mut self, symbol: Symbol) {
+        self.used.insert(symbol);
+    }
+}
+impl Iterator for SymbolGenerator {
+    type Item = Symbol;
+    fn next(&mut self) -> Option<Symbol> {
+        loop {
+            let letter = GreekAlphabet::from((self.next % 24) as u8 + 1);
+            let round = self.next / 24;
+            self.next += 1;
+            let mut symbol = Symbol::greek(letter);
+            if round > 0 {
+                symbol = symbol.with_subscript(&round.to_string());
+            }
+            if self.used.insert(symbol.clone()) {
+                return Some(symbol)
+            }
+        }
+    }
+}
+
+fn checked(letter: GreekAlphabet) -> GreekAlphabet {
+    if letter == GreekAlphabet::Empty {
+        panic!("charackter not exist in GreekAlphabet")
+    }
+    letter
+}
+
+fn latin_lookalike(letter: GreekAlphabet) -> char {
+    match letter {
+        GreekAlphabet::Alpha    =>  'A',
+        GreekAlphabet::Beta     =>  'B',
+        GreekAlphabet::Epsilon  =>  'E',
+        GreekAlphabet::Zeta     =>  'Z',
+        GreekAlphabet::Eta      =>  'H',
+        GreekAlphabet::Iota     =>  'I',
+        GreekAlphabet::Kappa    =>  'K',
+        GreekAlphabet::My       =>  'M',
+        GreekAlphabet::Ny       =>  'N',
+        GreekAlphabet::Rho      =>  'P',
+        GreekAlphabet::Tau      =>  'T',
+        GreekAlphabet::Chi      =>  'X',
+        _                       =>  'O'
+    }
+}
+
+// a script which is written after `_` or `^` needs braces unless it is a single word
+fn script_text(text: &str) -> String {
+    if text.chars().all(char::is_alphanumeric) {
+        text.to_string()
+    } else {
+        format!("{{{}}}", text)
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    offset: usize,
+    end: usize,
+}
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.offset..self.end].chars().next()
+    }
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+    fn expect(&mut self, expected: char) -> Result<(), SymbolError> {
+        match self.peek() {
+            Some(c) if c == expected    =>  { self.bump(); Ok(()) }
+            Some(c)                     =>  Err(SymbolError::Unexpected(self.offset, c)),
+            None                        =>  Err(SymbolError::Empty)
+        }
+    }
+    fn command(&mut self) -> &'a str {
+        self.take_while(|c| c.is_ascii_alphabetic())
+    }
+    // the content of `{...}`, braces may be nested
+    fn group(&mut self) -> Result<&'a str, SymbolError> {
+        let open = self.offset;
+        self.expect('{')?;
+        let start = self.offset;
+        let mut depth = 1;
+        while let Some(c) = self.bump() {
+            match c {
+                '{' =>  depth += 1,
+                '}' =>  depth -= 1,
+                _   =>  {}
+            }
+            if depth == 0 {
+                return Ok(&self.text[start..self.offset - 1])
+            }
+        }
+        Err(SymbolError::Unclosed(open))
+    }
+
+    fn symbol(&mut self) -> Result<Symbol, SymbolError> {
+        let mut symbol = self.accented_base()?;
+        loop {
+            let primes = match self.peek() {
+                Some('\'') | Some('′')  =>  1,
+                Some('″')               =>  2,
+                Some('‴')               =>  3,
+                Some('⁗')               =>  4,
+                _                       =>  break
+            };
+            symbol.primes = symbol.primes.checked_add(primes).ok_or(SymbolError::TooManyPrimes(self.offset))?;
+            self.bump();
+        }
+        loop {
+            // an empty script like `x_{}` is no script, as with `with_subscript("")`
+            match self.peek() {
+                Some('_')   =>  { self.bump(); symbol.subscript = Some(self.script()?).filter(|s| !s.is_empty()); }
+                Some('^')   =>  { self.bump(); symbol.superscript = Some(self.script()?).filter(|s| !s.is_empty()); }
+                Some(c) if is_subscript(c)      =>  {
+                    let script = self.take_while(is_subscript);
+                    symbol.subscript = from_subscript(script).ok();
+                }
+                Some(c) if is_superscript(c)    =>  {
+                    let script = self.take_while(is_superscript);
+                    symbol.superscript = from_superscript(script).ok();
+                }
+                _           =>  return Ok(symbol)
+            }
+        }
+    }
+    fn accented_base(&mut self) -> Result<Symbol, SymbolError> {
+        let start = self.offset;
+        if self.peek() == Some('\\') {
+            self.bump();
+            let name = self.command();
+            if let Some(accent) = SymbolAccent::from_latex(name) {
+                let inner = self.group()?;
+                let mut parser = Parser { text: self.text, offset: self.offset - 1 - inner.len(), end: self.offset - 1 };
+                let symbol = parser.base()?;
+                if let Some(c) = parser.peek() {
+                    return Err(SymbolError::Unexpected(parser.offset, c))
+                }
+                return Ok(symbol.with_accent(accent))
+            }
+            self.offset = start;
+        }
+        let mut symbol = self.base()?;
+        if let Some(accent) = self.peek().and_then(SymbolAccent::from_combining) {
+            self.bump();
+            symbol.accent = Some(accent);
+        }
+        Ok(symbol)
+    }
+    fn base(&mut self) -> Result<Symbol, SymbolError> {
+        let start = self.offset;
+        match self.bump() {
+            None                                =>  Err(SymbolError::Empty),
+            Some('\\')                          =>  {
+                let name = self.command();
+                greek_command(name).ok_or_else(|| SymbolError::UnknownCommand(start, name.to_string()))
+            }
+            Some(c) if c.is_ascii_alphabetic()  =>  Ok(Symbol::latin(c)),
+            Some(c)                             =>  {
+                let letter = GreekAlphabet::from(c);
+                if letter == GreekAlphabet::Empty {
+                    return Err(SymbolError::Unexpected(start, c))
+                }
+                Ok(if c.is_uppercase() { Symbol::greek_upper(letter) } else { Symbol::greek(letter) })
+            }
+        }
+    }
+    fn script(&mut self) -> Result<String, SymbolError> {
+        match self.peek() {
+            Some('{')                       =>  Ok(self.group()?.to_string()),
+            Some(c) if c.is_alphanumeric()  =>  Ok(self.take_while(char::is_alphanumeric).to_string()),
+            Some(c)                         =>  Err(SymbolError::Unexpected(self.offset, c)),
+            None                            =>  Err(SymbolError::Empty)
+        }
+    }
+    fn take_while<F: Fn(char) -> bool>(&mut self, f: F) -> &'a str {
+        let start = self.offset;
+        while self.peek().is_some_and(&f) {
+            self.bump();
+        }
+        &self.text[start..self.offset]
+    }
+}
+
+fn greek_command(name: &str) -> Option<Symbol> {
+    let (letter, uppercase) = greek_name(name)?;
+    Some(if uppercase { Symbol::greek_upper(letter) } else { Symbol::greek(letter) })
+}
@@ -0,0 +1,508 @@
+use super::Real;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// the identity of the next recording of a tape
+static NEXT_RECORDING : AtomicU64 = AtomicU64::new(0);
+
+/// a tape which records the operations on variables for reverse mode automatic differentiation
+///
+/// One [`backward`](Var::backward) pass returns the gradient of an output for all inputs,
+/// which is faster than forward mode for functions of many inputs and one output.
+/// The tape can be evaluated again for other inputs with [`set`](Tape::set) and [`forward`](Tape::forward),
+/// as long as the recorded operations do not depend on the values, or be [`clear`](Tape::clear)ed to record again.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::Tape;
+/// let tape = Tape::new();
+/// let x = tape.vars(&[1.0, 2.0, 3.0]);
+/// let y = tape.dot(&x, &x).sqrt() + x[0] * x[1].sin();
+/// let gradients = y.backward().of_all(&x);
+/// let norm = 14f64.sqrt();
+/// let expected = [1.0 / norm + 2f64.sin(), 2.0 / norm + 2f64.cos(), 3.0 / norm];
+/// assert_eq!(norm + 2f64.sin(), y.value());
+/// assert!(gradients.iter().zip(&expected).all(|(a, b)| (a - b).abs() < 1e-15));
+/// // the same operations for other inputs
+/// tape.set(&x[1], 0.0);
+/// tape.forward();
+/// assert_eq!(10f64.sqrt(), y.value());
+/// assert_eq!(1.0 / 10f64.sqrt(), y.backward().of(&x[0]));
+/// ```
+# [derive(Debug)]
+pub struct Tape<T: Real> {
+    nodes: RefCell<Vec<Node<T>>>,
+    // the identity of the recording, which changes when the tape is cleared
+    recording: u64,
+}
+
+/// a variable on a [`Tape`], which records the operations with it
+///
+/// # Panics
+/// if the variables of an operation are of different tapes
+pub struct Var<'t, T: Real> {
+    tape: &'t Tape<T>,
+    index: usize,
+}
+
+/// the gradient of an output of a [`Tape`]
+///
+/// # Panics
+/// if a variable is of another tape or of an earlier recording of the tape
+# [derive(Clone,PartialEq,Debug)]
+pub struct Gradients<T: Real> {
+    adjoints: Vec<T>,
+    recording: u64,
+}
+
+// an operation of the tape with the indices of the arguments and the value
+# [derive(Clone,Copy,Debug)]
+struct Node<T: Real> {
+    operation: Operation<T>,
+    arguments: [usize; 2],
+    value: T,
+}
+
+// the operations, of which those with real numbers keep the numbers
+# [derive(Clone,Copy,Debug)]
+enum Operation<T: Real> {
+    Input,
+    Constant,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Atan2,
+    Hypot,
+    AddReal(T),
+    SubReal(T),
+    RealSub(T),
+    MulReal(T),
+    DivReal(T),
+    RealDiv(T),
+    Neg,
+    Recip,
+    Abs,
+    Powi(i32),
+    Powf(T),
+    Sqrt,
+    Cbrt,
+    Exp,
+    ExpM1,
+    Ln,
+    Ln1p,
+    Log10,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Sinh,
+    Cosh,
+    Tanh,
+    Asinh,
+    Acosh,
+    Atanh,
+}
+
+impl<T: Real> Operation<T> {
+    // returns the number of arguments
+    fn arity(&self) -> usize {
+        match self {
+            Operation::Input | Operation::Constant  =>  0,
+            Operation::Add | Operation::Sub | Operation::Mul | Operation::Div
+            | Operation::Pow | Operation::Atan2 | Operation::Hypot  =>  2,
+            _                                       =>  1
+        }
+    }
+    // returns the value of the operation of the arguments
+    fn evaluate(&self, a: T, b: T) -> T {
+        match *self {
+            Operation::Input | Operation::Constant  =>  a,
+            Operation::Add          =>  a + b,
+            Operation::Sub          =>  a - b,
+            Operation::Mul          =>  a * b,
+            Operation::Div          =>  a / b,
+            Operation::Pow          =>  a.powf(b),
+            Operation::Atan2        =>  a.atan2(b),
+            Operation::Hypot        =>  a.hypot(b),
+            Operation::AddReal(c)   =>  a + c,
+            Operation::SubReal(c)   =>  a - c,
+            Operation::RealSub(c)   =>  c - a,
+            Operation::MulReal(c)   =>  a * c,
+            Operation::DivReal(c)   =>  a / c,
+            Operation::RealDiv(c)   =>  c / a,
+            Operation::Neg          =>  -a,
+            Operation::Recip        =>  T::one() / a,
+            Operation::Abs          =>  a.abs(),
+            Operation::Powi(n)      =>  a.powi(n),
+            Operation::Powf(n)      =>  a.powf(n),
+            Operation::Sqrt         =>  a.sqrt(),
+            Operation::Cbrt         =>  a.cbrt(),
+            Operation::Exp          =>  a.exp(),
+            Operation::ExpM1        =>  a.exp_m1(),
+            Operation::Ln           =>  a.ln(),
+            Operation::Ln1p         =>  a.ln_1p(),
+            Operation::Log10        =>  a.log10(),
+            Operation::Sin          =>  a.sin(),
+            Operation::Cos          =>  a.cos(),
+            Operation::Tan          =>  a.tan(),
+            Operation::Asin         =>  a.asin(),
+            Operation::Acos         =>  a.acos(),
+            Operation::Atan         =>  a.atan(),
+            Operation::Sinh         =>  a.sinh(),
+            Operation::Cosh         =>  a.cosh(),
+            Operation::Tanh         =>  a.tanh(),
+            Operation::Asinh        =>  a.asinh(),
+            Operation::Acosh        =>  a.acosh(),
+            Operation::Atanh        =>  a.atanh()
+        }
+    }
+    // returns the partial derivatives of the operation for the arguments and the value
+    fn partials(&self, a: T, b: T, value: T) -> [T; 2] {
+        let one = T::one();
+        let zero = T::zero();
+        let first = match *self {
+            Operation::Input | Operation::Constant  =>  zero,
+            Operation::Add          =>  return [one, one],
+            Operation::Sub          =>  return [one, -one],
+            Operation::Mul          =>  return [b, a],
+            Operation::Div          =>  return [one / b, -value / b],
+            Operation::Pow          =>  return [b * a.powf(b - one), value * a.ln()],
+            Operation::Atan2        =>  {
+                let norm = a.hypot(b);
+                return [b / norm / norm, -a / norm / norm]
+            },
+            Operation::Hypot        =>  return [a / value, b / value],
+            Operation::AddReal(_) | Operation::SubReal(_)   =>  one,
+            Operation::RealSub(_)   =>  -one,
+            Operation::MulReal(c)   =>  c,
+            Operation::DivReal(c)   =>  one / c,
+            Operation::RealDiv(_)   =>  -value / a,
+            Operation::Neg          =>  -one,
+            Operation::Recip        =>  -value * value,
+            Operation::Abs          =>  one.copysign(a),
+            Operation::Powi(0)      =>  zero,
+            Operation::Powi(n)      =>  T::from_f64(n as f64) * n.checked_sub(1).map_or_else(|| a.powi(n) / a, |m| a.powi(m)),
+            Operation::Powf(n)      =>  n * a.powf(n - one),
+            Operation::Sqrt         =>  one / (value + value),
+            Operation::Cbrt         =>  one / (T::from_f64(3.0) * value * value),
+            Operation::Exp          =>  value,
+            Operation::ExpM1        =>  a.exp(),
+            Operation::Ln           =>  one / a,
+            Operation::Ln1p         =>  one / (one + a),
+            Operation::Log10        =>  one / (a * T::from_f64(std::f64::consts::LN_10)),
+            Operation::Sin          =>  a.cos(),
+            Operation::Cos          =>  -a.sin(),
+            Operation::Tan          =>  one + value * value,
+            Operation::Asin         =>  one / (one - a * a).sqrt(),
+            Operation::Acos         =>  -one / (one - a * a).sqrt(),
+            Operation::Atan         =>  one / (one + a * a),
+            Operation::Sinh         =>  a.cosh(),
+            Operation::Cosh         =>  a.sinh(),
+            Operation::Tanh         =>  one - value * value,
+            Operation::Asinh        =>  one / (a * a + one).sqrt(),
+            Operation::Acosh        =>  one / (a * a - one).sqrt(),
+            Operation::Atanh        =>  one / (one - a * a)
+        };
+        [first, zero]
+    }
+}
+
+impl<T: Real> Tape<T> {
+    /// returns an empty tape
+    pub fn new() -> Self {
+        Tape { nodes: RefCell::new(Vec::new()), recording: NEXT_RECORDING.fetch_add(1, Ordering::Relaxed) }
+    }
+    /// returns an input variable
+    pub fn var(&self, value: T) -> Var<'_, T> {
+        self.push(Operation::Input, [0, 0], value)
+    }
+    /// returns input variables of the values
+    pub fn vars(&self, values: &[T]) -> Vec<Var<'_, T>> {
+        values.iter().map(|&value| self.var(value)).collect()
+    }
+    /// returns a constant, which has no gradient
+    pub fn constant(&self, value: T) -> Var<'_, T> {
+        self.push(Operation::Constant, [0, 0], value)
+    }
+    /// returns the number of recorded operations, inputs and constants
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+    /// returns `true` if nothing is recorded
+    pub fn is_empty(&self) -> bool {
+        self.nodes.borrow().is_empty()
+    }
+    /// removes all recorded operations and keeps the memory for the next recording
+    pub fn clear(&mut self) {
+        self.nodes.get_mut().clear();
+        self.recording = NEXT_RECORDING.fetch_add(1, Ordering::Relaxed);
+    }
+    /// sets the value of an input, which changes the values of the outputs after [`forward`](Tape::forward)
+    ///
+    /// # Panics
+    /// if the variable is not an input of the tape
+    pub fn set(&self, var: &Var<'_, T>, value: T) {
+        assert!(std::ptr::eq(self, var.tape), "the variable is of another tape");
+        let node = &mut self.nodes.borrow_mut()[var.index];
+        assert!(matches!(node.operation, Operation::Input), "the variable is not an input");
+        node.value = value;
+    }
+    /// evaluates the recorded operations again with the values of the inputs
+    pub fn forward(&self) {
+        let mut nodes = self.nodes.borrow_mut();
+        for index in 0..nodes.len() {
+            let Node { operation, arguments, .. } = nodes[index];
+            if operation.arity() > 0 {
+                nodes[index].value = operation.evaluate(nodes[arguments[0]].value, nodes[arguments[1]].value);
+            }
+        }
+    }
+    /// returns the sum of the variables, `0` for none
+    pub fn sum<'t>(&'t self, vars: &[Var<'t, T>]) -> Var<'t, T> {
+        match vars.split_first() {
+            Some((&first, rest)) =>  rest.iter().fold(first, |sum, &var| sum + var),
+            None                 =>  self.constant(T::zero())
+        }
+    }
+    /// returns the sum of the products of the variables
+    ///
+    /// # Panics
+    /// if the lengths differ
+    pub fn dot<'t>(&'t self, a: &[Var<'t, T>], b: &[Var<'t, T>]) -> Var<'t, T> {
+        assert_eq!(a.len(), b.len(), "the vectors have different lengths");
+        match a.iter().zip(b).map(|(&a, &b)| a * b).reduce(|sum, product| sum + product) {
+            Some(dot)   =>  dot,
+            None        =>  self.constant(T::zero())
+        }
+    }
+    /// returns the euclidean norm of the variables without an overflow of the squares
+    pub fn norm<'t>(&'t self, vars: &[Var<'t, T>]) -> Var<'t, T> {
+        match vars.split_first() {
+            Some((&first, rest)) =>  rest.iter().fold(first.abs(), |norm, var| norm.hypot(var)),
+            None                 =>  self.constant(T::zero())
+        }
+    }
+
+    // records an operation of the arguments
+    fn push(&self, operation: Operation<T>, arguments: [usize; 2], value: T) -> Var<'_, T> {
+        let mut nodes = self.nodes.borrow_mut();
+        let value = match operation.arity() {
+            0   =>  value,
+            _   =>  operation.evaluate(nodes[arguments[0]].value, nodes[arguments[1]].value)
+        };
+        nodes.push(Node { operation, arguments, value });
+        Var { tape: self, index: nodes.len() - 1 }
+    }
+}
+
+impl<'t, T: Real> Var<'t, T> {
+    /// returns the value
+    pub fn value(&self) -> T {
+        self.tape.nodes.borrow()[self.index].value
+    }
+    /// returns the gradient of the variable for all variables recorded before it
+    pub fn backward(&self) -> Gradients<T> {
+        let nodes = self.tape.nodes.borrow();
+        let mut adjoints = vec![T::zero(); self.index + 1];
+        adjoints[self.index] = T::one();
+        for index in (0..=self.index).rev() {
+            let adjoint = adjoints[index];
+            let Node { operation, arguments, value } = nodes[index];
+            if adjoint == T::zero() || operation.arity() == 0 {
+                continue;
+            }
+            let partials = operation.partials(nodes[arguments[0]].value, nodes[arguments[1]].value, value);
+            for argument in 0..operation.arity() {
+                adjoints[arguments[argument]] = adjoints[arguments[argument]] + partials[argument] * adjoint;
+            }
+        }
+        Gradients { adjoints, recording: self.tape.recording }
+    }
+
+    // records an operation of the variable
+    fn unary(&self, operation: Operation<T>) -> Self {
+        self.tape.push(operation, [self.index, self.index], T::zero())
+    }
+    // records an operation of the variables
+    fn binary(&self, other: &Self, operation: Operation<T>) -> Self {
+        assert!(std::ptr::eq(self.tape, other.tape), "the variables are of different tapes");
+        self.tape.push(operation, [self.index, other.index], T::zero())
+    }
+    /// returns `x` to the power of a variable, for positive `x`
+    pub fn pow(&self, n: &Self) -> Self {
+        self.binary(n, Operation::Pow)
+    }
+    /// returns the angle of the point `(other, self)` in `-π..=π`
+    pub fn atan2(&self, other: &Self) -> Self {
+        self.binary(other, Operation::Atan2)
+    }
+    /// returns `√(x² + y²)` without an overflow of the squares
+    pub fn hypot(&self, other: &Self) -> Self {
+        self.binary(other, Operation::Hypot)
+    }
+    /// returns `x` to an integer power
+    pub fn powi(&self, n: i32) -> Self {
+        self.unary(Operation::Powi(n))
+    }
+    /// returns `x` to a real power
+    pub fn powf(&self, n: T) -> Self {
+        self.unary(Operation::Powf(n))
+    }
+}
+
+// the elementary functions of one variable
+macro_rules! impl_var_functions {
+    ($($doc:literal $name:ident => $operation:ident;)*) => {
+        impl<'t, T: Real> Var<'t, T> {
+            $(
+                #[doc = $doc]
+                pub fn $name(&self) -> Self {
+                    self.unary(Operation::$operation)
+                }
+            )*
+        }
+    };
+}
+impl_var_functions! {
+    "returns `1/x`" recip => Recip;
+    "returns the absolute value, with the derivative `1` or `-1` at `±0` by the sign of zero" abs => Abs;
+    "returns the square root" sqrt => Sqrt;
+    "returns the cube root" cbrt => Cbrt;
+    "returns `eˣ`" exp => Exp;
+    "returns `eˣ - 1`, which is accurate near `0`" exp_m1 => ExpM1;
+    "returns the natural logarithm" ln => Ln;
+    "returns `ln(1 + x)`, which is accurate near `0`" ln_1p => Ln1p;
+    "returns the logarithm to the base 10" log10 => Log10;
+    "returns the sine" sin => Sin;
+    "returns the cosine" cos => Cos;
+    "returns the tangent" tan => Tan;
+    "returns the arcsine" asin => Asin;
+    "returns the arccosine" acos => Acos;
+    "returns the arctangent" atan => Atan;
+    "returns the hyperbolic sine" sinh => Sinh;
+    "returns the hyperbolic cosine" cosh => Cosh;
+    "returns the hyperbolic tangent" tanh => Tanh;
+    "returns the inverse hyperbolic sine" asinh => Asinh;
+    "returns the inverse hyperbolic cosine" acosh => Acosh;
+    "returns the inverse hyperbolic tangent" atanh => Atanh;
+}
+
+impl<T: Real> Gradients<T> {
+    /// returns the partial derivative of a variable, `0` for variables recorded after the output
+    ///
+    /// # Panics
+    /// if the variable is of another tape or of an earlier recording of the tape
+    pub fn of(&self, var: &Var<'_, T>) -> T {
+        assert_eq!(self.recording, var.tape.recording, "the variable is of another tape");
+        self.adjoints.get(var.index).copied().unwrap_or_else(T::zero)
+    }
+    /// returns the partial derivatives of the variables
+    pub fn of_all(&self, vars: &[Var<'_, T>]) -> Vec<T> {
+        vars.iter().map(|var| self.of(var)).collect()
+    }
+}
+
+impl<T: Real> std::default::Default for Tape<T> {
+    fn default() -> Self {
+        Tape::new()
+    }
+}
+
+impl<'t, T: Real> Clone for Var<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'t, T: Real> Copy for Var<'t, T> {}
+
+impl<'t, T: Real> std::fmt::Debug for Var<'t, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.debug_struct("Var").field("index", &self.index).field("value", &self.value()).finish()
+    }
+}
+impl<'t, T: Real> std::fmt::Display for Var<'t, T> {
+    /// writes the value
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        std::fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+// the operators of variables with each other and with real numbers
+macro_rules! impl_var_ops {
+    ($($trait:ident, $method:ident, $assign:ident, $assign_method:ident, $operation:ident, $real:ident;)*) => {
+        $(
+            impl<'t, T: Real> std::ops::$trait for Var<'t, T> {
+                type Output = Var<'t, T>;
+                fn $method(self, other: Var<'t, T>) -> Var<'t, T> {
+                    self.binary(&other, Operation::$operation)
+                }
+            }
+            impl<'t, T: Real> std::ops::$trait<T> for Var<'t, T> {
+                type Output = Var<'t, T>;
+                fn $method(self, other: T) -> Var<'t, T> {
+                    self.unary(Operation::$real(other))
+                }
+            }
+            impl<'t, T: Real> std::ops::$assign for Var<'t, T> {
+                fn $assign_method(&mut self, other: Var<'t, T>) {
+                    *self = self.binary(&other, Operation::$operation);
+                }
+            }
+            impl<'t, T: Real> std::ops::$assign<T> for Var<'t, T> {
+                fn $assign_method(&mut self, other: T) {
+                    *self = self.unary(Operation::$real(other));
+                }
+            }
+        )*
+    };
+}
+impl_var_ops! {
+    Add, add, AddAssign, add_assign, Add, AddReal;
+    Sub, sub, SubAssign, sub_assign, Sub, SubReal;
+    Mul, mul, MulAssign, mul_assign, Mul, MulReal;
+    Div, div, DivAssign, div_assign, Div, DivReal;
+}
+
+impl<'t, T: Real> std::ops::Neg for Var<'t, T> {
+    type Output = Var<'t, T>;
+    fn neg(self) -> Var<'t, T> {
+        self.unary(Operation::Neg)
+    }
+}
+
+// the operators of real numbers with variables
+macro_rules! impl_real_var_ops {
+    ($($float:ty),*) => {
+        $(
+            impl<'t> std::ops::Add<Var<'t, $float>> for $float {
+                type Output = Var<'t, $float>;
+                fn add(self, other: Var<'t, $float>) -> Var<'t, $float> {
+                    other.unary(Operation::AddReal(self))
+                }
+            }
+            impl<'t> std::ops::Sub<Var<'t, $float>> for $float {
+                type Output = Var<'t, $float>;
+                fn sub(self, other: Var<'t, $float>) -> Var<'t, $float> {
+                    other.unary(Operation::RealSub(self))
+                }
+            }
+            impl<'t> std::ops::Mul<Var<'t, $float>> for $float {
+                type Output = Var<'t, $float>;
+                fn mul(self, other: Var<'t, $float>) -> Var<'t, $float> {
+                    other.unary(Operation::MulReal(self))
+                }
+            }
+            impl<'t> std::ops::Div<Var<'t, $float>> for $float {
+                type Output = Var<'t, $float>;
+                fn div(self, other: Var<'t, $float>) -> Var<'t, $float> {
+                    other.unary(Operation::RealDiv(self))
+                }
+            }
+        )*
+    };
+}
+impl_real_var_ops!(f32, f64);
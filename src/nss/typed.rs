@@ -0,0 +1,290 @@
+use super::{Dimension, DimensionError, Quantity, SiUnit};
+
+use std::marker::PhantomData;
+
+/// an exponent of a base unit as a type, for the dimensions of [`TypedQuantity`]
+# [derive(Clone,Copy,PartialEq,Eq,Hash,Debug,Default)]
+pub struct Exp<const N: i8>;
+
+/// an exponent type with its value
+pub trait Exponent {
+    const VALUE: i8;
+}
+impl<const N: i8> Exponent for Exp<N> {
+    const VALUE : i8 = N;
+}
+
+/// the sum of two exponent types
+pub trait ExpAdd<Rhs> {
+    type Output: Exponent;
+}
+/// the difference of two exponent types
+pub trait ExpSub<Rhs> {
+    type Output: Exponent;
+}
+
+// the sums and differences of all exponents in the table
+macro_rules! impl_exp_table {
+    ($($n:literal)*) => {
+        impl_exp_table!(@rows [$($n)*] $($n)*);
+    };
+    (@rows $all:tt $($n:literal)*) => {
+        $( impl_exp_table!(@row $n $all); )*
+    };
+    (@row $n:literal [$($m:literal)*]) => {
+        $(
+            impl ExpAdd<Exp<$m>> for Exp<$n> {
+                type Output = Exp<{ $n + $m }>;
+            }
+            impl ExpSub<Exp<$m>> for Exp<$n> {
+                type Output = Exp<{ $n - $m }>;
+            }
+        )*
+    };
+}
+impl_exp_table!(-8 -7 -6 -5 -4 -3 -2 -1 0 1 2 3 4 5 6 7 8);
+
+/// a dimension as a type, with the exponents of metre, kilogram, second, ampere, kelvin, mole and candela as [`Exp`]
+pub struct Dim<L, M, T, I, K, N, J>(PhantomData<(L, M, T, I, K, N, J)>);
+
+/// a dimension type with its runtime [`Dimension`]
+pub trait DimensionType {
+    const DIMENSION: Dimension;
+}
+impl<L: Exponent, M: Exponent, T: Exponent, I: Exponent, K: Exponent, N: Exponent, J: Exponent> DimensionType for Dim<L, M, T, I, K, N, J> {
+    const DIMENSION : Dimension = Dimension::new([L::VALUE, M::VALUE, T::VALUE, I::VALUE, K::VALUE, N::VALUE, J::VALUE]);
+}
+
+/// the product of two dimension types
+pub trait DimMul<Rhs> {
+    type Output: DimensionType;
+}
+/// the quotient of two dimension types
+pub trait DimDiv<Rhs> {
+    type Output: DimensionType;
+}
+impl<L1, M1, T1, I1, K1, N1, J1, L2, M2, T2, I2, K2, N2, J2> DimMul<Dim<L2, M2, T2, I2, K2, N2, J2>> for Dim<L1, M1, T1, I1, K1, N1, J1>
+where L1: ExpAdd<L2>, M1: ExpAdd<M2>, T1: ExpAdd<T2>, I1: ExpAdd<I2>, K1: ExpAdd<K2>, N1: ExpAdd<N2>, J1: ExpAdd<J2> {
+    type Output = Dim<L1::Output, M1::Output, T1::Output, I1::Output, K1::Output, N1::Output, J1::Output>;
+}
+impl<L1, M1, T1, I1, K1, N1, J1, L2, M2, T2, I2, K2, N2, J2> DimDiv<Dim<L2, M2, T2, I2, K2, N2, J2>> for Dim<L1, M1, T1, I1, K1, N1, J1>
+where L1: ExpSub<L2>, M1: ExpSub<M2>, T1: ExpSub<T2>, I1: ExpSub<I2>, K1: ExpSub<K2>, N1: ExpSub<N2>, J1: ExpSub<J2> {
+    type Output = Dim<L1::Output, M1::Output, T1::Output, I1::Output, K1::Output, N1::Output, J1::Output>;
+}
+
+/// the dimension type with the given exponents of metre, kilogram, second, ampere, kelvin, mole and candela
+pub type Dims<const L: i8, const M: i8, const T: i8, const I: i8, const K: i8, const N: i8, const J: i8> =
+    Dim<Exp<L>, Exp<M>, Exp<T>, Exp<I>, Exp<K>, Exp<N>, Exp<J>>;
+
+/// a value in SI base units whose dimension is checked at compile time
+///
+/// The dimension is the type `D`, so a `TypedQuantity` is a plain `f64` at runtime.
+/// Multiplying and dividing give the type of the combined dimension, adding needs equal types.
+/// The exponents range from -8 to 8 in the factors of a product or quotient,
+/// so the exponents of a result are computed at compile time and never overflow.
+/// [`Quantity`] is the runtime counterpart, both convert into each other and format the same way.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{Length, Time, Velocity, Quantity};
+/// # use std::convert::TryFrom;
+/// let distance = Length::new(100.0);
+/// let time = Time::new(8.0);
+/// let speed : Velocity = distance / time;
+/// assert_eq!(12.5, speed.value());
+/// assert_eq!("12.5 m·s⁻¹", speed.to_string());
+/// assert_eq!(Length::new(200.0), speed * Time::new(16.0));
+/// let quantity = Quantity::from(speed);
+/// assert_eq!(Ok(speed), Velocity::try_from(quantity));
+/// ```
+///
+/// Adding a length to a time does not compile:
+/// ```compile_fail
+/// # use math_sd::nss::{Length, Time};
+/// let wrong = Length::new(1.0) + Time::new(1.0);
+/// ```
+# [repr(transparent)]
+pub struct TypedQuantity<D> {
+    value: f64,
+    dimension: PhantomData<D>,
+}
+
+impl<D: DimensionType> TypedQuantity<D> {
+    /// returns the `TypedQuantity` of `value` SI base units
+    pub const fn new(value: f64) -> Self {
+        TypedQuantity { value, dimension: PhantomData }
+    }
+    /// returns the value in SI base units
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+    /// returns the runtime dimension
+    pub const fn dimension(&self) -> Dimension {
+        D::DIMENSION
+    }
+    /// returns the `SiUnit` of the dimension, if it has one
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Force, SiUnit, Velocity};
+    /// assert_eq!(Some(SiUnit::Newton), Force::new(1.0).unit());
+    /// assert_eq!(None, Velocity::new(1.0).unit());
+    /// ```
+    pub fn unit(&self) -> Option<SiUnit> {
+        SiUnit::from_dimension(D::DIMENSION)
+    }
+}
+
+impl<D> Clone for TypedQuantity<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<D> Copy for TypedQuantity<D> {}
+impl<D> std::cmp::PartialEq for TypedQuantity<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+impl<D> std::cmp::PartialOrd for TypedQuantity<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+impl<D> std::default::Default for TypedQuantity<D> {
+    fn default() -> Self {
+        TypedQuantity { value: 0.0, dimension: PhantomData }
+    }
+}
+impl<D: DimensionType> std::fmt::Debug for TypedQuantity<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        f.debug_struct("TypedQuantity").field("value", &self.value).field("dimension", &D::DIMENSION).finish()
+    }
+}
+impl<D: DimensionType> std::fmt::Display for TypedQuantity<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        std::fmt::Display::fmt(&Quantity::from(*self), f)
+    }
+}
+
+impl<D: DimensionType> std::convert::From<TypedQuantity<D>> for Quantity {
+    fn from(quantity: TypedQuantity<D>) -> Self {
+        Quantity::new(quantity.value, D::DIMENSION)
+    }
+}
+impl<D: DimensionType> std::convert::TryFrom<Quantity> for TypedQuantity<D> {
+    type Error = DimensionError;
+    fn try_from(quantity: Quantity) -> Result<Self, DimensionError> {
+        if quantity.dimension() == D::DIMENSION {
+            Ok(TypedQuantity::new(quantity.value()))
+        } else {
+            Err(DimensionError { left: quantity.dimension(), right: D::DIMENSION })
+        }
+    }
+}
+
+impl<D> std::ops::Add for TypedQuantity<D> {
+    type Output = TypedQuantity<D>;
+    fn add(self, other: Self) -> Self {
+        TypedQuantity { value: self.value + other.value, dimension: PhantomData }
+    }
+}
+impl<D> std::ops::Sub for TypedQuantity<D> {
+    type Output = TypedQuantity<D>;
+    fn sub(self, other: Self) -> Self {
+        TypedQuantity { value: self.value - other.value, dimension: PhantomData }
+    }
+}
+impl<D> std::ops::AddAssign for TypedQuantity<D> {
+    fn add_assign(&mut self, other: Self) {
+        self.value += other.value;
+    }
+}
+impl<D> std::ops::SubAssign for TypedQuantity<D> {
+    fn sub_assign(&mut self, other: Self) {
+        self.value -= other.value;
+    }
+}
+impl<D> std::ops::Neg for TypedQuantity<D> {
+    type Output = TypedQuantity<D>;
+    fn neg(self) -> Self {
+        TypedQuantity { value: -self.value, dimension: PhantomData }
+    }
+}
+impl<D1: DimMul<D2>, D2> std::ops::Mul<TypedQuantity<D2>> for TypedQuantity<D1> {
+    type Output = TypedQuantity<D1::Output>;
+    fn mul(self, other: TypedQuantity<D2>) -> Self::Output {
+        TypedQuantity { value: self.value * other.value, dimension: PhantomData }
+    }
+}
+impl<D1: DimDiv<D2>, D2> std::ops::Div<TypedQuantity<D2>> for TypedQuantity<D1> {
+    type Output = TypedQuantity<D1::Output>;
+    fn div(self, other: TypedQuantity<D2>) -> Self::Output {
+        TypedQuantity { value: self.value / other.value, dimension: PhantomData }
+    }
+}
+impl<D> std::ops::Mul<f64> for TypedQuantity<D> {
+    type Output = TypedQuantity<D>;
+    fn mul(self, factor: f64) -> Self {
+        TypedQuantity { value: self.value * factor, dimension: PhantomData }
+    }
+}
+impl<D> std::ops::Div<f64> for TypedQuantity<D> {
+    type Output = TypedQuantity<D>;
+    fn div(self, divisor: f64) -> Self {
+        TypedQuantity { value: self.value / divisor, dimension: PhantomData }
+    }
+}
+impl<D> std::ops::Mul<TypedQuantity<D>> for f64 {
+    type Output = TypedQuantity<D>;
+    fn mul(self, quantity: TypedQuantity<D>) -> TypedQuantity<D> {
+        TypedQuantity { value: self * quantity.value, dimension: PhantomData }
+    }
+}
+impl<D> std::ops::Div<TypedQuantity<D>> for f64 where Dims<0, 0, 0, 0, 0, 0, 0>: DimDiv<D> {
+    type Output = TypedQuantity<<Dims<0, 0, 0, 0, 0, 0, 0> as DimDiv<D>>::Output>;
+    fn div(self, quantity: TypedQuantity<D>) -> Self::Output {
+        TypedQuantity { value: self / quantity.value, dimension: PhantomData }
+    }
+}
+
+/// a pure number
+pub type Dimensionless = TypedQuantity<Dims<0, 0, 0, 0, 0, 0, 0>>;
+/// a length in metres
+pub type Length = TypedQuantity<Dims<1, 0, 0, 0, 0, 0, 0>>;
+/// a mass in kilograms
+pub type Mass = TypedQuantity<Dims<0, 1, 0, 0, 0, 0, 0>>;
+/// a time in seconds
+pub type Time = TypedQuantity<Dims<0, 0, 1, 0, 0, 0, 0>>;
+/// an electric current in amperes
+pub type Current = TypedQuantity<Dims<0, 0, 0, 1, 0, 0, 0>>;
+/// a temperature in kelvins
+pub type Temperature = TypedQuantity<Dims<0, 0, 0, 0, 1, 0, 0>>;
+/// an amount of substance in moles
+pub type Amount = TypedQuantity<Dims<0, 0, 0, 0, 0, 1, 0>>;
+/// a luminous intensity in candelas
+pub type LuminousIntensity = TypedQuantity<Dims<0, 0, 0, 0, 0, 0, 1>>;
+/// an area in square metres
+pub type Area = TypedQuantity<Dims<2, 0, 0, 0, 0, 0, 0>>;
+/// a volume in cubic metres
+pub type Volume = TypedQuantity<Dims<3, 0, 0, 0, 0, 0, 0>>;
+/// a velocity in metres per second
+pub type Velocity = TypedQuantity<Dims<1, 0, -1, 0, 0, 0, 0>>;
+/// an acceleration in metres per square second
+pub type Acceleration = TypedQuantity<Dims<1, 0, -2, 0, 0, 0, 0>>;
+/// a frequency in hertz
+pub type Frequency = TypedQuantity<Dims<0, 0, -1, 0, 0, 0, 0>>;
+/// a force in newtons
+pub type Force = TypedQuantity<Dims<1, 1, -2, 0, 0, 0, 0>>;
+/// a pressure in pascals
+pub type Pressure = TypedQuantity<Dims<-1, 1, -2, 0, 0, 0, 0>>;
+/// an energy in joules
+pub type Energy = TypedQuantity<Dims<2, 1, -2, 0, 0, 0, 0>>;
+/// a power in watts
+pub type Power = TypedQuantity<Dims<2, 1, -3, 0, 0, 0, 0>>;
+/// an electric charge in coulombs
+pub type Charge = TypedQuantity<Dims<0, 0, 1, 1, 0, 0, 0>>;
+/// an electric voltage in volts
+pub type Voltage = TypedQuantity<Dims<2, 1, -3, -1, 0, 0, 0>>;
+/// an electric resistance in ohms
+pub type Resistance = TypedQuantity<Dims<2, 1, -3, -2, 0, 0, 0>>;
@@ -0,0 +1,437 @@
+use super::{Dimension, DimensionError, Quantity, SiPrefix};
+use super::script::from_superscript;
+
+// (symbol, factor to SI base units, exponents of the dimension, takes an SI prefix)
+const LINEAR_UNITS : [(&str, f64, [i8;7], bool);64] = [
+    // SI base units, the kilogram as gram for the prefixes
+    ("m", 1.0, [1, 0, 0, 0, 0, 0, 0], true),       ("g", 1e-3, [0, 1, 0, 0, 0, 0, 0], true),
+    ("kg", 1.0, [0, 1, 0, 0, 0, 0, 0], false),     ("s", 1.0, [0, 0, 1, 0, 0, 0, 0], true),
+    ("A", 1.0, [0, 0, 0, 1, 0, 0, 0], true),       ("K", 1.0, [0, 0, 0, 0, 1, 0, 0], true),
+    ("mol", 1.0, [0, 0, 0, 0, 0, 1, 0], true),     ("cd", 1.0, [0, 0, 0, 0, 0, 0, 1], true),
+    // SI derived units
+    ("N", 1.0, [1, 1, -2, 0, 0, 0, 0], true),      ("Pa", 1.0, [-1, 1, -2, 0, 0, 0, 0], true),
+    ("J", 1.0, [2, 1, -2, 0, 0, 0, 0], true),      ("W", 1.0, [2, 1, -3, 0, 0, 0, 0], true),
+    ("C", 1.0, [0, 0, 1, 1, 0, 0, 0], true),       ("V", 1.0, [2, 1, -3, -1, 0, 0, 0], true),
+    ("F", 1.0, [-2, -1, 4, 2, 0, 0, 0], true),     ("Ω", 1.0, [2, 1, -3, -2, 0, 0, 0], true),
+    ("ohm", 1.0, [2, 1, -3, -2, 0, 0, 0], true),   ("S", 1.0, [-2, -1, 3, 2, 0, 0, 0], true),
+    ("Wb", 1.0, [2, 1, -2, -1, 0, 0, 0], true),    ("T", 1.0, [0, 1, -2, -1, 0, 0, 0], true),
+    ("H", 1.0, [2, 1, -2, -2, 0, 0, 0], true),     ("Hz", 1.0, [0, 0, -1, 0, 0, 0, 0], true),
+    ("rad", 1.0, [0, 0, 0, 0, 0, 0, 0], true),     ("°", std::f64::consts::PI / 180.0, [0, 0, 0, 0, 0, 0, 0], false),
+    // units accepted for use with the SI
+    ("min", 60.0, [0, 0, 1, 0, 0, 0, 0], false),   ("h", 3600.0, [0, 0, 1, 0, 0, 0, 0], false),
+    ("d", 86400.0, [0, 0, 1, 0, 0, 0, 0], false),  ("L", 1e-3, [3, 0, 0, 0, 0, 0, 0], true),
+    ("l", 1e-3, [3, 0, 0, 0, 0, 0, 0], true),      ("t", 1e3, [0, 1, 0, 0, 0, 0, 0], false),
+    ("bar", 1e5, [-1, 1, -2, 0, 0, 0, 0], true),   ("eV", 1.602176634e-19, [2, 1, -2, 0, 0, 0, 0], true),
+    ("Å", 1e-10, [1, 0, 0, 0, 0, 0, 0], false),    ("atm", 101325.0, [-1, 1, -2, 0, 0, 0, 0], false),
+    ("mmHg", 133.322387415, [-1, 1, -2, 0, 0, 0, 0], false),
+    // imperial and US customary units
+    ("in", 0.0254, [1, 0, 0, 0, 0, 0, 0], false),  ("ft", 0.3048, [1, 0, 0, 0, 0, 0, 0], false),
+    ("yd", 0.9144, [1, 0, 0, 0, 0, 0, 0], false),  ("mi", 1609.344, [1, 0, 0, 0, 0, 0, 0], false),
+    ("ac", 4046.8564224, [2, 0, 0, 0, 0, 0, 0], false),
+    ("gal", 3.785411784e-3, [3, 0, 0, 0, 0, 0, 0], false),  ("qt", 9.46352946e-4, [3, 0, 0, 0, 0, 0, 0], false),
+    ("pt", 4.73176473e-4, [3, 0, 0, 0, 0, 0, 0], false),    ("floz", 2.95735295625e-5, [3, 0, 0, 0, 0, 0, 0], false),
+    ("lb", 0.45359237, [0, 1, 0, 0, 0, 0, 0], false),       ("oz", 0.028349523125, [0, 1, 0, 0, 0, 0, 0], false),
+    ("st", 6.35029318, [0, 1, 0, 0, 0, 0, 0], false),       ("lbf", 4.4482216152605, [1, 1, -2, 0, 0, 0, 0], false),
+    ("psi", 6894.757293168361, [-1, 1, -2, 0, 0, 0, 0], false),
+    ("mph", 0.44704, [1, 0, -1, 0, 0, 0, 0], false),        ("hp", 745.699_871_582_270_2, [2, 1, -3, 0, 0, 0, 0], false),
+    ("BTU", 1055.05585262, [2, 1, -2, 0, 0, 0, 0], false),  ("cal", 4.184, [2, 1, -2, 0, 0, 0, 0], true),
+    ("°R", 5.0 / 9.0, [0, 0, 0, 0, 1, 0, 0], false),
+    // nautical units
+    ("nmi", 1852.0, [1, 0, 0, 0, 0, 0, 0], false), ("kn", 1852.0 / 3600.0, [1, 0, -1, 0, 0, 0, 0], false),
+    ("ftm", 1.8288, [1, 0, 0, 0, 0, 0, 0], false),
+    // CGS units
+    ("dyn", 1e-5, [1, 1, -2, 0, 0, 0, 0], false),  ("erg", 1e-7, [2, 1, -2, 0, 0, 0, 0], false),
+    ("G", 1e-4, [0, 1, -2, -1, 0, 0, 0], false),   ("Mx", 1e-8, [2, 1, -2, -1, 0, 0, 0], false),
+    ("P", 0.1, [-1, 1, -1, 0, 0, 0, 0], false),    ("St", 1e-4, [2, 0, -1, 0, 0, 0, 0], false),
+    ("Gal", 1e-2, [1, 0, -2, 0, 0, 0, 0], false),
+];
+
+// (symbol, ice point, degrees per kelvin as a fraction) of the affine temperatures
+const AFFINE_UNITS : [(&str, f64, (f64, f64));4] = [
+    ("°C", 0.0, (1.0, 1.0)),    ("degC", 0.0, (1.0, 1.0)),
+    ("°F", 32.0, (9.0, 5.0)),   ("degF", 32.0, (9.0, 5.0)),
+];
+// the ice point in kelvin
+const ICE_POINT : f64 = 273.15;
+
+// (symbol, reference in SI base units, exponents of the dimension, 10 for power and 20 for root-power quantities)
+const LOG_UNITS : [(&str, f64, [i8;7], f64);4] = [
+    ("dB", 1.0, [0, 0, 0, 0, 0, 0, 0], 10.0),      ("dBm", 1e-3, [2, 1, -3, 0, 0, 0, 0], 10.0),
+    ("dBW", 1.0, [2, 1, -3, 0, 0, 0, 0], 10.0),    ("dBV", 1.0, [2, 1, -3, -1, 0, 0, 0], 20.0),
+];
+
+/// an error of parsing a unit expression or converting between units
+///
+/// The offsets are byte offsets into the unit expression.
+# [derive(Clone,PartialEq,Eq,Debug)]
+pub enum UnitError {
+    /// the unit expression is empty
+    Empty,
+    /// a unit symbol which is not known, as `(offset, symbol)`
+    UnknownUnit(usize, String),
+    /// a character which does not fit the expression, as `(offset, char)`
+    Unexpected(usize, char),
+    /// the expression ends where a unit or exponent was expected, as the offset of the end
+    UnexpectedEnd(usize),
+    /// a unit which raises an exponent of the dimension out of the range of `i8`, as the offset of the unit
+    Overflow(usize),
+    /// an affine or logarithmic unit which is combined with other units or raised to a power, as `(offset, symbol)`
+    NotCombinable(usize, String),
+    /// units of different dimensions
+    Incompatible(DimensionError),
+}
+
+impl std::fmt::Display for UnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        match self {
+            UnitError::Empty                        =>  write!(f,"empty unit"),
+            UnitError::UnknownUnit(offset, symbol)  =>  write!(f,"unknown unit {:?} at {}",symbol,offset),
+            UnitError::Unexpected(offset, c)        =>  write!(f,"unexpected {:?} at {}",c,offset),
+            UnitError::UnexpectedEnd(offset)        =>  write!(f,"unexpected end at {}",offset),
+            UnitError::Overflow(offset)             =>  write!(f,"dimension exponent overflow at {}",offset),
+            UnitError::NotCombinable(offset, symbol)    =>  write!(f,"unit {:?} at {} cannot be combined",symbol,offset),
+            UnitError::Incompatible(error)          =>  write!(f,"{}",error)
+        }
+    }
+}
+impl std::error::Error for UnitError {}
+
+// how the value of a unit relates to SI base units
+# [derive(Clone,Copy,PartialEq,Debug)]
+enum Scale {
+    // SI = value · factor
+    Linear,
+    // SI = (value − ice point) · denominator / numerator + 273.15, with the degrees per kelvin as a fraction
+    Affine(f64, (f64, f64)),
+    // SI = factor · 10^(value / divisor)
+    Logarithmic(f64),
+}
+
+/// a unit of measurement, parsed from a unit expression
+///
+/// An expression multiplies units with `*`, `·` or whitespace and divides them with `/`.
+/// Exponents are written as `^2`, `^-1`, superscripts like `²` and `⁻¹`, or digits right after a symbol like `m2`.
+/// Parentheses group units, a number like `1` or `1000` stands for itself.
+/// Units which take SI prefixes can have any [`SiPrefix`] (`km`, `μs`, `kPa`).
+///
+/// Besides SI, the units include imperial and US customary units (`in`, `ft`, `mi`, `lb`, `gal`, `psi`, ...),
+/// nautical units (`nmi`, `kn`), CGS units (`dyn`, `erg`, `G`, `P`), the affine temperatures `°C` and `°F`
+/// and the logarithmic units `dB`, `dBm`, `dBW` and `dBV`.
+/// Affine and logarithmic units cannot be combined with other units.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{Dimension, SiUnit, Unit};
+/// let joule : Unit = "kg·m²/s²".parse().unwrap();
+/// assert_eq!(SiUnit::Joule.dimension(), joule.dimension());
+/// let newton_metre : Unit = "N m".parse().unwrap();
+/// assert_eq!(joule, newton_metre);
+/// let cubic_foot : Unit = "ft^3".parse().unwrap();
+/// assert!((cubic_foot.factor() - 0.028316846592).abs() < 1e-15);
+/// ```
+# [derive(Clone,Copy,PartialEq,Debug)]
+pub struct Unit {
+    factor: f64,
+    dimension: Dimension,
+    scale: Scale,
+}
+
+impl Unit {
+    /// parses a unit expression
+    ///
+    /// # Errors
+    /// Returns a `UnitError` pointing at the token which cannot be parsed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Unit, UnitError};
+    /// assert_eq!(Err(UnitError::UnknownUnit(4, String::from("parsec"))), Unit::parse("m / parsec"));
+    /// assert_eq!(Err(UnitError::Unexpected(3, ')')), Unit::parse("m/s)"));
+    /// assert_eq!(Err(UnitError::UnexpectedEnd(5)), Unit::parse("kg/s^"));
+    /// assert_eq!(Err(UnitError::NotCombinable(0, String::from("°C"))), Unit::parse("°C/s"));
+    /// assert_eq!(Err(UnitError::Overflow(6)), Unit::parse("m^127 m"));
+    /// assert_eq!(Err(UnitError::Overflow(2)), Unit::parse("1/m^-128"));
+    /// ```
+    pub fn parse(expression: &str) -> Result<Unit, UnitError> {
+        let mut parser = Parser { text: expression, offset: 0, terms: 0, powered: false, nonlinear: None };
+        parser.skip_whitespace();
+        if parser.offset == expression.len() {
+            return Err(UnitError::Empty)
+        }
+        let unit = parser.expression()?;
+        if let Some(c) = parser.peek() {
+            return Err(UnitError::Unexpected(parser.offset, c))
+        }
+        match parser.nonlinear {
+            Some((offset, symbol)) if parser.terms > 1 || parser.powered  =>  Err(UnitError::NotCombinable(offset, symbol)),
+            _                                                               =>  Ok(unit)
+        }
+    }
+    /// returns the factor of the unit to SI base units
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+    /// returns the dimension of the unit
+    pub fn dimension(&self) -> Dimension {
+        self.dimension
+    }
+    /// returns `true` unless the unit is affine or logarithmic
+    pub fn is_linear(&self) -> bool {
+        self.scale == Scale::Linear
+    }
+    /// converts a value in this unit into SI base units
+    pub fn to_si(&self, value: f64) -> f64 {
+        match self.scale {
+            Scale::Linear               =>  value * self.factor,
+            Scale::Affine(ice, (numerator, denominator))    =>  (value - ice) * denominator / numerator + ICE_POINT,
+            Scale::Logarithmic(divisor) =>  self.factor * 10f64.powf(value / divisor)
+        }
+    }
+    /// converts a value in SI base units into this unit
+    pub fn from_si(&self, value: f64) -> f64 {
+        match self.scale {
+            Scale::Linear               =>  value / self.factor,
+            Scale::Affine(ice, (numerator, denominator))    =>  (value - ICE_POINT) * numerator / denominator + ice,
+            Scale::Logarithmic(divisor) =>  divisor * (value / self.factor).log10()
+        }
+    }
+    /// converts a value in this unit into another unit
+    ///
+    /// # Errors
+    /// Returns `UnitError::Incompatible` when the units have different dimensions.
+    pub fn convert(&self, value: f64, to: &Unit) -> Result<f64, UnitError> {
+        if self.dimension != to.dimension {
+            return Err(UnitError::Incompatible(DimensionError { left: self.dimension, right: to.dimension }))
+        }
+        if let (Scale::Affine(ice, (numerator, denominator)), Scale::Affine(to_ice, (to_numerator, to_denominator))) = (self.scale, to.scale) {
+            // directly, as the ice point in kelvin would round the values
+            return Ok((value - ice) * (denominator * to_numerator) / (numerator * to_denominator) + to_ice)
+        }
+        Ok(to.from_si(self.to_si(value)))
+    }
+    /// returns the `Quantity` of a value in this unit
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use math_sd::nss::{Quantity, SiUnit, Unit};
+    /// let inch = Unit::parse("in").unwrap();
+    /// assert_eq!(0.254 * SiUnit::Metre, inch.quantity(10.0));
+    /// ```
+    pub fn quantity(&self, value: f64) -> Quantity {
+        Quantity::new(self.to_si(value), self.dimension)
+    }
+    /// returns the value of a `Quantity` in this unit
+    ///
+    /// # Errors
+    /// Returns `UnitError::Incompatible` when the quantity has another dimension.
+    pub fn value_of(&self, quantity: Quantity) -> Result<f64, UnitError> {
+        if self.dimension != quantity.dimension() {
+            return Err(UnitError::Incompatible(DimensionError { left: quantity.dimension(), right: self.dimension }))
+        }
+        Ok(self.from_si(quantity.value()))
+    }
+    fn linear(factor: f64, dimension: Dimension) -> Self {
+        Unit { factor, dimension, scale: Scale::Linear }
+    }
+    fn checked_powi(&self, n: i8) -> Option<Self> {
+        Some(Unit::linear(self.factor.powi(n as i32), self.dimension.checked_powi(n)?))
+    }
+    fn checked_mul(&self, other: &Unit) -> Option<Self> {
+        Some(Unit::linear(self.factor * other.factor, self.dimension.checked_mul(other.dimension)?))
+    }
+    // the unit of a single symbol, with or without an SI prefix
+    fn symbol(symbol: &str) -> Option<Unit> {
+        if let Some(&(_, factor, exponents, _)) = LINEAR_UNITS.iter().find(|unit| unit.0 == symbol) {
+            return Some(Unit::linear(factor, Dimension::new(exponents)))
+        }
+        if let Some(&(_, ice, (numerator, denominator))) = AFFINE_UNITS.iter().find(|unit| unit.0 == symbol) {
+            let scale = Scale::Affine(ice, (numerator, denominator));
+            return Some(Unit { factor: denominator / numerator, dimension: Dimension::TEMPERATURE, scale })
+        }
+        if let Some(&(_, reference, exponents, divisor)) = LOG_UNITS.iter().find(|unit| unit.0 == symbol) {
+            return Some(Unit { factor: reference, dimension: Dimension::new(exponents), scale: Scale::Logarithmic(divisor) })
+        }
+        let prefixes = SiPrefix::ALL.iter().map(|&prefix| (prefix.symbol(), prefix))
+            .chain([("u", SiPrefix::Micro), ("µ", SiPrefix::Micro)]);
+        for (prefix_symbol, prefix) in prefixes {
+            let rest = match symbol.strip_prefix(prefix_symbol) {
+                Some(rest)  =>  rest,
+                None        =>  continue
+            };
+            if let Some(&(_, factor, exponents, _)) = LINEAR_UNITS.iter().find(|unit| unit.0 == rest && unit.3) {
+                return Some(Unit::linear(prefix.factor() * factor, Dimension::new(exponents)))
+            }
+        }
+        None
+    }
+}
+
+impl std::str::FromStr for Unit {
+    type Err = UnitError;
+    fn from_str(expression: &str) -> Result<Self, UnitError> {
+        Unit::parse(expression)
+    }
+}
+
+/// converts a value between two unit expressions, see [`Unit`]
+///
+/// # Errors
+/// Returns a `UnitError` when an expression cannot be parsed or the units have different dimensions.
+///
+/// # Examples
+/// ```rust
+/// # use math_sd::nss::{convert, UnitError};
+/// assert_eq!(Ok(0.44704), convert(1.0, "mi/h", "m/s"));
+/// assert_eq!(Ok(212.0), convert(100.0, "°C", "°F"));
+/// assert_eq!(Ok(37.0), convert(98.6, "°F", "°C"));
+/// assert_eq!(Ok(373.15), convert(100.0, "°C", "K"));
+/// assert_eq!(Ok(1.0), convert(0.0, "dBm", "mW"));
+/// assert_eq!(Ok(30.0), convert(1.0, "W", "dBm"));
+/// assert_eq!(Ok(1e7), convert(1.0, "J", "erg"));
+/// assert_eq!(Ok(1.852), convert(1.0, "kn", "km/h"));
+/// assert!(matches!(convert(1.0, "m", "s"), Err(UnitError::Incompatible(_))));
+/// ```
+pub fn convert(value: f64, from: &str, to: &str) -> Result<f64, UnitError> {
+    Unit::parse(from)?.convert(value, &Unit::parse(to)?)
+}
+
+// a recursive descent parser of unit expressions
+struct Parser<'a> {
+    text: &'a str,
+    offset: usize,
+    // the number of units and numbers
+    terms: usize,
+    // whether an exponent was given
+    powered: bool,
+    // the last affine or logarithmic unit
+    nonlinear: Option<(usize, String)>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.offset..].chars().next()
+    }
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.offset += c.len_utf8();
+        }
+    }
+    // terms joined by `*`, `·`, `/` or whitespace
+    fn expression(&mut self) -> Result<Unit, UnitError> {
+        let mut unit = self.term()?;
+        loop {
+            self.skip_whitespace();
+            let divide = match self.peek() {
+                Some('*') | Some('·') | Some('⋅') | Some('×')    =>  false,
+                Some('/')                                       =>  true,
+                Some(')') | None                                =>  return Ok(unit),
+                Some(_)                                         =>  {
+                    let start = self.offset;
+                    let term = self.term()?;
+                    unit = unit.checked_mul(&term).ok_or(UnitError::Overflow(start))?;
+                    continue
+                }
+            };
+            self.offset += self.peek().map_or(0, char::len_utf8);
+            self.skip_whitespace();
+            let start = self.offset;
+            let term = self.term()?;
+            let term = if divide { term.checked_powi(-1) } else { Some(term) };
+            unit = term.and_then(|term| unit.checked_mul(&term)).ok_or(UnitError::Overflow(start))?;
+        }
+    }
+    // a factor with an optional exponent
+    fn term(&mut self) -> Result<Unit, UnitError> {
+        let start = self.offset;
+        let unit = self.factor()?;
+        let exponent = match self.peek() {
+            Some('^')   =>  {
+                self.offset += 1;
+                let start = self.offset;
+                if let Some('-') | Some('+') = self.peek() {
+                    self.offset += 1;
+                }
+                self.digits();
+                self.text[start..self.offset].parse::<i8>().map_err(|_| self.unexpected(start))?
+            }
+            Some(c) if "⁻⁺⁰¹²³⁴⁵⁶⁷⁸⁹".contains(c)   =>  {
+                let start = self.offset;
+                while let Some(c) = self.peek().filter(|c| "⁻⁺⁰¹²³⁴⁵⁶⁷⁸⁹".contains(*c)) {
+                    self.offset += c.len_utf8();
+                }
+                let exponent = from_superscript(&self.text[start..self.offset]).unwrap_or_default();
+                exponent.parse::<i8>().map_err(|_| self.unexpected(start))?
+            }
+            Some(c) if c.is_ascii_digit() && self.text[..self.offset].ends_with(|c: char| !c.is_ascii_digit() && c != ')')  =>  {
+                let start = self.offset;
+                self.digits();
+                self.text[start..self.offset].parse::<i8>().map_err(|_| self.unexpected(start))?
+            }
+            _   =>  return Ok(unit)
+        };
+        self.powered = true;
+        unit.checked_powi(exponent).ok_or(UnitError::Overflow(start))
+    }
+    // a unit symbol, a number or an expression in parentheses
+    fn factor(&mut self) -> Result<Unit, UnitError> {
+        let start = self.offset;
+        match self.peek() {
+            Some('(')   =>  {
+                self.offset += 1;
+                self.skip_whitespace();
+                let unit = self.expression()?;
+                match self.peek() {
+                    Some(')')   =>  {
+                        self.offset += 1;
+                        Ok(unit)
+                    }
+                    _           =>  Err(self.unexpected(self.offset))
+                }
+            }
+            Some(c) if c.is_ascii_digit()   =>  {
+                self.digits();
+                if self.peek() == Some('.') {
+                    self.offset += 1;
+                    self.digits();
+                }
+                self.terms += 1;
+                let number = self.text[start..self.offset].parse().map_err(|_| self.unexpected(start))?;
+                Ok(Unit::linear(number, Dimension::DIMENSIONLESS))
+            }
+            Some(c) if is_symbol_char(c)    =>  {
+                while let Some(c) = self.peek().filter(|c| is_symbol_char(*c)) {
+                    self.offset += c.len_utf8();
+                }
+                let symbol = &self.text[start..self.offset];
+                let unit = Unit::symbol(symbol).ok_or_else(|| UnitError::UnknownUnit(start, symbol.to_string()))?;
+                self.terms += 1;
+                if !unit.is_linear() {
+                    self.nonlinear = Some((start, symbol.to_string()));
+                }
+                Ok(unit)
+            }
+            _   =>  Err(self.unexpected(start))
+        }
+    }
+    fn digits(&mut self) {
+        while let Some(c) = self.peek().filter(char::is_ascii_digit) {
+            self.offset += c.len_utf8();
+        }
+    }
+    fn unexpected(&self, offset: usize) -> UnitError {
+        match self.text[offset..].chars().next() {
+            Some(c) =>  UnitError::Unexpected(offset, c),
+            None    =>  UnitError::UnexpectedEnd(offset)
+        }
+    }
+}
+
+// whether the text is the symbol of a unit without a prefix
+pub(super) fn is_unit_symbol(symbol: &str) -> bool {
+    LINEAR_UNITS.iter().any(|unit| unit.0 == symbol) || AFFINE_UNITS.iter().any(|unit| unit.0 == symbol)
+        || LOG_UNITS.iter().any(|unit| unit.0 == symbol)
+}
+
+fn is_symbol_char(c: char) -> bool {
+    c.is_alphabetic() || c == '°' || c == 'µ'
+}
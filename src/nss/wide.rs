@@ -0,0 +1,8 @@
+// the 256 bit product as (high, low)
+pub(super) fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    let mask = u64::MAX as u128;
+    let (a1, a0, b1, b0) = (a >> 64, a & mask, b >> 64, b & mask);
+    let (low, left, right) = (a0 * b0, a1 * b0, a0 * b1);
+    let middle = (low >> 64) + (left & mask) + (right & mask);
+    (a1 * b1 + (left >> 64) + (right >> 64) + (middle >> 64), (middle << 64) | (low & mask))
+}